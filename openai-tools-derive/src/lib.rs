@@ -0,0 +1,200 @@
+//! Derive macros for `openai-tools`.
+//!
+//! Use these through the `derive` feature of `openai-tools`, which re-exports
+//! them next to the traits they implement.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Expr, Fields, Lit, LitStr};
+
+/// Derives `openai_tools::common::structured_output::JsonSchema` for a struct with named fields.
+///
+/// Each field becomes a required property whose JSON type follows the field's
+/// Rust type (`Option` fields are nullable) and whose description is the
+/// field's doc comment. Field names
+/// honor `#[serde(rename = "...")]` and the container's
+/// `#[serde(rename_all = "...")]`, and fields marked `#[serde(skip)]` or
+/// `#[serde(skip_deserializing)]` are left out.
+#[proc_macro_derive(JsonSchema)]
+pub fn derive_json_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_json_schema(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand_json_schema(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "JsonSchema can only be derived for structs with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "JsonSchema can only be derived for structs")),
+    };
+
+    let rename_all = rename_all_rule(&input.attrs)?;
+    let mut names = Vec::new();
+    let mut properties = Vec::new();
+    for field in fields {
+        let serde = SerdeField::parse(&field.attrs)?;
+        if serde.skip {
+            continue;
+        }
+        let name = serde.rename.unwrap_or_else(|| {
+            let ident = field.ident.as_ref().unwrap().to_string();
+            let ident = ident.trim_start_matches("r#");
+            rename_all.map_or_else(|| ident.to_string(), |rule| rule.apply(ident))
+        });
+        let ty = &field.ty;
+        let description = match doc_comment(&field.attrs) {
+            Some(doc) => quote!(::std::option::Option::Some(#doc)),
+            None => quote!(::std::option::Option::<&str>::None),
+        };
+        properties.push(quote! {
+            let mut property = <#ty as ::openai_tools::common::structured_output::JsonSchema>::json_schema();
+            if let (::std::option::Option::Some(object), ::std::option::Option::Some(description)) = (property.as_object_mut(), #description) {
+                object.insert("description".to_string(), description.into());
+            }
+            properties.insert(#name.to_string(), property);
+        });
+        names.push(name);
+    }
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::openai_tools::common::structured_output::JsonSchema));
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::openai_tools::common::structured_output::JsonSchema for #ident #ty_generics #where_clause {
+            fn json_schema() -> ::openai_tools::__private::serde_json::Value {
+                let mut properties = ::openai_tools::__private::serde_json::Map::new();
+                #({ #properties })*
+                ::openai_tools::__private::serde_json::json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": [#(#names),*],
+                    "additionalProperties": false,
+                })
+            }
+        }
+    })
+}
+
+/// The `#[serde(...)]` field attributes that affect the schema.
+#[derive(Default)]
+struct SerdeField {
+    rename: Option<String>,
+    skip: bool,
+}
+
+impl SerdeField {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut field = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                    field.skip = true;
+                } else if meta.path.is_ident("rename") && meta.input.peek(syn::Token![=]) {
+                    field.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    skip_meta_value(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(field)
+    }
+}
+
+/// A `#[serde(rename_all = "...")]` rule, applied to snake_case field names.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            other => return Err(syn::Error::new_spanned(lit, format!("unknown rename rule `rename_all = {:?}`", other))),
+        })
+    }
+
+    /// Renames a field the way serde does.
+    fn apply(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => field.split('_').map(|word| map_first_char(word, char::to_ascii_uppercase)).collect(),
+            Self::Camel => map_first_char(&Self::Pascal.apply(field), char::to_ascii_lowercase),
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+/// Applies `f` to the first character of `word`.
+fn map_first_char(word: &str, f: fn(&char) -> char) -> String {
+    let mut chars = word.chars();
+    chars.next().map(|first| f(&first).to_string() + chars.as_str()).unwrap_or_default()
+}
+
+/// Reads the container's `#[serde(rename_all = "...")]` rule, if any.
+fn rename_all_rule(attrs: &[Attribute]) -> syn::Result<Option<RenameRule>> {
+    let mut rule = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") && meta.input.peek(syn::Token![=]) {
+                rule = Some(RenameRule::from_lit(&meta.value()?.parse::<LitStr>()?)?);
+            } else {
+                skip_meta_value(&meta)?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(rule)
+}
+
+/// Consumes the value of a `#[serde(...)]` item the schema does not depend on.
+fn skip_meta_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse::<TokenStream2>()?;
+    }
+    Ok(())
+}
+
+/// Joins the lines of the `///` doc comment, or `None` if there is none.
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta.require_name_value().ok()?.value {
+            Expr::Lit(expr) => match &expr.lit {
+                Lit::Str(doc) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
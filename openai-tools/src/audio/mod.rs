@@ -6,6 +6,7 @@
 //! ## Key Features
 //!
 //! - **Text-to-Speech (TTS)**: Convert text to natural-sounding audio
+//! - **Streaming TTS**: Receive audio chunks as they are generated for early playback
 //! - **Transcription**: Convert audio files to text
 //! - **Translation**: Translate audio to English text
 //! - **Multiple Voices**: Choose from various voice options
@@ -188,8 +189,12 @@ pub mod response;
 
 #[cfg(test)]
 mod tests {
-    use crate::audio::request::{AudioFormat, SttModel, TimestampGranularity, TranscriptionFormat, TtsModel, Voice};
-    use crate::audio::response::TranscriptionResponse;
+    use crate::audio::request::{
+        AudioFormat, SttModel, TimestampGranularity, TranscribeOptions, TranscriptionFormat, TranslateOptions, TtsModel, TtsOptions, TtsRequest,
+        Voice,
+    };
+    use crate::audio::response::{SubtitleCue, TranscriptionResponse};
+    use std::time::Duration;
 
     #[test]
     fn test_transcription_response_deserialization() {
@@ -290,4 +295,106 @@ mod tests {
         assert_eq!(TimestampGranularity::Word.as_str(), "word");
         assert_eq!(TimestampGranularity::Segment.as_str(), "segment");
     }
+
+    #[test]
+    fn test_subtitle_cue_parse_srt() {
+        let srt = "1\r\n00:00:00,000 --> 00:00:02,500\r\nHello, world!\r\n\r\n2\r\n00:00:02,500 --> 00:01:05,040\r\nThis is the\r\nsecond cue.\r\n";
+
+        let cues = SubtitleCue::parse(srt).expect("Should parse SRT");
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0], SubtitleCue { index: 1, start: Duration::ZERO, end: Duration::from_millis(2500), text: "Hello, world!".to_string() });
+        assert_eq!(cues[1].index, 2);
+        assert_eq!(cues[1].start, Duration::from_millis(2500));
+        assert_eq!(cues[1].end, Duration::from_millis(65_040));
+        assert_eq!(cues[1].text, "This is the\nsecond cue.");
+    }
+
+    #[test]
+    fn test_subtitle_cue_parse_vtt() {
+        let vtt = "WEBVTT\n\nNOTE generated by whisper\n\n00:00:00.000 --> 00:00:02.500\nHello, world!\n\nintro\n00:02.500 --> 01:00:01.250 align:start\nSecond cue.\n";
+
+        let cues = SubtitleCue::parse(vtt).expect("Should parse VTT");
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].index, 1);
+        assert_eq!(cues[0].end, Duration::from_millis(2500));
+        assert_eq!(cues[0].text, "Hello, world!");
+        assert_eq!(cues[1].index, 2);
+        assert_eq!(cues[1].start, Duration::from_millis(2500));
+        assert_eq!(cues[1].end, Duration::from_millis(3_601_250));
+        assert_eq!(cues[1].text, "Second cue.");
+    }
+
+    #[test]
+    fn test_subtitle_cue_parse_invalid_timestamp() {
+        assert!(SubtitleCue::parse("1\n00:00:xx,000 --> 00:00:01,000\nBad\n").is_err());
+    }
+
+    #[test]
+    fn test_tts_request_instructions_only_when_provided() {
+        let with_instructions =
+            TtsOptions { model: TtsModel::Gpt4oMiniTts, instructions: Some("Speak cheerfully.".to_string()), ..Default::default() };
+        let body = serde_json::to_value(TtsRequest::new("Hello", with_instructions)).unwrap();
+        assert_eq!(body["model"], "gpt-4o-mini-tts");
+        assert_eq!(body["instructions"], "Speak cheerfully.");
+
+        let without_instructions = TtsOptions { model: TtsModel::Gpt4oMiniTts, ..Default::default() };
+        let body = serde_json::to_value(TtsRequest::new("Hello", without_instructions)).unwrap();
+        assert!(body.get("instructions").is_none());
+
+        // Models without instruction support drop the field
+        let unsupported = TtsOptions { model: TtsModel::Tts1, instructions: Some("Speak cheerfully.".to_string()), ..Default::default() };
+        let body = serde_json::to_value(TtsRequest::new("Hello", unsupported)).unwrap();
+        assert!(body.get("instructions").is_none());
+    }
+
+    #[test]
+    fn test_transcribe_options_form_fields_only_when_set() {
+        let fields = TranscribeOptions::default().form_fields();
+        assert_eq!(fields, vec![("model", "whisper-1".to_string())]);
+
+        let options = TranscribeOptions {
+            prompt: Some("Kubernetes, kubectl, etcd".to_string()),
+            temperature: Some(0.2),
+            timestamp_granularities: Some(vec![TimestampGranularity::Word]),
+            ..Default::default()
+        };
+        let fields = options.form_fields();
+        assert!(fields.contains(&("prompt", "Kubernetes, kubectl, etcd".to_string())));
+        assert!(fields.contains(&("temperature", "0.2".to_string())));
+        assert!(fields.contains(&("timestamp_granularities[]", "word".to_string())));
+        assert!(!fields.iter().any(|(name, _)| *name == "language"));
+    }
+
+    #[test]
+    fn test_translate_options_form_fields_only_when_set() {
+        assert_eq!(TranslateOptions::default().form_fields(), vec![("model", "whisper-1".to_string())]);
+
+        let options = TranslateOptions { prompt: Some("Bonjour".to_string()), temperature: Some(0.5), ..Default::default() };
+        let fields = options.form_fields();
+        assert_eq!(fields[1..], [("prompt", "Bonjour".to_string()), ("temperature", "0.5".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_text_to_speech_to_file_streams_to_disk() {
+        use crate::audio::request::Audio;
+        use crate::common::mock_server;
+
+        let audio_bytes: String = "ID3-fake-mp3-frame-".repeat(1000);
+        let (base_url, server) = mock_server::serve(vec![(200, audio_bytes.clone())]).await;
+        let audio = Audio::with_url(base_url, "sk-test".to_string());
+
+        let path = std::env::temp_dir().join(format!("openai-tools-tts-{}.mp3", std::process::id()));
+        let mut progress = Vec::new();
+        let written = audio.text_to_speech_to_file("Hello", TtsOptions::default(), &path, |bytes| progress.push(bytes)).await.unwrap();
+
+        assert_eq!(written, audio_bytes.len() as u64);
+        assert_eq!(std::fs::read(&path).unwrap(), audio_bytes.as_bytes());
+        assert!(!progress.is_empty());
+        assert!(progress.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(progress.last().copied(), Some(written));
+        std::fs::remove_file(&path).ok();
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /v1/audio/speech HTTP/1.1");
+    }
 }
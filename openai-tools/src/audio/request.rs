@@ -27,14 +27,17 @@
 //! }
 //! ```
 
-use crate::audio::response::TranscriptionResponse;
+use crate::audio::response::{SubtitleCue, TranscriptionResponse};
 use crate::common::auth::AuthProvider;
-use crate::common::client::create_http_client;
-use crate::common::errors::{ErrorResponse, OpenAIToolError, Result};
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
 use request::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::time::Duration;
+use tokio::io::AsyncWriteExt;
 
 /// Default API path for Audio
 const AUDIO_PATH: &str = "audio";
@@ -315,6 +318,29 @@ pub struct TranscribeOptions {
     pub timestamp_granularities: Option<Vec<TimestampGranularity>>,
 }
 
+impl TranscribeOptions {
+    /// Returns the multipart text fields for these options; unset options are omitted.
+    pub(crate) fn form_fields(self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![("model", self.model.unwrap_or_default().as_str().to_string())];
+        if let Some(language) = self.language {
+            fields.push(("language", language));
+        }
+        if let Some(prompt) = self.prompt {
+            fields.push(("prompt", prompt));
+        }
+        if let Some(response_format) = self.response_format {
+            fields.push(("response_format", response_format.as_str().to_string()));
+        }
+        if let Some(temperature) = self.temperature {
+            fields.push(("temperature", temperature.to_string()));
+        }
+        for granularity in self.timestamp_granularities.unwrap_or_default() {
+            fields.push(("timestamp_granularities[]", granularity.as_str().to_string()));
+        }
+        fields
+    }
+}
+
 /// Options for audio translation.
 #[derive(Debug, Clone, Default)]
 pub struct TranslateOptions {
@@ -328,9 +354,27 @@ pub struct TranslateOptions {
     pub temperature: Option<f32>,
 }
 
+impl TranslateOptions {
+    /// Returns the multipart text fields for these options; unset options are omitted.
+    pub(crate) fn form_fields(self) -> Vec<(&'static str, String)> {
+        // whisper-1 is the only supported model for translation
+        let mut fields = vec![("model", self.model.unwrap_or(SttModel::Whisper1).as_str().to_string())];
+        if let Some(prompt) = self.prompt {
+            fields.push(("prompt", prompt));
+        }
+        if let Some(response_format) = self.response_format {
+            fields.push(("response_format", response_format.as_str().to_string()));
+        }
+        if let Some(temperature) = self.temperature {
+            fields.push(("temperature", temperature.to_string()));
+        }
+        fields
+    }
+}
+
 /// Request payload for TTS.
 #[derive(Debug, Clone, Serialize)]
-struct TtsRequest {
+pub(crate) struct TtsRequest {
     model: String,
     input: String,
     voice: String,
@@ -343,6 +387,28 @@ struct TtsRequest {
     instructions: Option<String>,
 }
 
+impl TtsRequest {
+    /// Builds the request payload, dropping `instructions` if the model does not support them.
+    pub(crate) fn new(text: &str, options: TtsOptions) -> Self {
+        let instructions = match options.instructions {
+            Some(_) if !options.model.supports_instructions() => {
+                tracing::warn!("Model '{}' does not support instructions parameter. Ignoring instructions.", options.model);
+                None
+            }
+            instructions => instructions,
+        };
+
+        Self {
+            model: options.model.as_str().to_string(),
+            input: text.to_string(),
+            voice: options.voice.as_str().to_string(),
+            response_format: Some(options.response_format.as_str().to_string()),
+            speed: options.speed,
+            instructions,
+        }
+    }
+}
+
 /// Client for interacting with the OpenAI Audio API.
 ///
 /// This struct provides methods for text-to-speech, transcription, and translation.
@@ -374,6 +440,8 @@ pub struct Audio {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl Audio {
@@ -397,36 +465,36 @@ impl Audio {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Audio client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Audio client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Audio client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Audio client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Audio client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -436,6 +504,12 @@ impl Audio {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -449,8 +523,8 @@ impl Audio {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("User-Agent", request::header::HeaderValue::from_static("openai-tools-rust"));
@@ -494,39 +568,143 @@ impl Audio {
     /// }
     /// ```
     pub async fn text_to_speech(&self, text: &str, options: TtsOptions) -> Result<Vec<u8>> {
+        let response = self.send_speech_request(text, options).await?;
+
+        let bytes = response.bytes().await.map_err(OpenAIToolError::from)?;
+
+        Ok(bytes.to_vec())
+    }
+
+    /// Converts text to speech, streaming the audio as it is generated.
+    ///
+    /// Unlike [`text_to_speech`](Self::text_to_speech), the audio is not buffered
+    /// in memory: chunks of the HTTP response body are yielded as they arrive, so
+    /// playback can begin before synthesis finishes. The chunks are encoded in
+    /// `options.response_format`; use [`AudioFormat::Opus`] or [`AudioFormat::Pcm`]
+    /// for the lowest latency.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert to speech (max 4096 characters)
+    /// * `options` - TTS options (model, voice, format, speed)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Stream)` - A stream of audio chunks
+    /// * `Err(OpenAIToolError)` - If the request fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use openai_tools::audio::request::{Audio, AudioFormat, TtsOptions};
+    /// use std::io::Write;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let audio = Audio::new()?;
+    ///
+    ///     let options = TtsOptions { response_format: AudioFormat::Opus, ..Default::default() };
+    ///     let mut stream = audio.text_to_speech_stream("A very long podcast script...", options).await?;
+    ///
+    ///     let mut file = std::fs::File::create("podcast.opus")?;
+    ///     while let Some(chunk) = stream.next().await {
+    ///         file.write_all(&chunk?)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn text_to_speech_stream(&self, text: &str, options: TtsOptions) -> Result<impl Stream<Item = Result<Bytes>> + Send> {
+        let response = self.send_speech_request(text, options).await?;
+
+        Ok(response.bytes_stream().map(|chunk| chunk.map_err(OpenAIToolError::from)))
+    }
+
+    /// Converts text to speech, streaming the audio directly into a file.
+    ///
+    /// Chunks are written to `path` as they arrive, so memory use stays flat
+    /// regardless of the length of the narration. `on_progress` is called after
+    /// each chunk with the total number of bytes written so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert to speech (max 4096 characters)
+    /// * `options` - TTS options (model, voice, format, speed)
+    /// * `path` - The file to write; it is created or truncated
+    /// * `on_progress` - Callback receiving the number of bytes written so far
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The total number of bytes written
+    /// * `Err(OpenAIToolError)` - If the request fails or the file cannot be written
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::audio::request::{Audio, TtsOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let audio = Audio::new()?;
+    ///
+    ///     let chapter = std::fs::read_to_string("chapter1.txt")?;
+    ///     let total = audio
+    ///         .text_to_speech_to_file(&chapter, TtsOptions::default(), "chapter1.mp3", |bytes| {
+    ///             println!("{} KiB written", bytes / 1024);
+    ///         })
+    ///         .await?;
+    ///     println!("Done: {} bytes", total);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn text_to_speech_to_file<P, F>(&self, text: &str, options: TtsOptions, path: P, mut on_progress: F) -> Result<u64>
+    where
+        P: AsRef<Path>,
+        F: FnMut(u64),
+    {
+        let stream = self.text_to_speech_stream(text, options).await?;
+        futures_util::pin_mut!(stream);
+
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(|e| OpenAIToolError::Error(format!("Failed to create audio file '{}': {}", path.display(), e)))?;
+
+        let mut written = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk).await.map_err(|e| OpenAIToolError::Error(format!("Failed to write audio file: {}", e)))?;
+            written += chunk.len() as u64;
+            on_progress(written);
+        }
+        file.flush().await.map_err(|e| OpenAIToolError::Error(format!("Failed to write audio file: {}", e)))?;
+
+        Ok(written)
+    }
+
+    /// Sends a speech request and returns the response once its status has been checked.
+    async fn send_speech_request(&self, text: &str, options: TtsOptions) -> Result<request::Response> {
         let (client, mut headers) = self.create_client()?;
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
 
-        // Check if instructions parameter is supported by the model
-        let instructions = if options.instructions.is_some() {
-            if options.model.supports_instructions() {
-                options.instructions
-            } else {
-                tracing::warn!("Model '{}' does not support instructions parameter. Ignoring instructions.", options.model);
-                None
-            }
-        } else {
-            None
-        };
-
-        let request_body = TtsRequest {
-            model: options.model.as_str().to_string(),
-            input: text.to_string(),
-            voice: options.voice.as_str().to_string(),
-            response_format: Some(options.response_format.as_str().to_string()),
-            speed: options.speed,
-            instructions,
-        };
+        let request_body = TtsRequest::new(text, options);
 
         let body = serde_json::to_string(&request_body).map_err(OpenAIToolError::SerdeJsonError)?;
 
         let url = format!("{}/speech", self.auth.endpoint(AUDIO_PATH));
 
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
-        let bytes = response.bytes().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        if !status.is_success() {
+            let content = response.text().await.map_err(OpenAIToolError::from)?;
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
 
-        Ok(bytes.to_vec())
+        Ok(response)
     }
 
     /// Transcribes audio from a file path.
@@ -604,6 +782,57 @@ impl Audio {
     /// }
     /// ```
     pub async fn transcribe_bytes(&self, audio_data: &[u8], filename: &str, options: TranscribeOptions) -> Result<TranscriptionResponse> {
+        let content = self.send_transcription(audio_data, filename, options).await?;
+        serde_json::from_str::<TranscriptionResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
+    }
+
+    /// Transcribes an audio file into timed subtitle cues.
+    ///
+    /// Requests SRT output (or VTT, if `options.response_format` is
+    /// [`TranscriptionFormat::Vtt`]) and parses it into [`SubtitleCue`]s.
+    /// Any other response format in `options` is replaced with SRT.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_path` - Path to the audio file
+    /// * `options` - Transcription options
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<SubtitleCue>)` - The subtitle cues in order
+    /// * `Err(OpenAIToolError)` - If the request fails or the subtitles cannot be parsed
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::audio::request::{Audio, TranscribeOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let audio = Audio::new()?;
+    ///
+    ///     let cues = audio.transcribe_subtitles("audio.mp3", TranscribeOptions::default()).await?;
+    ///     for cue in cues {
+    ///         println!("[{:?} - {:?}] {}", cue.start, cue.end, cue.text);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn transcribe_subtitles(&self, audio_path: &str, mut options: TranscribeOptions) -> Result<Vec<SubtitleCue>> {
+        let audio_content = tokio::fs::read(audio_path).await.map_err(|e| OpenAIToolError::Error(format!("Failed to read audio file: {}", e)))?;
+        let filename = Path::new(audio_path).file_name().and_then(|n| n.to_str()).unwrap_or("audio.mp3").to_string();
+
+        if options.response_format != Some(TranscriptionFormat::Vtt) {
+            options.response_format = Some(TranscriptionFormat::Srt);
+        }
+
+        let content = self.send_transcription(&audio_content, &filename, options).await?;
+        SubtitleCue::parse(&content)
+    }
+
+    /// Sends a transcription request and returns the raw response body.
+    async fn send_transcription(&self, audio_data: &[u8], filename: &str, options: TranscribeOptions) -> Result<String> {
         let (client, headers) = self.create_client()?;
 
         let audio_part = Part::bytes(audio_data.to_vec())
@@ -613,48 +842,27 @@ impl Audio {
 
         let mut form = Form::new().part("file", audio_part);
 
-        // Add model
-        let model = options.model.unwrap_or_default();
-        form = form.text("model", model.as_str().to_string());
-
-        // Add optional parameters
-        if let Some(language) = options.language {
-            form = form.text("language", language);
-        }
-        if let Some(prompt) = options.prompt {
-            form = form.text("prompt", prompt);
-        }
-        if let Some(response_format) = options.response_format {
-            form = form.text("response_format", response_format.as_str().to_string());
-        }
-        if let Some(temperature) = options.temperature {
-            form = form.text("temperature", temperature.to_string());
-        }
-        if let Some(granularities) = options.timestamp_granularities {
-            for g in granularities {
-                form = form.text("timestamp_granularities[]", g.as_str().to_string());
-            }
+        for (name, value) in options.form_fields() {
+            form = form.text(name, value);
         }
 
         let url = format!("{}/transcriptions", self.auth.endpoint(AUDIO_PATH));
 
-        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
-        serde_json::from_str::<TranscriptionResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
+        Ok(content)
     }
 
     /// Translates audio to English text.
@@ -717,37 +925,24 @@ impl Audio {
 
         let mut form = Form::new().part("file", audio_part);
 
-        // Add model (whisper-1 is the only supported model for translation)
-        let model = options.model.unwrap_or(SttModel::Whisper1);
-        form = form.text("model", model.as_str().to_string());
-
-        // Add optional parameters
-        if let Some(prompt) = options.prompt {
-            form = form.text("prompt", prompt);
-        }
-        if let Some(response_format) = options.response_format {
-            form = form.text("response_format", response_format.as_str().to_string());
-        }
-        if let Some(temperature) = options.temperature {
-            form = form.text("temperature", temperature.to_string());
+        for (name, value) in options.form_fields() {
+            form = form.text(name, value);
         }
 
         let url = format!("{}/translations", self.auth.endpoint(AUDIO_PATH));
 
-        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<TranscriptionResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -2,7 +2,9 @@
 //!
 //! This module defines the response structures for the OpenAI Audio API.
 
+use crate::common::errors::{OpenAIToolError, Result};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Response structure from transcription/translation endpoints.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,3 +64,76 @@ pub struct Segment {
     /// No speech probability
     pub no_speech_prob: f64,
 }
+
+/// A single cue of an SRT or WebVTT subtitle file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleCue {
+    /// 1-based position of the cue (the SRT counter, or the cue's position in a VTT file)
+    pub index: usize,
+    /// Time at which the cue appears
+    pub start: Duration,
+    /// Time at which the cue disappears
+    pub end: Duration,
+    /// Cue text; multi-line cues are joined with `\n`
+    pub text: String,
+}
+
+impl SubtitleCue {
+    /// Parses SRT or WebVTT subtitle content into cues.
+    ///
+    /// The format is detected from the timestamps, so both `00:00:01,000` (SRT)
+    /// and `00:00:01.000` / `00:01.000` (VTT) are accepted. The `WEBVTT` header,
+    /// `NOTE`/`STYLE`/`REGION` blocks, and cue settings after the end timestamp
+    /// are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::audio::response::SubtitleCue;
+    /// use std::time::Duration;
+    ///
+    /// let srt = "1\n00:00:00,000 --> 00:00:01,500\nHello there.\n";
+    /// let cues = SubtitleCue::parse(srt).unwrap();
+    /// assert_eq!(cues[0].end, Duration::from_millis(1500));
+    /// assert_eq!(cues[0].text, "Hello there.");
+    /// ```
+    pub fn parse(content: &str) -> Result<Vec<SubtitleCue>> {
+        let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+
+        let mut cues = Vec::new();
+        for block in content.split("\n\n") {
+            let lines: Vec<&str> = block.lines().filter(|line| !line.trim().is_empty()).collect();
+            let Some(timing_pos) = lines.iter().position(|line| line.contains("-->")) else {
+                continue;
+            };
+
+            let (start, end) = parse_timing_line(lines[timing_pos])?;
+            let index = lines[..timing_pos].last().and_then(|id| id.trim().parse::<usize>().ok()).unwrap_or(cues.len() + 1);
+            let text = lines[timing_pos + 1..].join("\n");
+            cues.push(SubtitleCue { index, start, end, text });
+        }
+        Ok(cues)
+    }
+}
+
+/// Parses a `start --> end [settings]` timing line.
+fn parse_timing_line(line: &str) -> Result<(Duration, Duration)> {
+    let (start, rest) = line.split_once("-->").ok_or_else(|| OpenAIToolError::Error(format!("Invalid subtitle timing line: {}", line)))?;
+    let end = rest.split_whitespace().next().unwrap_or_default();
+    Ok((parse_timestamp(start.trim())?, parse_timestamp(end)?))
+}
+
+/// Parses an `hh:mm:ss,mmm`, `hh:mm:ss.mmm`, or `mm:ss.mmm` timestamp.
+fn parse_timestamp(value: &str) -> Result<Duration> {
+    let invalid = || OpenAIToolError::Error(format!("Invalid subtitle timestamp: {}", value));
+
+    let (clock, millis) = value.split_once([',', '.']).ok_or_else(invalid)?;
+    let millis: u64 = millis.parse().map_err(|_| invalid())?;
+    let fields = clock.split(':').map(|field| field.parse::<u64>().map_err(|_| invalid())).collect::<Result<Vec<_>>>()?;
+    let seconds = match fields.as_slice() {
+        [h, m, s] => h * 3600 + m * 60 + s,
+        [m, s] => m * 60 + s,
+        _ => return Err(invalid()),
+    };
+    Ok(Duration::from_millis(seconds * 1000 + millis))
+}
@@ -28,6 +28,9 @@
 //! 4. Poll for completion or wait for webhook
 //! 5. Download results from the output file
 //!
+//! Steps 4 and 5 can be combined with [`Batches::wait_and_fetch_results`], which polls
+//! until the batch finishes and returns the parsed output lines.
+//!
 //! # Quick Start
 //!
 //! ```rust,no_run
@@ -109,8 +112,10 @@
 pub mod request;
 pub mod response;
 
-pub use request::{BatchEndpoint, Batches, CompletionWindow, CreateBatchRequest};
-pub use response::{BatchError, BatchErrors, BatchListResponse, BatchObject, BatchStatus, RequestCounts};
+pub use request::{BatchEndpoint, BatchInputBuilder, BatchInputLine, Batches, CompletionWindow, CreateBatchRequest};
+pub use response::{
+    BatchError, BatchErrors, BatchListResponse, BatchObject, BatchResultError, BatchResultLine, BatchResultResponse, BatchStatus, RequestCounts,
+};
 
 #[cfg(test)]
 mod tests {
@@ -216,4 +221,122 @@ mod tests {
         assert_eq!(errors.data[0].code, "invalid_request");
         assert_eq!(errors.data[0].line, Some(5));
     }
+
+    #[test]
+    fn test_batch_status_is_terminal() {
+        assert!(!BatchStatus::Validating.is_terminal());
+        assert!(!BatchStatus::InProgress.is_terminal());
+        assert!(!BatchStatus::Finalizing.is_terminal());
+        assert!(!BatchStatus::Cancelling.is_terminal());
+        assert!(BatchStatus::Completed.is_terminal());
+        assert!(BatchStatus::Failed.is_terminal());
+        assert!(BatchStatus::Expired.is_terminal());
+        assert!(BatchStatus::Cancelled.is_terminal());
+    }
+
+    #[test]
+    fn test_batch_result_lines_parsing() {
+        let jsonl = concat!(
+            r#"{"id":"batch_req_1","custom_id":"request-1","response":{"status_code":200,"request_id":"req_1","body":{"object":"chat.completion","model":"gpt-4o-mini"}},"error":null}"#,
+            "\n\n",
+            r#"{"id":"batch_req_2","custom_id":"request-2","response":null,"error":{"code":"invalid_request","message":"Bad request"}}"#,
+            "\n",
+        );
+
+        let lines = BatchResultLine::parse_jsonl(jsonl).unwrap();
+        assert_eq!(lines.len(), 2);
+
+        assert_eq!(lines[0].custom_id, "request-1");
+        assert!(lines[0].is_success());
+        let body: serde_json::Value = lines[0].body_as().unwrap();
+        assert_eq!(body["model"], "gpt-4o-mini");
+
+        assert_eq!(lines[1].custom_id, "request-2");
+        assert!(!lines[1].is_success());
+        assert_eq!(lines[1].error.as_ref().unwrap().message, "Bad request");
+        assert!(lines[1].body_as::<serde_json::Value>().is_err());
+    }
+
+    #[test]
+    fn test_batch_input_builder_to_jsonl() {
+        use crate::chat::request::ChatCompletion;
+        use crate::common::{message::Message, models::ChatModel, role::Role};
+
+        let mut builder = BatchInputBuilder::new();
+        for (id, question) in [("request-1", "Hello!"), ("request-2", "How are you?")] {
+            let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+            chat.messages(vec![Message::from_string(Role::User, question)]);
+            builder.add_chat_request(id, &chat).unwrap();
+        }
+
+        assert_eq!(builder.len(), 2);
+        assert_eq!(builder.endpoint(), Some(BatchEndpoint::ChatCompletions));
+
+        let jsonl = builder.to_jsonl().unwrap();
+        let lines: Vec<serde_json::Value> = jsonl.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["custom_id"], "request-1");
+        assert_eq!(lines[0]["method"], "POST");
+        assert_eq!(lines[0]["url"], "/v1/chat/completions");
+        assert_eq!(lines[0]["body"]["model"], "gpt-4o-mini");
+        assert_eq!(lines[1]["body"]["messages"][0]["content"], "How are you?");
+    }
+
+    #[test]
+    fn test_batch_input_builder_chat_line_matches_request_body() {
+        use crate::chat::request::ChatCompletion;
+        use crate::common::{errors::OpenAIToolError, message::Message, models::ChatModel, role::Role};
+
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        let mut builder = BatchInputBuilder::new();
+        assert!(matches!(builder.add_chat_request("request-1", &chat), Err(OpenAIToolError::MissingField("messages"))));
+        assert!(builder.is_empty());
+
+        chat.messages(vec![Message::from_string(Role::User, "Hello!")]).temperature(0.5);
+        builder.add_chat_request("request-1", &chat).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(builder.lines()[0].body, expected);
+
+        // Parameters the model rejects fail here instead of in the batch results
+        chat.model(ChatModel::O1);
+        assert!(matches!(builder.add_chat_request("request-2", &chat), Err(OpenAIToolError::UnsupportedParameter { .. })));
+        assert_eq!(builder.len(), 1);
+    }
+
+    #[test]
+    fn test_batch_input_builder_embedding_request() {
+        use crate::common::auth::{AuthProvider, OpenAIAuth};
+        use crate::embedding::request::Embedding;
+
+        let mut embedding = Embedding::with_auth(AuthProvider::OpenAI(OpenAIAuth::new("test-key")));
+        embedding.input_text("Hello, world!");
+
+        let mut builder = BatchInputBuilder::new();
+        builder.add_embedding_request("embed-1", &embedding).unwrap();
+
+        let line = &builder.lines()[0];
+        assert_eq!(line.url, "/v1/embeddings");
+        assert_eq!(line.body["input"], "Hello, world!");
+    }
+
+    #[test]
+    fn test_batch_input_builder_validation() {
+        use crate::chat::request::ChatCompletion;
+        use crate::common::auth::{AuthProvider, OpenAIAuth};
+        use crate::common::{message::Message, models::ChatModel, role::Role};
+        use crate::embedding::request::Embedding;
+
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::User, "Hello!")]);
+        let mut builder = BatchInputBuilder::new();
+        builder.add_chat_request("request-1", &chat).unwrap();
+
+        // Duplicate custom_id
+        assert!(builder.add_chat_request("request-1", &chat).is_err());
+
+        // Mixed endpoints
+        let embedding = Embedding::with_auth(AuthProvider::OpenAI(OpenAIAuth::new("test-key")));
+        assert!(builder.add_embedding_request("embed-1", &embedding).is_err());
+        assert_eq!(builder.len(), 1);
+    }
 }
@@ -9,6 +9,8 @@
 //! - **Retrieve Batch**: Get the status and details of a batch job
 //! - **List Batches**: List all batch jobs
 //! - **Cancel Batch**: Cancel an in-progress batch job
+//! - **Wait and Fetch Results**: Poll a batch until it finishes and download its results
+//! - **Build Input Files**: Generate batch input JSONL from `ChatCompletion` and `Embedding` requests
 //!
 //! # Quick Start
 //!
@@ -29,12 +31,17 @@
 //! }
 //! ```
 
-use crate::batch::response::{BatchListResponse, BatchObject};
+use crate::batch::response::{BatchListResponse, BatchObject, BatchResultLine, BatchStatus};
+use crate::chat::request::ChatCompletion;
 use crate::common::auth::AuthProvider;
-use crate::common::client::create_http_client;
-use crate::common::errors::{OpenAIToolError, Result};
-use serde::Serialize;
-use std::collections::HashMap;
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
+use crate::common::metadata;
+use crate::embedding::request::Embedding;
+use crate::files::request::{FilePurpose, Files};
+use crate::files::response::File;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 /// Default API path for Batches
@@ -132,13 +139,177 @@ impl CreateBatchRequest {
     ///
     /// # Arguments
     ///
-    /// * `metadata` - Key-value pairs to attach to the batch
-    pub fn with_metadata(mut self, metadata: HashMap<String, String>) -> Self {
-        self.metadata = Some(metadata);
+    /// * `metadata` - Key-value pairs to attach to the batch, as a
+    ///   [`Metadata`](crate::common::metadata::Metadata) or a `HashMap`
+    pub fn with_metadata(mut self, metadata: impl Into<HashMap<String, String>>) -> Self {
+        self.metadata = Some(metadata.into());
         self
     }
 }
 
+/// A single request line in a batch input file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchInputLine {
+    /// A unique ID used to match the request with its result.
+    pub custom_id: String,
+
+    /// The HTTP method (always "POST").
+    pub method: String,
+
+    /// The API endpoint path (e.g., "/v1/chat/completions").
+    pub url: String,
+
+    /// The request body.
+    pub body: serde_json::Value,
+}
+
+/// Builder for batch input JSONL files.
+///
+/// Converts configured `ChatCompletion` and `Embedding` requests into batch
+/// input lines, so the request types used for synchronous calls can be reused.
+/// All requests in a batch must target the same endpoint, and every `custom_id`
+/// must be unique.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use openai_tools::batch::request::{BatchInputBuilder, Batches, CreateBatchRequest};
+/// use openai_tools::chat::request::ChatCompletion;
+/// use openai_tools::common::message::Message;
+/// use openai_tools::common::role::Role;
+/// use openai_tools::files::request::Files;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = BatchInputBuilder::new();
+///     for (i, question) in ["What is Rust?", "What is Tokio?"].iter().enumerate() {
+///         let mut chat = ChatCompletion::new();
+///         chat.messages(vec![Message::from_string(Role::User, *question)]);
+///         builder.add_chat_request(format!("request-{}", i), &chat)?;
+///     }
+///
+///     let file = builder.upload(&Files::new()?).await?;
+///     let request = CreateBatchRequest::new(file.id, builder.endpoint().unwrap());
+///     let batch = Batches::new()?.create(request).await?;
+///     println!("Created batch: {}", batch.id);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BatchInputBuilder {
+    lines: Vec<BatchInputLine>,
+    endpoint: Option<BatchEndpoint>,
+    custom_ids: HashSet<String>,
+}
+
+impl BatchInputBuilder {
+    /// Creates an empty batch input builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a Chat Completions request (`/v1/chat/completions`).
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_id` - A unique ID for the request
+    /// * `chat` - The configured chat completion request
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&mut Self)` - The builder for method chaining
+    /// * `Err(OpenAIToolError)` - If the request is invalid (see
+    ///   [`ChatCompletion::build_request_json`]), the ID is a duplicate or the batch
+    ///   targets another endpoint
+    pub fn add_chat_request(&mut self, custom_id: impl Into<String>, chat: &ChatCompletion) -> Result<&mut Self> {
+        // The same body `ChatCompletion::chat` would send, validated for the model
+        let body = serde_json::to_value(chat.prepare_request_body()?)?;
+        self.add_line(custom_id.into(), BatchEndpoint::ChatCompletions, body)
+    }
+
+    /// Adds an Embeddings request (`/v1/embeddings`).
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_id` - A unique ID for the request
+    /// * `embedding` - The configured embedding request
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&mut Self)` - The builder for method chaining
+    /// * `Err(OpenAIToolError)` - If the ID is a duplicate or the batch targets another endpoint
+    pub fn add_embedding_request(&mut self, custom_id: impl Into<String>, embedding: &Embedding) -> Result<&mut Self> {
+        let body = serde_json::to_value(&embedding.body)?;
+        self.add_line(custom_id.into(), BatchEndpoint::Embeddings, body)
+    }
+
+    fn add_line(&mut self, custom_id: String, endpoint: BatchEndpoint, body: serde_json::Value) -> Result<&mut Self> {
+        if let Some(existing) = self.endpoint {
+            if existing != endpoint {
+                return Err(OpenAIToolError::Error(format!(
+                    "All requests in a batch must use the same endpoint (expected {}, got {})",
+                    existing.as_str(),
+                    endpoint.as_str()
+                )));
+            }
+        }
+        if !self.custom_ids.insert(custom_id.clone()) {
+            return Err(OpenAIToolError::Error(format!("Duplicate custom_id in batch: {}", custom_id)));
+        }
+        self.endpoint = Some(endpoint);
+        self.lines.push(BatchInputLine { custom_id, method: "POST".to_string(), url: endpoint.as_str().to_string(), body });
+        Ok(self)
+    }
+
+    /// Returns the endpoint targeted by the requests, or `None` if the builder is empty.
+    pub fn endpoint(&self) -> Option<BatchEndpoint> {
+        self.endpoint
+    }
+
+    /// Returns the request lines added so far.
+    pub fn lines(&self) -> &[BatchInputLine] {
+        &self.lines
+    }
+
+    /// Returns the number of requests.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns `true` if no requests have been added.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Serializes the requests into JSONL, one request per line.
+    pub fn to_jsonl(&self) -> Result<String> {
+        let mut jsonl = String::new();
+        for line in &self.lines {
+            jsonl.push_str(&serde_json::to_string(line)?);
+            jsonl.push('\n');
+        }
+        Ok(jsonl)
+    }
+
+    /// Uploads the JSONL input file with purpose `batch`.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - The Files client used for the upload
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(File)` - The uploaded file, whose ID can be passed to `CreateBatchRequest::new`
+    /// * `Err(OpenAIToolError)` - If the builder is empty or the upload fails
+    pub async fn upload(&self, files: &Files) -> Result<File> {
+        if self.is_empty() {
+            return Err(OpenAIToolError::Error("Batch input is empty.".into()));
+        }
+        let jsonl = self.to_jsonl()?;
+        files.upload_bytes(jsonl.as_bytes(), "batch_input.jsonl", FilePurpose::Batch).await
+    }
+}
+
 /// Client for interacting with the OpenAI Batch API.
 ///
 /// This struct provides methods to create, list, retrieve, and cancel batch jobs.
@@ -166,6 +337,8 @@ pub struct Batches {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl Batches {
@@ -189,36 +362,36 @@ impl Batches {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Batches client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Batches client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Batches client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Batches client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Batches client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -228,6 +401,12 @@ impl Batches {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -241,8 +420,8 @@ impl Batches {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
@@ -259,6 +438,8 @@ impl Batches {
     /// # Returns
     ///
     /// * `Ok(BatchObject)` - The created batch object
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the metadata exceeds the API limits
+    ///   (see [`Metadata`](crate::common::metadata::Metadata))
     /// * `Err(OpenAIToolError)` - If the request fails
     ///
     /// # Example
@@ -283,19 +464,26 @@ impl Batches {
     /// }
     /// ```
     pub async fn create(&self, request: CreateBatchRequest) -> Result<BatchObject> {
+        request.metadata.as_ref().map(metadata::validate_map).transpose()?;
         let (client, headers) = self.create_client()?;
 
         let body = serde_json::to_string(&request).map_err(OpenAIToolError::SerdeJsonError)?;
 
         let url = self.auth.endpoint(BATCHES_PATH);
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<BatchObject>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
@@ -331,17 +519,92 @@ impl Batches {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(BATCHES_PATH), batch_id);
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<BatchObject>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
+    /// Waits for a batch job to finish and returns its parsed results.
+    ///
+    /// Polls `retrieve` every `poll_interval` until the batch reaches a terminal
+    /// status. For completed (or expired/cancelled, with partial results) batches,
+    /// the output file and error file are downloaded via the Files API and parsed
+    /// into one [`BatchResultLine`] per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_id` - The ID of the batch to wait for
+    /// * `poll_interval` - The interval between status checks
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<BatchResultLine>)` - The results, output file lines first, then error file lines
+    /// * `Err(OpenAIToolError)` - If the batch failed or a request fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use openai_tools::batch::request::Batches;
+    /// use openai_tools::chat::response::Response;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let batches = Batches::new()?;
+    ///
+    ///     let results = batches.wait_and_fetch_results("batch_abc123", Duration::from_secs(60)).await?;
+    ///     for line in &results {
+    ///         if line.is_success() {
+    ///             let completion: Response = line.body_as()?;
+    ///             println!("{}: {:?}", line.custom_id, completion.choices[0].message.content);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_and_fetch_results(&self, batch_id: &str, poll_interval: Duration) -> Result<Vec<BatchResultLine>> {
+        let batch = loop {
+            let batch = self.retrieve(batch_id).await?;
+            if batch.status.is_terminal() {
+                break batch;
+            }
+            tokio::time::sleep(poll_interval).await;
+        };
+
+        if batch.status == BatchStatus::Failed {
+            let reason = batch
+                .errors
+                .as_ref()
+                .map(|errors| errors.data.iter().map(|e| format!("{}: {}", e.code, e.message)).collect::<Vec<_>>().join("; "))
+                .unwrap_or_default();
+            return Err(OpenAIToolError::Error(format!("Batch {} failed: {}", batch_id, reason)));
+        }
+
+        let mut files = Files::with_auth(self.auth.clone());
+        if let Some(timeout) = self.timeout {
+            files.timeout(timeout);
+        }
+
+        let mut results = Vec::new();
+        for file_id in [&batch.output_file_id, &batch.error_file_id].into_iter().flatten() {
+            let content = files.content(file_id).await?;
+            results.extend(BatchResultLine::parse_jsonl(&String::from_utf8_lossy(&content))?);
+        }
+        Ok(results)
+    }
+
     /// Cancels an in-progress batch job.
     ///
     /// The batch will transition to "cancelling" and eventually "cancelled".
@@ -373,14 +636,20 @@ impl Batches {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}/cancel", self.auth.endpoint(BATCHES_PATH), batch_id);
 
-        let response = client.post(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<BatchObject>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
@@ -442,14 +711,20 @@ impl Batches {
             url.push_str(&params.join("&"));
         }
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<BatchListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 }
@@ -2,7 +2,9 @@
 //!
 //! This module defines the response types for the OpenAI Batch API.
 
-use serde::{Deserialize, Serialize};
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::pagination::{impl_paginated, Page};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// The status of a batch job.
@@ -27,6 +29,14 @@ pub enum BatchStatus {
     Cancelled,
 }
 
+impl BatchStatus {
+    /// Returns `true` if the batch will not change status anymore
+    /// (`Completed`, `Failed`, `Expired`, or `Cancelled`).
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Expired | Self::Cancelled)
+    }
+}
+
 /// Counts of requests in different states within the batch.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestCounts {
@@ -145,3 +155,87 @@ pub struct BatchListResponse {
     /// Whether there are more batches to retrieve.
     pub has_more: bool,
 }
+
+impl From<BatchListResponse> for Page<BatchObject> {
+    fn from(response: BatchListResponse) -> Self {
+        Page { data: response.data, first_id: response.first_id, last_id: response.last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(BatchListResponse, BatchObject);
+
+/// The response recorded for a single request in a batch output file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResultResponse {
+    /// The HTTP status code of the response.
+    pub status_code: u16,
+
+    /// The unique ID of the API request.
+    pub request_id: Option<String>,
+
+    /// The JSON body of the response.
+    pub body: serde_json::Value,
+}
+
+/// An error recorded for a single request in a batch output or error file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResultError {
+    /// A machine-readable error code.
+    pub code: Option<String>,
+
+    /// A human-readable error message.
+    pub message: String,
+}
+
+/// A single line of a batch output (or error) file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResultLine {
+    /// The ID of the batch request.
+    pub id: Option<String>,
+
+    /// The `custom_id` given to the request in the input file.
+    pub custom_id: String,
+
+    /// The response to the request, if one was received.
+    pub response: Option<BatchResultResponse>,
+
+    /// The error, if the request failed without a response.
+    pub error: Option<BatchResultError>,
+}
+
+impl BatchResultLine {
+    /// Returns `true` if the request received a 2xx response.
+    pub fn is_success(&self) -> bool {
+        self.response.as_ref().map(|r| (200..300).contains(&r.status_code)).unwrap_or(false)
+    }
+
+    /// Deserializes the response body into a concrete type.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::batch::response::BatchResultLine;
+    /// use openai_tools::chat::response::Response;
+    ///
+    /// # fn example(line: &BatchResultLine) -> Result<(), Box<dyn std::error::Error>> {
+    /// let completion: Response = line.body_as()?;
+    /// println!("{}: {:?}", line.custom_id, completion.choices[0].message.content);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_as<T: DeserializeOwned>(&self) -> Result<T> {
+        let response = self.response.as_ref().ok_or_else(|| OpenAIToolError::Error(format!("No response for request '{}'", self.custom_id)))?;
+        serde_json::from_value(response.body.clone()).map_err(OpenAIToolError::SerdeJsonError)
+    }
+
+    /// Parses the contents of a batch output (or error) JSONL file.
+    ///
+    /// Blank lines are skipped.
+    pub fn parse_jsonl(content: &str) -> Result<Vec<BatchResultLine>> {
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(OpenAIToolError::SerdeJsonError))
+            .collect()
+    }
+}
@@ -0,0 +1,102 @@
+//! Response caching for Chat Completions.
+//!
+//! Repeated identical requests (e.g. deterministic prompts with `temperature(0.0)`
+//! and a fixed `seed`) can be served from a cache instead of calling the API again.
+//! A cache is attached with [`ChatCompletion::with_cache`](crate::chat::request::ChatCompletion::with_cache);
+//! the key is a hash of the endpoint and the request body serialized with sorted
+//! object keys, so it does not depend on the iteration order of map fields.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use openai_tools::chat::cache::InMemoryResponseCache;
+//! use openai_tools::chat::request::ChatCompletion;
+//! use openai_tools::common::message::Message;
+//! use openai_tools::common::role::Role;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut chat = ChatCompletion::new();
+//! chat.with_cache(InMemoryResponseCache::new())
+//!     .temperature(0.0)
+//!     .messages(vec![Message::from_string(Role::User, "Hello!")]);
+//!
+//! let first = chat.chat().await?; // calls the API
+//! let second = chat.chat().await?; // served from the cache
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::chat::response::Response;
+use crate::common::errors::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Storage for chat responses keyed by request hash.
+///
+/// Implementations must be thread-safe; `get` and `put` take `&self`, so
+/// use interior mutability for the backing store.
+pub trait ResponseCache: Send + Sync + std::fmt::Debug {
+    /// Returns the cached response for `key`, if any.
+    fn get(&self, key: &str) -> Option<Response>;
+
+    /// Stores `response` under `key`.
+    fn put(&self, key: &str, response: &Response);
+}
+
+/// In-memory [`ResponseCache`] backed by a `HashMap`.
+///
+/// Entries live as long as the cache and are never evicted.
+#[derive(Debug, Default)]
+pub struct InMemoryResponseCache {
+    entries: Mutex<HashMap<String, Response>>,
+}
+
+impl InMemoryResponseCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached responses.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns `true` if no responses are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+impl ResponseCache for InMemoryResponseCache {
+    fn get(&self, key: &str) -> Option<Response> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).get(key).cloned()
+    }
+
+    fn put(&self, key: &str, response: &Response) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).insert(key.to_string(), response.clone());
+    }
+}
+
+/// Computes the cache key for a request as a hex-encoded 64-bit FNV-1a hash.
+///
+/// FNV-1a is used instead of `DefaultHasher` so keys stay stable across Rust
+/// releases, which matters for caches persisted outside the process. The body
+/// is hashed through a `serde_json::Value`, whose objects keep their keys
+/// sorted, so `HashMap` fields such as `metadata` and `logit_bias` hash the
+/// same no matter their iteration order.
+pub(crate) fn cache_key<T: Serialize>(endpoint: &str, body: &T) -> Result<String> {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let body = serde_json::to_value(body)?.to_string();
+    let hash =
+        endpoint.bytes().chain(std::iter::once(b'\n')).chain(body.bytes()).fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME));
+    Ok(format!("{:016x}", hash))
+}
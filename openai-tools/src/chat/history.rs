@@ -0,0 +1,302 @@
+//! Conversation history management with automatic trimming.
+//!
+//! [`HistoryManager`] keeps the messages of a long-running conversation within a
+//! token budget. When a new message pushes the history over the budget, a
+//! [`HistoryStrategy`] shrinks it:
+//!
+//! - [`TrimOldest`] drops the oldest messages
+//! - [`SummarizeOldest`] replaces the oldest messages with a summary generated by the API
+//!
+//! Leading system messages are never evicted, and the latest message is always kept.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use openai_tools::chat::history::HistoryManager;
+//! use openai_tools::chat::request::ChatCompletion;
+//! use openai_tools::common::message::Message;
+//! use openai_tools::common::models::ChatModel;
+//! use openai_tools::common::role::Role;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut history = HistoryManager::new(ChatModel::Gpt4oMini, 8_000);
+//! history.add_message(Message::from_string(Role::System, "You are a helpful assistant.")).await?;
+//! history.add_message(Message::from_string(Role::User, "Hello!")).await?;
+//!
+//! let mut chat = ChatCompletion::new();
+//! let response = chat.model(ChatModel::Gpt4oMini).messages(history.messages().to_vec()).chat().await?;
+//! history.add_message(response.choices[0].message.clone()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::chat::request::ChatCompletion;
+use crate::common::errors::Result;
+use crate::common::message::Message;
+use crate::common::models::ChatModel;
+use crate::common::pricing::{message_token_counts, TOKENS_PER_REPLY};
+use crate::common::role::Role;
+use std::future::Future;
+use std::ops::Range;
+
+/// Default number of tokens reserved for the summary written by [`SummarizeOldest`].
+pub const DEFAULT_SUMMARY_TOKENS: usize = 256;
+
+/// Instructions sent with the evicted messages when asking for a summary.
+const SUMMARY_INSTRUCTIONS: &str = "Summarize the following conversation concisely. \
+     Preserve names, facts, decisions and open questions needed to continue it.";
+
+/// Prefix of the system message holding a summary of evicted messages.
+const SUMMARY_PREFIX: &str = "Summary of the earlier conversation:\n";
+
+/// Strategy for shrinking a conversation history that exceeds its token budget.
+///
+/// Implement this trait to plug a custom windowing strategy into [`HistoryManager`].
+pub trait HistoryStrategy {
+    /// Shrinks `messages` so that their estimated prompt size fits in `max_tokens`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model whose tokenizer is used to count tokens
+    /// * `messages` - The full history, including the newest message
+    /// * `max_tokens` - The token budget
+    fn fit(&self, model: &ChatModel, messages: Vec<Message>, max_tokens: usize) -> impl Future<Output = Result<Vec<Message>>> + Send;
+}
+
+/// Drops the oldest messages until the history fits the budget.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrimOldest;
+
+impl HistoryStrategy for TrimOldest {
+    fn fit(&self, model: &ChatModel, mut messages: Vec<Message>, max_tokens: usize) -> impl Future<Output = Result<Vec<Message>>> + Send {
+        let range = eviction_range(model, &messages, max_tokens);
+        messages.drain(range);
+        async move { Ok(messages) }
+    }
+}
+
+/// Replaces the oldest messages with a summary generated by the API.
+///
+/// The summary is stored as a system message right after the leading system
+/// messages. When the history overflows again, the previous summary is folded
+/// into the new one.
+#[derive(Debug, Clone)]
+pub struct SummarizeOldest {
+    /// Client used to request summaries
+    chat: ChatCompletion,
+    /// Tokens reserved for the summary
+    max_summary_tokens: usize,
+}
+
+impl SummarizeOldest {
+    /// Creates a strategy that summarizes with the given client.
+    ///
+    /// The client's model, authentication and other settings are used for the
+    /// summary request; its messages are replaced.
+    pub fn new(chat: ChatCompletion) -> Self {
+        Self { chat, max_summary_tokens: DEFAULT_SUMMARY_TOKENS }
+    }
+
+    /// Sets the number of tokens reserved for the summary (default: [`DEFAULT_SUMMARY_TOKENS`]).
+    pub fn max_summary_tokens(&mut self, max_summary_tokens: usize) -> &mut Self {
+        self.max_summary_tokens = max_summary_tokens;
+        self
+    }
+}
+
+impl HistoryStrategy for SummarizeOldest {
+    fn fit(&self, model: &ChatModel, mut messages: Vec<Message>, max_tokens: usize) -> impl Future<Output = Result<Vec<Message>>> + Send {
+        let range = eviction_range(model, &messages, max_tokens.saturating_sub(self.max_summary_tokens));
+        let mut chat = self.chat.clone();
+        let max_summary_tokens = self.max_summary_tokens as u64;
+
+        async move {
+            if range.is_empty() {
+                return Ok(messages);
+            }
+            let start = range.start;
+            let transcript =
+                messages.drain(range).map(|message| format!("{}: {}", message.role.as_str(), message_text(&message))).collect::<Vec<_>>().join("\n");
+
+            let response = chat
+                .messages(vec![Message::from_string(Role::System, SUMMARY_INSTRUCTIONS), Message::from_string(Role::User, transcript)])
+                .max_completion_tokens(max_summary_tokens)
+                .chat()
+                .await?;
+            let summary = response.texts().into_iter().next().unwrap_or_default();
+            messages.insert(start, Message::from_string(Role::System, format!("{}{}", SUMMARY_PREFIX, summary)));
+            Ok(messages)
+        }
+    }
+}
+
+/// Conversation history kept within a token budget.
+///
+/// Tokens are estimated locally with the model's tokenizer, in the same way as
+/// [`RequestPreview`](crate::common::pricing::RequestPreview).
+#[derive(Debug, Clone)]
+pub struct HistoryManager<S: HistoryStrategy = TrimOldest> {
+    /// Model whose tokenizer is used to count tokens
+    model: ChatModel,
+    /// Token budget of the history
+    max_tokens: usize,
+    /// Strategy applied when the budget is exceeded
+    strategy: S,
+    /// The retained messages
+    messages: Vec<Message>,
+    /// Estimated prompt tokens of `messages`
+    tokens: usize,
+}
+
+impl HistoryManager<TrimOldest> {
+    /// Creates an empty history that drops the oldest messages to stay within `max_tokens`.
+    pub fn new(model: ChatModel, max_tokens: usize) -> Self {
+        Self::with_strategy(model, max_tokens, TrimOldest)
+    }
+}
+
+impl<S: HistoryStrategy> HistoryManager<S> {
+    /// Creates an empty history that uses `strategy` to stay within `max_tokens`.
+    pub fn with_strategy(model: ChatModel, max_tokens: usize, strategy: S) -> Self {
+        Self { model, max_tokens, strategy, messages: Vec::new(), tokens: TOKENS_PER_REPLY }
+    }
+
+    /// Appends a message, shrinking the history if it exceeds the token budget.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&mut Self)` - The manager, for method chaining
+    /// * `Err(OpenAIToolError)` - If the strategy fails (e.g. the summary request);
+    ///   the message is kept and the history is left untrimmed
+    pub async fn add_message(&mut self, message: Message) -> Result<&mut Self> {
+        self.tokens += message_token_counts(&self.model, std::slice::from_ref(&message))[0];
+        self.messages.push(message);
+
+        if self.tokens > self.max_tokens {
+            self.messages = self.strategy.fit(&self.model, self.messages.clone(), self.max_tokens).await?;
+            self.tokens = TOKENS_PER_REPLY + message_token_counts(&self.model, &self.messages).iter().sum::<usize>();
+        }
+        Ok(self)
+    }
+
+    /// Returns the retained messages, ready to pass to [`ChatCompletion::messages`].
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Returns the estimated prompt tokens of the retained messages.
+    pub fn token_count(&self) -> usize {
+        self.tokens
+    }
+
+    /// Returns the token budget.
+    pub fn max_tokens(&self) -> usize {
+        self.max_tokens
+    }
+
+    /// Removes all messages.
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.tokens = TOKENS_PER_REPLY;
+    }
+}
+
+/// Returns the range of messages to evict so that the rest fits in `max_tokens`.
+///
+/// Leading system and developer messages (other than a previous summary) and the latest
+/// message are never evicted. Tool results left without the assistant message
+/// that requested them are evicted too, since the API rejects them.
+fn eviction_range(model: &ChatModel, messages: &[Message], max_tokens: usize) -> Range<usize> {
+    let counts = message_token_counts(model, messages);
+    let start = messages.iter().take_while(|message| message.role.is_instruction() && !is_summary(message)).count();
+    let mut total = TOKENS_PER_REPLY + counts.iter().sum::<usize>();
+
+    let mut end = start;
+    while end + 1 < messages.len() && (total > max_tokens || (end > start && messages[end].role == Role::Tool)) {
+        total -= counts[end];
+        end += 1;
+    }
+    start..end
+}
+
+/// Returns `true` if `message` is a summary written by [`SummarizeOldest`].
+fn is_summary(message: &Message) -> bool {
+    message.role == Role::System && message_text(message).starts_with(SUMMARY_PREFIX)
+}
+
+/// Joins the text parts of a message.
+fn message_text(message: &Message) -> String {
+    let contents = message.content.iter().chain(message.content_list.iter().flatten());
+    contents.filter_map(|content| content.text.as_deref()).collect::<Vec<_>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::mock_server;
+
+    #[tokio::test]
+    async fn test_trim_oldest_keeps_system_prompt_and_latest_message() {
+        let mut history = HistoryManager::new(ChatModel::Gpt4oMini, 40);
+        history.add_message(Message::from_string(Role::System, "Be brief.")).await.unwrap();
+        for i in 0..6 {
+            history.add_message(Message::from_string(Role::User, format!("Question number {}", i))).await.unwrap();
+            history.add_message(Message::from_string(Role::Assistant, format!("Answer number {}", i))).await.unwrap();
+        }
+
+        let messages = history.messages();
+        assert!(history.token_count() <= 40);
+        assert!(messages.len() < 13);
+        assert_eq!(message_text(&messages[0]), "Be brief.");
+        assert_eq!(message_text(messages.last().unwrap()), "Answer number 5");
+        assert_eq!(history.token_count(), TOKENS_PER_REPLY + message_token_counts(&ChatModel::Gpt4oMini, messages).iter().sum::<usize>());
+    }
+
+    #[tokio::test]
+    async fn test_trim_oldest_does_not_leave_orphaned_tool_results() {
+        let messages = vec![
+            Message::from_string(Role::Assistant, "Calling a tool"),
+            Message::from_string(Role::Tool, "Tool output"),
+            Message::from_string(Role::User, "Thanks"),
+        ];
+        // Only enough room for the last message
+        let fitted = TrimOldest.fit(&ChatModel::Gpt4oMini, messages.clone(), 1).await.unwrap();
+        assert_eq!(fitted.len(), 1);
+        assert_eq!(message_text(&fitted[0]), "Thanks");
+
+        let budget = TOKENS_PER_REPLY + message_token_counts(&ChatModel::Gpt4oMini, &messages[1..]).iter().sum::<usize>();
+        let fitted = TrimOldest.fit(&ChatModel::Gpt4oMini, messages, budget).await.unwrap();
+        assert_eq!(fitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_summarize_oldest_replaces_evicted_messages_with_summary() {
+        let completion = r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1677652288, "model": "gpt-4o-mini",
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": "The user likes blue."}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 20, "completion_tokens": 5, "total_tokens": 25}}"#;
+        let (base_url, server) = mock_server::serve(vec![(200, completion.to_string())]).await;
+
+        let mut strategy = SummarizeOldest::new(ChatCompletion::with_url(base_url, "sk-test".to_string()));
+        strategy.max_summary_tokens(20);
+        let messages = vec![
+            Message::from_string(Role::System, "Be brief."),
+            Message::from_string(Role::User, "My favorite color is blue."),
+            Message::from_string(Role::Assistant, "Noted!"),
+            Message::from_string(Role::User, "What should I paint my room?"),
+        ];
+        let budget =
+            TOKENS_PER_REPLY + 20 + message_token_counts(&ChatModel::Gpt4oMini, &[messages[0].clone(), messages[3].clone()]).iter().sum::<usize>();
+        let fitted = strategy.fit(&ChatModel::Gpt4oMini, messages, budget).await.unwrap();
+
+        assert_eq!(fitted.len(), 3);
+        assert_eq!(message_text(&fitted[0]), "Be brief.");
+        assert_eq!(message_text(&fitted[1]), "Summary of the earlier conversation:\nThe user likes blue.");
+        assert!(is_summary(&fitted[1]));
+        assert_eq!(message_text(&fitted[2]), "What should I paint my room?");
+
+        let requests = server.await.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(sent["messages"][1]["content"], "user: My favorite color is blue.\nassistant: Noted!");
+        assert_eq!(sent["max_completion_tokens"], 20);
+    }
+}
@@ -158,8 +158,11 @@
 //! }
 //! ```
 
+pub mod cache;
+pub mod history;
 pub mod request;
 pub mod response;
+pub mod stored;
 
 #[cfg(test)]
 mod tests {
@@ -208,4 +211,85 @@ mod tests {
         assert!(json.contains("items"));
         assert!(json.contains("array"));
     }
+
+    #[test]
+    fn test_parse_content_reports_offending_text() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Answer {
+            #[allow(dead_code)]
+            value: u32,
+        }
+        let response = |content: &str| -> crate::chat::response::Response {
+            serde_json::from_value(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion", "created": 1677652288, "model": "gpt-4o-mini",
+                "choices": [{"index": 0, "message": {"role": "assistant", "content": content}, "finish_reason": "stop"}],
+                "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(response(r#"{"value": 42}"#).parse_content::<Answer>().unwrap().value, 42);
+
+        let err = response(r#"{"value": "forty-two"}"#).parse_content::<Answer>().unwrap_err();
+        assert!(matches!(err, crate::common::errors::OpenAIToolError::SerdeJsonError(_)));
+        assert!(err.to_string().contains(r#"in structured output: {"value": "forty-two"}"#), "{}", err);
+
+        let long = format!(r#"{{"value": "{}"}}"#, "x".repeat(1000));
+        let err = response(&long).parse_content::<Answer>().unwrap_err();
+        assert!(err.to_string().ends_with("x..."), "{}", err);
+    }
+
+    #[test]
+    fn test_finish_reason_deserialization() {
+        use crate::chat::response::{Choice, FinishReason};
+
+        let choice = |finish_reason: &str| -> Choice {
+            serde_json::from_value(serde_json::json!({
+                "index": 0, "message": {"role": "assistant", "content": "Hi"}, "finish_reason": finish_reason
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(choice("stop").finish_reason, FinishReason::Stop);
+        assert_eq!(choice("length").finish_reason, FinishReason::Length);
+        assert_eq!(choice("tool_calls").finish_reason, FinishReason::ToolCalls);
+        assert_eq!(choice("content_filter").finish_reason, FinishReason::ContentFilter);
+        assert_eq!(choice("function_call").finish_reason, FinishReason::FunctionCall);
+
+        let unknown = choice("something_new").finish_reason;
+        assert_eq!(unknown, FinishReason::Unknown("something_new".to_string()));
+        assert_eq!(unknown.to_string(), "something_new");
+        assert_eq!(serde_json::to_value(&unknown).unwrap(), "something_new");
+        assert_eq!(serde_json::to_value(FinishReason::ToolCalls).unwrap(), "tool_calls");
+
+        assert!(choice("stop").finished_normally());
+        assert!(choice("tool_calls").finished_normally());
+        assert!(!choice("length").finished_normally());
+        assert!(!choice("content_filter").finished_normally());
+    }
+
+    #[test]
+    fn test_refusal_response() {
+        let response: crate::chat::response::Response = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1", "object": "chat.completion", "created": 1677652288, "model": "gpt-4o-mini",
+            "choices": [
+                {"index": 0, "message": {"role": "assistant", "content": null, "refusal": "I'm sorry, I can't help with that."}, "finish_reason": "stop"},
+                {"index": 1, "message": {"role": "assistant", "content": "{\"value\": 1}", "refusal": null}, "finish_reason": "stop"}
+            ],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+        }))
+        .unwrap();
+
+        let refused = &response.choices[0];
+        assert!(refused.is_refusal());
+        assert_eq!(refused.message.refusal(), Some("I'm sorry, I can't help with that."));
+        assert!(refused.message.content.is_none());
+
+        let answered = &response.choices[1];
+        assert!(!answered.is_refusal());
+        assert_eq!(answered.message.refusal(), None);
+
+        let err = response.parse_content::<serde_json::Value>().unwrap_err();
+        assert!(err.to_string().contains("refused to respond"), "{}", err);
+    }
 }
@@ -193,20 +193,28 @@
 //! }
 //! ```
 
-use crate::chat::response::Response;
+use crate::audio::request::Voice;
+use crate::chat::cache::{cache_key, ResponseCache};
+use crate::chat::response::{FinishReason, Response};
 use crate::common::{
-    auth::{AuthProvider, OpenAIAuth},
-    client::create_http_client,
-    errors::{ErrorResponse, OpenAIToolError, Result},
-    message::{Content, Message},
-    models::{ChatModel, ParameterRestriction},
+    auth::AuthProvider,
+    client::{cached_http_client, run_cancellable},
+    errors::{request_id_header, OpenAIToolError, Result},
+    message::{cache_ordering_issues, Content, Message},
+    models::{ChatModel, ParameterRestriction, ParameterSupport, ParameterSupportTable},
+    pricing::RequestPreview,
+    role::Role,
     structured_output::Schema,
     tool::Tool,
+    transport::{send_with, HttpRequest, HttpResponse, Transport},
 };
 use core::str;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
+use strum::{Display, EnumString};
 
 /// Response format structure for OpenAI API requests
 ///
@@ -234,6 +242,121 @@ impl Format {
     }
 }
 
+/// Processing tier used to serve a request
+///
+/// Flex processing trades latency for lower cost, while priority processing
+/// offers faster, more consistent latency at a higher price.
+///
+/// # API Reference
+///
+/// Corresponds to the `service_tier` parameter in the OpenAI Chat Completions API.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, Display, PartialEq, Eq)]
+pub enum ServiceTier {
+    /// Use the tier configured in the project settings (default)
+    #[strum(serialize = "auto")]
+    #[serde(rename = "auto")]
+    Auto,
+
+    /// Standard pricing and performance
+    #[strum(serialize = "default")]
+    #[serde(rename = "default")]
+    Default,
+
+    /// Lower cost with higher latency, suited to batch-like workloads
+    #[strum(serialize = "flex")]
+    #[serde(rename = "flex")]
+    Flex,
+
+    /// Faster, more consistent latency at a higher price
+    #[strum(serialize = "priority")]
+    #[serde(rename = "priority")]
+    Priority,
+}
+
+/// Output modality the model may generate
+///
+/// Implements `AsRef<str>`, so it can be passed to
+/// [`ChatCompletion::modalities`] alongside plain strings.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, Display, PartialEq, Eq)]
+pub enum Modality {
+    /// Text output
+    #[strum(serialize = "text")]
+    #[serde(rename = "text")]
+    Text,
+
+    /// Audio output (requires an audio-capable model such as `gpt-4o-audio-preview`)
+    #[strum(serialize = "audio")]
+    #[serde(rename = "audio")]
+    Audio,
+}
+
+impl AsRef<str> for Modality {
+    fn as_ref(&self) -> &str {
+        match self {
+            Modality::Text => "text",
+            Modality::Audio => "audio",
+        }
+    }
+}
+
+/// Audio format for Chat Completions audio output
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumString, Display, PartialEq, Eq)]
+pub enum AudioOutputFormat {
+    /// WAV format
+    #[strum(serialize = "wav")]
+    #[serde(rename = "wav")]
+    Wav,
+
+    /// AAC format
+    #[strum(serialize = "aac")]
+    #[serde(rename = "aac")]
+    Aac,
+
+    /// MP3 format
+    #[strum(serialize = "mp3")]
+    #[serde(rename = "mp3")]
+    Mp3,
+
+    /// FLAC format
+    #[strum(serialize = "flac")]
+    #[serde(rename = "flac")]
+    Flac,
+
+    /// Opus format
+    #[strum(serialize = "opus")]
+    #[serde(rename = "opus")]
+    Opus,
+
+    /// Raw 16-bit PCM, suited to streaming playback
+    #[strum(serialize = "pcm16")]
+    #[serde(rename = "pcm16")]
+    Pcm16,
+}
+
+/// Audio output parameters for the `audio` request parameter
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct AudioConfig {
+    voice: Voice,
+    format: AudioOutputFormat,
+}
+
+/// Predicted output for the `prediction` request parameter
+///
+/// Serialized as `{"type": "content", "content": "..."}`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) struct Prediction {
+    #[serde(rename = "type")]
+    type_name: String,
+    content: String,
+}
+
+impl Prediction {
+    /// Creates a static-content prediction
+    pub fn content<T: AsRef<str>>(content: T) -> Self {
+        Self { type_name: "content".to_string(), content: content.as_ref().to_string() }
+    }
+}
+
 // =============================================================================
 // Chat API serialization wrappers
 //
@@ -241,6 +364,7 @@ impl Format {
 // but Chat Completions API expects different type names and structure:
 //   - "input_text"  → {"type": "text", "text": "..."}
 //   - "input_image" → {"type": "image_url", "image_url": {"url": "..."}}
+//   - "input_audio" → {"type": "input_audio", "input_audio": {"data": "...", "format": "..."}}
 //
 // These zero-copy wrappers convert at serialization time without changing
 // the public API or affecting the Responses API path.
@@ -260,7 +384,9 @@ impl<'a> Serialize for ChatContentRef<'a> {
             "input_text" => {
                 let mut state = serializer.serialize_struct("Content", 2)?;
                 state.serialize_field("type", "text")?;
-                state.serialize_field("text", &self.0.text)?;
+                if let Some(ref text) = self.0.text {
+                    state.serialize_field("text", text)?;
+                }
                 state.end()
             }
             "input_image" => {
@@ -275,6 +401,14 @@ impl<'a> Serialize for ChatContentRef<'a> {
                 }
                 state.end()
             }
+            "input_audio" => {
+                let mut state = serializer.serialize_struct("Content", 2)?;
+                state.serialize_field("type", "input_audio")?;
+                if let Some(ref input_audio) = self.0.input_audio {
+                    state.serialize_field("input_audio", input_audio)?;
+                }
+                state.end()
+            }
             other => {
                 // Pass through unknown types as-is
                 let mut state = serializer.serialize_struct("Content", 3)?;
@@ -309,8 +443,12 @@ impl<'a> Serialize for ChatMessageRef<'a> {
         state.serialize_field("role", &msg.role)?;
 
         if let Some(ref content) = msg.content {
-            // Single content: serialize as plain text string
-            state.serialize_field("content", &content.text)?;
+            match content.text {
+                // Single content: serialize as plain text string
+                Some(ref text) => state.serialize_field("content", text)?,
+                // Single non-text content (e.g. an image): serialize as a one-element list
+                None => state.serialize_field("content", &[ChatContentRef(content)])?,
+            }
         } else if let Some(ref contents) = msg.content_list {
             // Multi-modal content: wrap each element with ChatContentRef
             let chat_contents: Vec<ChatContentRef<'_>> = contents.iter().map(ChatContentRef).collect();
@@ -323,6 +461,10 @@ impl<'a> Serialize for ChatMessageRef<'a> {
         if let Some(ref tool_calls) = msg.tool_calls {
             state.serialize_field("tool_calls", tool_calls)?;
         }
+        if let Some(ref audio) = msg.audio {
+            // Earlier audio responses are referenced by ID only
+            state.serialize_field("audio", &serde_json::json!({ "id": audio.id }))?;
+        }
 
         state.end()
     }
@@ -341,6 +483,22 @@ where
     seq.end()
 }
 
+/// Puts the original messages back when dropped, so that
+/// [`ChatCompletion::chat_until_complete`] leaves the history unchanged even if
+/// it returns early or is cancelled.
+struct RestoreMessages<'a> {
+    chat: &'a mut ChatCompletion,
+    original: Option<Vec<Message>>,
+}
+
+impl Drop for RestoreMessages<'_> {
+    fn drop(&mut self) {
+        if let Some(original) = self.original.take() {
+            self.chat.request_body.messages = original;
+        }
+    }
+}
+
 /// Request body structure for OpenAI Chat Completions API
 ///
 /// This structure represents the parameters that will be sent in the request body
@@ -353,12 +511,18 @@ pub(crate) struct Body {
     /// Whether to store the request and response at OpenAI
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) store: Option<bool>,
+    /// Key-value pairs attached to the stored completion
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<HashMap<String, String>>,
+    /// Key grouping requests with a shared prefix for prompt caching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) prompt_cache_key: Option<String>,
     /// Frequency penalty parameter to reduce repetition (-2.0 to 2.0)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) frequency_penalty: Option<f32>,
     /// Logit bias to adjust the probability of specific tokens
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) logit_bias: Option<HashMap<String, i32>>,
+    pub(crate) logit_bias: Option<HashMap<String, f32>>,
     /// Whether to include probability information for each token
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) logprobs: Option<bool>,
@@ -374,6 +538,9 @@ pub(crate) struct Body {
     /// Available modalities for the response (e.g., text, audio)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) modalities: Option<Vec<String>>,
+    /// Voice and format of the audio output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) audio: Option<AudioConfig>,
     /// Presence penalty to encourage new topics (-2.0 to 2.0)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) presence_penalty: Option<f32>,
@@ -386,6 +553,12 @@ pub(crate) struct Body {
     /// Optional tools that can be used by the model
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) tools: Option<Vec<Tool>>,
+    /// Predicted output used to speed up regeneration of mostly-known content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) prediction: Option<Prediction>,
+    /// Processing tier for the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) service_tier: Option<ServiceTier>,
     /// A stable identifier for the end user, used for safety monitoring and abuse detection
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) safety_identifier: Option<String>,
@@ -482,6 +655,16 @@ pub struct ChatCompletion {
     pub(crate) request_body: Body,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
+    /// HTTP client built on first use and reused by later requests
+    cached_http_client: OnceLock<request::Client>,
+    /// Cache serving repeated identical requests, if any
+    cache: Option<Arc<dyn ResponseCache>>,
+    /// Transport replacing the HTTP client for non-streaming requests, if any
+    transport: Option<Arc<dyn Transport>>,
+    /// Parameter support used to validate requests for the configured model
+    parameter_support: ParameterSupportTable,
 }
 
 impl Default for ChatCompletion {
@@ -513,7 +696,16 @@ impl ChatCompletion {
     /// ```
     pub fn new() -> Self {
         let auth = AuthProvider::openai_from_env().map_err(|e| OpenAIToolError::Error(format!("Failed to load OpenAI auth: {}", e))).unwrap();
-        Self { auth, request_body: Body::default(), timeout: None }
+        Self {
+            auth,
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        }
     }
 
     /// Creates a new ChatCompletion instance with a specified model
@@ -549,7 +741,16 @@ impl ChatCompletion {
     /// ```
     pub fn with_model(model: ChatModel) -> Self {
         let auth = AuthProvider::openai_from_env().map_err(|e| OpenAIToolError::Error(format!("Failed to load OpenAI auth: {}", e))).unwrap();
-        Self { auth, request_body: Body { model, ..Default::default() }, timeout: None }
+        Self {
+            auth,
+            request_body: Body { model, ..Default::default() },
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        }
     }
 
     /// Creates a new ChatCompletion instance with a custom authentication provider
@@ -580,7 +781,40 @@ impl ChatCompletion {
     /// let mut chat = ChatCompletion::with_auth(auth);
     /// ```
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, request_body: Body::default(), timeout: None }
+        Self {
+            auth,
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        }
+    }
+
+    /// Creates a new ChatCompletion instance that sends requests with the given HTTP client
+    ///
+    /// Use this to reuse a pre-configured `reqwest::Client` (connection limits,
+    /// proxy, TLS settings, ...). The API key is loaded as in [`ChatCompletion::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `OPENAI_API_KEY` environment variable is not set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use openai_tools::chat::request::ChatCompletion;
+    ///
+    /// let http_client = request::Client::builder().pool_max_idle_per_host(16).timeout(Duration::from_secs(30)).build().unwrap();
+    /// let mut chat = ChatCompletion::with_http_client(http_client);
+    /// ```
+    pub fn with_http_client(http_client: request::Client) -> Self {
+        let mut chat = Self::new();
+        chat.http_client = Some(http_client);
+        chat
     }
 
     /// Creates a new ChatCompletion instance for Azure OpenAI API
@@ -611,7 +845,16 @@ impl ChatCompletion {
     /// ```
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, request_body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        })
     }
 
     /// Creates a new ChatCompletion instance by auto-detecting the provider
@@ -633,7 +876,16 @@ impl ChatCompletion {
     /// ```
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, request_body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        })
     }
 
     /// Creates a new ChatCompletion instance with URL-based provider detection
@@ -670,7 +922,16 @@ impl ChatCompletion {
     /// ```
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, request_body: Body::default(), timeout: None }
+        Self {
+            auth,
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        }
     }
 
     /// Creates a new ChatCompletion instance from URL using environment variables
@@ -710,7 +971,16 @@ impl ChatCompletion {
     /// ```
     pub fn from_url<S: Into<String>>(base_url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(base_url)?;
-        Ok(Self { auth, request_body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        })
     }
 
     /// Returns the authentication provider
@@ -751,7 +1021,7 @@ impl ChatCompletion {
     pub fn base_url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         // Only modify if OpenAI provider
         if let AuthProvider::OpenAI(ref openai_auth) = self.auth {
-            let new_auth = OpenAIAuth::new(openai_auth.api_key()).with_base_url(url.as_ref());
+            let new_auth = openai_auth.clone().with_base_url(url.as_ref());
             self.auth = AuthProvider::OpenAI(new_auth);
         } else {
             tracing::warn!("base_url() is only supported for OpenAI provider. Use azure() or with_auth() for Azure.");
@@ -811,6 +1081,12 @@ impl ChatCompletion {
 
     /// Sets the request timeout duration
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -834,6 +1110,72 @@ impl ChatCompletion {
         self
     }
 
+    /// Serves repeated identical requests from a response cache
+    ///
+    /// The cache key is a hash of the endpoint and the serialized request body,
+    /// so any change to the model, messages or parameters is a cache miss.
+    /// Only successful responses are stored. Caching is best suited to
+    /// deterministic requests (`temperature(0.0)`, a fixed `seed`).
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The cache to read from and write to
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::cache::InMemoryResponseCache;
+    /// use openai_tools::chat::request::ChatCompletion;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.model_id("gpt-4o-mini")
+    ///     .with_cache(InMemoryResponseCache::new());
+    /// ```
+    pub fn with_cache<C: ResponseCache + 'static>(&mut self, cache: C) -> &mut Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Sends requests through a custom transport
+    ///
+    /// Use a [`MockTransport`](crate::common::transport::MockTransport) to test
+    /// code built on `ChatCompletion` without network access.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to send requests with
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn with_transport<T: Transport + 'static>(&mut self, transport: T) -> &mut Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets the parameter support table used to validate requests
+    ///
+    /// Register the capabilities of fine-tuned, new or third-party models so
+    /// that setters and [`validate_for_model`](Self::validate_for_model) know
+    /// which parameters they accept. Models without an entry use the
+    /// built-in [`ChatModel::parameter_support`].
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The parameter support table
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn parameter_support_table(&mut self, table: ParameterSupportTable) -> &mut Self {
+        self.parameter_support = table;
+        self
+    }
+
     /// Sets the chat message history
     ///
     /// # Arguments
@@ -843,11 +1185,56 @@ impl ChatCompletion {
     /// # Returns
     ///
     /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// Text converts into a message with the **user** role:
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::request::ChatCompletion;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.messages(vec!["Hello".into()]);
+    /// ```
     pub fn messages(&mut self, messages: Vec<Message>) -> &mut Self {
         self.request_body.messages = messages;
         self
     }
 
+    /// Sets the chat message history from `(role, text)` pairs
+    ///
+    /// Each pair becomes a text message, as with [`Message::from_string`].
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The `(role, text)` pairs, oldest first
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::role::Role;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.messages_from([
+    ///     (Role::System, "You are a helpful assistant."),
+    ///     (Role::User, "What is the capital of France?"),
+    ///     (Role::Assistant, "Paris."),
+    ///     (Role::User, "And of Germany?"),
+    /// ]);
+    /// ```
+    pub fn messages_from<I, S>(&mut self, messages: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (Role, S)>,
+        S: AsRef<str>,
+    {
+        self.messages(messages.into_iter().map(|(role, text)| Message::from_string(role, text)).collect())
+    }
+
     /// Adds a single message to the conversation history
     ///
     /// This method appends a new message to the existing conversation history.
@@ -891,6 +1278,66 @@ impl ChatCompletion {
         self
     }
 
+    /// Adds or replaces a metadata key-value pair for the request
+    ///
+    /// Metadata tags stored completions (see [`store`](Self::store)) so they can
+    /// be filtered later, e.g. in evals or the dashboard.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The metadata key
+    /// * `value` - The metadata value
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, key: K, value: V) -> &mut Self {
+        self.request_body.metadata.get_or_insert_with(HashMap::new).insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Replaces all metadata of the request
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The metadata key-value pairs
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn metadata_map(&mut self, metadata: HashMap<String, String>) -> &mut Self {
+        self.request_body.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the key used to route the request for prompt caching
+    ///
+    /// OpenAI caches prompt prefixes automatically. Requests sharing a long
+    /// prefix, such as a stable system prompt, get more cache hits when they
+    /// are sent with the same key. Cache hits are reported by
+    /// [`Usage::cached_tokens`](crate::common::usage::Usage::cached_tokens).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The cache key, e.g. an identifier of the shared prompt
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::chat::request::ChatCompletion;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.prompt_cache_key("support-bot-v3");
+    /// ```
+    pub fn prompt_cache_key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
+        self.request_body.prompt_cache_key = Some(key.as_ref().to_string());
+        self
+    }
+
     /// Sets the frequency penalty
     ///
     /// A parameter that penalizes based on word frequency to reduce repetition.
@@ -907,7 +1354,7 @@ impl ChatCompletion {
     ///
     /// A mutable reference to self for method chaining
     pub fn frequency_penalty(&mut self, frequency_penalty: f32) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+        let support = self.parameter_support();
         match support.frequency_penalty {
             ParameterRestriction::FixedValue(fixed) => {
                 if (frequency_penalty as f64 - fixed).abs() > f64::EPSILON {
@@ -932,26 +1379,86 @@ impl ChatCompletion {
 
     /// Sets logit bias to adjust the probability of specific tokens
     ///
+    /// Maps token IDs to a bias value between -100 and 100. Values near -100 effectively
+    /// ban a token, while values near 100 make it almost certain to be selected.
+    /// Entries outside of this range are ignored with a warning.
+    ///
     /// **Note:** Reasoning models (GPT-5, o-series) do not support logit_bias.
     /// For these models, this parameter will be ignored with a warning.
     ///
     /// # Arguments
     ///
-    /// * `logit_bias` - A map of token IDs to adjustment values
+    /// * `logit_bias` - A map of token IDs to adjustment values (range: -100 to 100)
     ///
     /// # Returns
     ///
     /// A mutable reference to self for method chaining
-    pub fn logit_bias<T: AsRef<str>>(&mut self, logit_bias: HashMap<T, i32>) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use openai_tools::chat::request::ChatCompletion;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.logit_bias(HashMap::from([(1734u32, -100.0f32), (2085u32, 5.0f32)]));
+    /// ```
+    pub fn logit_bias<K: ToString, V: Into<f64>>(&mut self, logit_bias: HashMap<K, V>) -> &mut Self {
+        let support = self.parameter_support();
         if !support.logit_bias {
             tracing::warn!("Model '{}' does not support logit_bias parameter. Ignoring.", self.request_body.model);
             return self;
         }
-        self.request_body.logit_bias = Some(logit_bias.into_iter().map(|(k, v)| (k.as_ref().to_string(), v)).collect::<HashMap<String, i32>>());
+        let mut validated = HashMap::new();
+        for (token, bias) in logit_bias {
+            let token = token.to_string();
+            let bias = bias.into();
+            if !(-100.0..=100.0).contains(&bias) {
+                tracing::warn!("logit_bias for token '{}' must be between -100 and 100. Ignoring bias={}.", token, bias);
+                continue;
+            }
+            validated.insert(token, bias as f32);
+        }
+        self.request_body.logit_bias = Some(validated);
         self
     }
 
+    /// Sets logit bias for words or phrases instead of raw token IDs
+    ///
+    /// Each string is tokenized with the tokenizer of `model` (falling back to `o200k_base`
+    /// for models unknown to tiktoken), and the bias is applied to every resulting token ID.
+    /// Biases outside of -100 to 100 are ignored with a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model whose tokenizer is used to encode the strings
+    /// * `logit_bias` - A map of strings to adjustment values (range: -100 to 100)
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::models::ChatModel;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.logit_bias_for_tokens(ChatModel::Gpt4oMini, HashMap::from([(" banana".to_string(), -100.0)]));
+    /// ```
+    pub fn logit_bias_for_tokens(&mut self, model: ChatModel, logit_bias: HashMap<String, f32>) -> &mut Self {
+        let bpe = tiktoken_rs::get_bpe_from_model(model.as_str()).unwrap_or_else(|_| tiktoken_rs::o200k_base().unwrap());
+        let mut token_bias: HashMap<u32, f32> = HashMap::new();
+        for (text, bias) in logit_bias {
+            for token in bpe.encode_with_special_tokens(&text) {
+                token_bias.insert(token, bias);
+            }
+        }
+        self.logit_bias(token_bias)
+    }
+
     /// Sets whether to include probability information for each token
     ///
     /// **Note:** Reasoning models (GPT-5, o-series) do not support logprobs.
@@ -965,7 +1472,7 @@ impl ChatCompletion {
     ///
     /// A mutable reference to self for method chaining
     pub fn logprobs(&mut self, logprobs: bool) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+        let support = self.parameter_support();
         if !support.logprobs {
             tracing::warn!("Model '{}' does not support logprobs parameter. Ignoring.", self.request_body.model);
             return self;
@@ -987,7 +1494,7 @@ impl ChatCompletion {
     ///
     /// A mutable reference to self for method chaining
     pub fn top_logprobs(&mut self, top_logprobs: u8) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+        let support = self.parameter_support();
         if !support.top_logprobs {
             tracing::warn!("Model '{}' does not support top_logprobs parameter. Ignoring.", self.request_body.model);
             return self;
@@ -998,6 +1505,9 @@ impl ChatCompletion {
 
     /// Sets the maximum number of tokens to generate
     ///
+    /// Sent as `max_completion_tokens`, which every chat model accepts. The
+    /// limit includes reasoning tokens for reasoning models (GPT-5, o-series).
+    ///
     /// # Arguments
     ///
     /// * `max_completion_tokens` - Maximum number of tokens
@@ -1010,6 +1520,39 @@ impl ChatCompletion {
         self
     }
 
+    /// Sets the maximum number of tokens to generate
+    ///
+    /// An alias of [`max_completion_tokens`](Self::max_completion_tokens) for
+    /// code written against the deprecated `max_tokens` request field. The
+    /// value is always sent as `max_completion_tokens`: reasoning models
+    /// (o-series, GPT-5) reject `max_tokens`, and all other chat models accept
+    /// the newer field, so the same builder call works for every model.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_tokens` - Maximum number of tokens
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::auth::AuthProvider;
+    ///
+    /// let mut chat = ChatCompletion::with_auth(AuthProvider::from_url_with_key("https://api.openai.com/v1", "sk-test"));
+    /// chat.model_id("o3-mini").messages(vec!["Hello!".into()]).max_tokens(256);
+    ///
+    /// let json = chat.build_request_json().unwrap();
+    /// assert!(json.contains("\"max_completion_tokens\":256"));
+    /// assert!(!json.contains("\"max_tokens\""));
+    /// ```
+    pub fn max_tokens(&mut self, max_tokens: u32) -> &mut Self {
+        self.max_completion_tokens(u64::from(max_tokens))
+    }
+
     /// Sets the number of responses to generate
     ///
     /// **Note:** Reasoning models (GPT-5, o-series) only support n=1.
@@ -1023,7 +1566,7 @@ impl ChatCompletion {
     ///
     /// A mutable reference to self for method chaining
     pub fn n(&mut self, n: u32) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+        let support = self.parameter_support();
         if !support.n_multiple && n != 1 {
             tracing::warn!("Model '{}' only supports n=1. Ignoring n={}.", self.request_body.model, n);
             return self;
@@ -1036,7 +1579,7 @@ impl ChatCompletion {
     ///
     /// # Arguments
     ///
-    /// * `modalities` - List of modalities (e.g., `["text", "audio"]`)
+    /// * `modalities` - List of modalities, as [`Modality`] values or strings (e.g., `["text", "audio"]`)
     ///
     /// # Returns
     ///
@@ -1046,6 +1589,41 @@ impl ChatCompletion {
         self
     }
 
+    /// Requests spoken audio in addition to text
+    ///
+    /// The generated audio is returned base64-encoded in the `audio` field of
+    /// the response message, together with its transcript (see
+    /// [`AudioOutput`](crate::common::message::AudioOutput)). If no modalities
+    /// were set, they become `["text", "audio"]`. Requires an audio-capable
+    /// model such as `gpt-4o-audio-preview`.
+    ///
+    /// # Arguments
+    ///
+    /// * `voice` - The voice the model speaks with
+    /// * `format` - The audio format of the output
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::audio::request::Voice;
+    /// use openai_tools::chat::request::{AudioOutputFormat, ChatCompletion};
+    /// use openai_tools::common::models::ChatModel;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.model(ChatModel::Gpt4oAudioPreview).audio_output(Voice::Alloy, AudioOutputFormat::Wav);
+    /// ```
+    pub fn audio_output(&mut self, voice: Voice, format: AudioOutputFormat) -> &mut Self {
+        self.request_body.audio = Some(AudioConfig { voice, format });
+        if self.request_body.modalities.is_none() {
+            self.modalities(vec![Modality::Text, Modality::Audio]);
+        }
+        self
+    }
+
     /// Sets the presence penalty
     ///
     /// A parameter that controls the tendency to include new content in the document.
@@ -1063,7 +1641,7 @@ impl ChatCompletion {
     ///
     /// A mutable reference to self for method chaining
     pub fn presence_penalty(&mut self, presence_penalty: f32) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+        let support = self.parameter_support();
         match support.presence_penalty {
             ParameterRestriction::FixedValue(fixed) => {
                 if (presence_penalty as f64 - fixed).abs() > f64::EPSILON {
@@ -1102,7 +1680,7 @@ impl ChatCompletion {
     ///
     /// A mutable reference to self for method chaining
     pub fn temperature(&mut self, temperature: f32) -> &mut Self {
-        let support = self.request_body.model.parameter_support();
+        let support = self.parameter_support();
         match support.temperature {
             ParameterRestriction::FixedValue(fixed) => {
                 if (temperature as f64 - fixed).abs() > f64::EPSILON {
@@ -1155,16 +1733,75 @@ impl ChatCompletion {
         self
     }
 
-    /// Sets the safety identifier for end-user tracking
+    /// Sets the predicted output of the response
     ///
-    /// A stable identifier used to help OpenAI detect users of your application
-    /// that may be violating usage policies. This enables per-user safety
-    /// monitoring and abuse detection.
+    /// With [Predicted Outputs](https://platform.openai.com/docs/guides/predicted-outputs),
+    /// parts of the response that match the prediction are generated much faster.
+    /// This suits regenerating mostly-unchanged content such as code edits.
+    /// How much of the prediction was used is reported by
+    /// [`Usage::accepted_prediction_token_count`](crate::common::usage::Usage::accepted_prediction_token_count)
+    /// and [`Usage::rejected_prediction_token_count`](crate::common::usage::Usage::rejected_prediction_token_count).
     ///
     /// # Arguments
     ///
-    /// * `safety_id` - A unique, stable identifier for the end user
-    ///   (recommended: hash of email or internal user ID)
+    /// * `content` - The expected content of the response
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::chat::request::ChatCompletion;
+    ///
+    /// let code = "fn main() {\n    println!(\"Hello\");\n}";
+    /// let mut chat = ChatCompletion::new();
+    /// chat.model_id("gpt-4o").prediction(code);
+    /// ```
+    pub fn prediction<T: AsRef<str>>(&mut self, content: T) -> &mut Self {
+        self.request_body.prediction = Some(Prediction::content(content));
+        self
+    }
+
+    /// Sets the processing tier for the request
+    ///
+    /// Use [`ServiceTier::Flex`] for cost-sensitive workloads that tolerate
+    /// higher latency, or [`ServiceTier::Priority`] for latency-sensitive ones.
+    /// The tier that actually served the request is reported by
+    /// [`Response::served_tier`].
+    ///
+    /// # Arguments
+    ///
+    /// * `tier` - The service tier to request
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::chat::request::{ChatCompletion, ServiceTier};
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.service_tier(ServiceTier::Flex);
+    /// ```
+    pub fn service_tier(&mut self, tier: ServiceTier) -> &mut Self {
+        self.request_body.service_tier = Some(tier);
+        self
+    }
+
+    /// Sets the safety identifier for end-user tracking
+    ///
+    /// A stable identifier used to help OpenAI detect users of your application
+    /// that may be violating usage policies. This enables per-user safety
+    /// monitoring and abuse detection.
+    ///
+    /// # Arguments
+    ///
+    /// * `safety_id` - A unique, stable identifier for the end user
+    ///   (recommended: hash of email or internal user ID)
     ///
     /// # Returns
     ///
@@ -1217,22 +1854,120 @@ impl ChatCompletion {
         self.request_body.messages.clone()
     }
 
-    /// Checks if the model is a reasoning model that doesn't support custom temperature
+    /// Checks the configured parameters against what the model supports
+    ///
+    /// The model's capabilities are looked up in the
+    /// [`ParameterSupportTable`] (see
+    /// [`parameter_support_table`](Self::parameter_support_table)), so that a
+    /// request the API would reject with a 400 fails locally instead. This
+    /// mostly matters for reasoning models (GPT-5, o-series), which reject
+    /// e.g. a custom `temperature`. [`chat()`](Self::chat) runs this check
+    /// before sending.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the model supports every parameter that is set
+    /// * `Err(OpenAIToolError::UnsupportedParameter)` - For the first parameter the model rejects
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::auth::AuthProvider;
+    /// use openai_tools::common::errors::OpenAIToolError;
+    ///
+    /// let mut chat = ChatCompletion::with_auth(AuthProvider::from_url_with_key("https://api.openai.com/v1", "sk-test"));
+    /// chat.model_id("gpt-4o-mini").temperature(0.2);
+    /// assert!(chat.validate_for_model().is_ok());
+    ///
+    /// // The temperature was set for a model that accepts it
+    /// chat.model_id("o3-mini");
+    /// assert!(matches!(chat.validate_for_model(), Err(OpenAIToolError::UnsupportedParameter { param, .. }) if param == "temperature"));
+    /// ```
+    pub fn validate_for_model(&self) -> Result<()> {
+        let body = &self.request_body;
+        let support = self.parameter_support();
+        let allows = |restriction: &ParameterRestriction, value: Option<f32>| match (restriction, value) {
+            (_, None) | (ParameterRestriction::Any, _) => true,
+            (ParameterRestriction::FixedValue(fixed), Some(value)) => (value as f64 - fixed).abs() <= f64::EPSILON,
+            (ParameterRestriction::NotSupported, Some(_)) => false,
+        };
+
+        let checks = [
+            ("temperature", allows(&support.temperature, body.temperature)),
+            ("frequency_penalty", allows(&support.frequency_penalty, body.frequency_penalty)),
+            ("presence_penalty", allows(&support.presence_penalty, body.presence_penalty)),
+            ("logprobs", support.logprobs || body.logprobs.is_none()),
+            ("top_logprobs", support.top_logprobs || body.top_logprobs.is_none()),
+            ("logit_bias", support.logit_bias || body.logit_bias.is_none()),
+            ("n", support.n_multiple || body.n.is_none_or(|n| n == 1)),
+        ];
+        match checks.into_iter().find(|(_, supported)| !supported) {
+            Some((param, _)) => Err(OpenAIToolError::UnsupportedParameter { model: body.model.to_string(), param: param.to_string() }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the parameter support of the configured model
+    fn parameter_support(&self) -> ParameterSupport {
+        self.parameter_support.get(self.request_body.model.as_str())
+    }
+
+    /// Validates the request
+    ///
+    /// Returns the body that [`chat()`](Self::chat) sends.
+    pub(crate) fn prepare_request_body(&self) -> Result<Body> {
+        let body = self.request_body.clone();
+
+        // Validate required fields before any HTTP call
+        if body.model.as_str().is_empty() {
+            return Err(OpenAIToolError::MissingField("model"));
+        }
+        if body.messages.is_empty() {
+            return Err(OpenAIToolError::MissingField("messages"));
+        }
+        if let Some(format) = &body.response_format {
+            format.json_schema.validate()?;
+        }
+        for tool in body.tools.iter().flatten() {
+            tool.validate()?;
+        }
+
+        self.validate_for_model()?;
+
+        Ok(body)
+    }
+
+    /// Returns the JSON body that [`chat()`](Self::chat) would send, without sending it
     ///
-    /// Reasoning models (o1, o3, o4 series) only support the default temperature value of 1.0.
-    /// This method checks if the current model is one of these reasoning models.
+    /// The body is built from the builder state and validated as in `chat()`,
+    /// so the output matches the actual request exactly. Useful for debugging
+    /// and for diffing requests.
     ///
     /// # Returns
     ///
-    /// `true` if the model is a reasoning model, `false` otherwise
+    /// * `Ok(String)` - The serialized request body
+    /// * `Err(OpenAIToolError::MissingField)` - If the model or messages are not set
+    /// * `Err(OpenAIToolError::UnsupportedParameter)` - If the model does not support a
+    ///   parameter set on the request
+    /// * `Err(OpenAIToolError)` - If the request is otherwise invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::auth::AuthProvider;
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
     ///
-    /// # Supported Reasoning Models
+    /// let mut chat = ChatCompletion::with_auth(AuthProvider::from_url_with_key("http://localhost:11434/v1", "ollama"));
+    /// chat.model_id("gpt-4o-mini").messages(vec![Message::from_string(Role::User, "Hello!")]);
     ///
-    /// - `o1`, `o1-pro`, and variants
-    /// - `o3`, `o3-mini`, and variants
-    /// - `o4-mini` and variants
-    fn is_reasoning_model(&self) -> bool {
-        self.request_body.model.is_reasoning_model()
+    /// let json = chat.build_request_json().unwrap();
+    /// assert!(json.contains("\"model\":\"gpt-4o-mini\""));
+    /// ```
+    pub fn build_request_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.prepare_request_body()?)?)
     }
 
     /// Sends the chat completion request to OpenAI API
@@ -1263,10 +1998,11 @@ impl ChatCompletion {
     /// - `n`: only 1 supported
     ///
     /// **Validation occurs at two points:**
-    /// 1. At setter time (when using `with_model()` constructor) - immediate warning
-    /// 2. At API call time (fallback) - for cases where model is changed after setting params
-    ///
-    /// Unsupported parameter values are ignored with a warning and the request proceeds.
+    /// 1. At setter time - unsupported values are ignored with a warning
+    /// 2. At API call time, with [`validate_for_model()`](Self::validate_for_model) - for
+    ///    cases where the model is changed after setting params. An unsupported
+    ///    value fails the request with `OpenAIToolError::UnsupportedParameter`
+    ///    before anything is sent.
     ///
     /// # Example
     ///
@@ -1293,90 +2029,22 @@ impl ChatCompletion {
     /// # }
     /// ```
     pub async fn chat(&mut self) -> Result<Response> {
-        // Validate that messages are set
-        if self.request_body.messages.is_empty() {
-            return Err(OpenAIToolError::Error("Messages are not set.".into()));
-        }
-
-        // Handle reasoning models that don't support certain parameters
-        // See: https://platform.openai.com/docs/guides/reasoning
-        if self.is_reasoning_model() {
-            let model = &self.request_body.model;
-
-            // Temperature: only default (1.0) is supported
-            if let Some(temp) = self.request_body.temperature {
-                if (temp - 1.0).abs() > f32::EPSILON {
-                    tracing::warn!(
-                        "Reasoning model '{}' does not support custom temperature. \
-                         Ignoring temperature={} and using default (1.0).",
-                        model,
-                        temp
-                    );
-                    self.request_body.temperature = None;
-                }
-            }
-
-            // Frequency penalty: only 0 is supported
-            if let Some(fp) = self.request_body.frequency_penalty {
-                if fp.abs() > f32::EPSILON {
-                    tracing::warn!(
-                        "Reasoning model '{}' does not support frequency_penalty. \
-                         Ignoring frequency_penalty={} and using default (0).",
-                        model,
-                        fp
-                    );
-                    self.request_body.frequency_penalty = None;
-                }
-            }
-
-            // Presence penalty: only 0 is supported
-            if let Some(pp) = self.request_body.presence_penalty {
-                if pp.abs() > f32::EPSILON {
-                    tracing::warn!(
-                        "Reasoning model '{}' does not support presence_penalty. \
-                         Ignoring presence_penalty={} and using default (0).",
-                        model,
-                        pp
-                    );
-                    self.request_body.presence_penalty = None;
-                }
-            }
-
-            // Logprobs: not supported
-            if self.request_body.logprobs.is_some() {
-                tracing::warn!("Reasoning model '{}' does not support logprobs. Ignoring logprobs parameter.", model);
-                self.request_body.logprobs = None;
-            }
+        self.request_body = self.prepare_request_body()?;
 
-            // Top logprobs: not supported
-            if self.request_body.top_logprobs.is_some() {
-                tracing::warn!("Reasoning model '{}' does not support top_logprobs. Ignoring top_logprobs parameter.", model);
-                self.request_body.top_logprobs = None;
-            }
+        let body = serde_json::to_string(&self.request_body)?;
 
-            // Logit bias: not supported
-            if self.request_body.logit_bias.is_some() {
-                tracing::warn!("Reasoning model '{}' does not support logit_bias. Ignoring logit_bias parameter.", model);
-                self.request_body.logit_bias = None;
-            }
+        // Get the endpoint URL from the auth provider
+        let endpoint = self.auth.endpoint(CHAT_COMPLETIONS_PATH);
 
-            // N: only 1 is supported
-            if let Some(n) = self.request_body.n {
-                if n != 1 {
-                    tracing::warn!(
-                        "Reasoning model '{}' does not support n != 1. \
-                         Ignoring n={} and using default (1).",
-                        model,
-                        n
-                    );
-                    self.request_body.n = None;
-                }
+        let cache_key = self.cache.as_ref().map(|_| cache_key(&endpoint, &self.request_body)).transpose()?;
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(response) = cache.get(key) {
+                tracing::debug!("Serving chat response from cache (key: {})", key);
+                return Ok(response);
             }
         }
 
-        let body = serde_json::to_string(&self.request_body)?;
-
-        let client = create_http_client(self.timeout)?;
+        let client = cached_http_client(self.http_client.as_ref(), &self.cached_http_client, self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
         headers.insert("User-Agent", request::header::HeaderValue::from_static("openai-tools-rust"));
@@ -1390,25 +2058,220 @@ impl ChatCompletion {
             tracing::info!("Request body: {}", body_for_debug);
         }
 
-        // Get the endpoint URL from the auth provider
-        let endpoint = self.auth.endpoint(CHAT_COMPLETIONS_PATH);
-
-        let response = client.post(&endpoint).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
-        let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let http_request = HttpRequest { method: request::Method::POST, url: endpoint, headers, body: Some(body) };
+        let HttpResponse { status, headers, body: content } = send_with(self.transport.as_ref(), &client, http_request).await?;
+        let request_id = request_id_header(&headers);
 
         if cfg!(debug_assertions) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
+        let response = serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)?;
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            cache.put(key, &response);
+        }
+        Ok(response)
+    }
+
+    /// Estimates the prompt tokens and cost of the request without sending it
+    ///
+    /// Tokens are counted locally with the model's tokenizer, and the cost is
+    /// computed from the built-in pricing table. No network call is made.
+    ///
+    /// # Returns
+    ///
+    /// A `RequestPreview` with the estimated prompt tokens and input cost in USD
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.messages(vec![Message::from_string(Role::User, "Hello!")]);
+    ///
+    /// let preview = chat.preview();
+    /// println!("~{} tokens, ~${:.6}", preview.estimated_prompt_tokens, preview.estimated_cost.unwrap_or_default());
+    /// ```
+    pub fn preview(&self) -> RequestPreview {
+        RequestPreview::estimate(&self.request_body.model, None, &self.request_body.messages)
+    }
+
+    /// Checks that the request is laid out to benefit from automatic prompt caching
+    ///
+    /// Caching only applies when the static prefix of the request (tools and system
+    /// messages) is byte-identical across requests. This debug helper logs a warning
+    /// and returns a description for each system message placed after user,
+    /// assistant or tool messages. Tool parameters always serialize in the same
+    /// order, so they need no check.
+    ///
+    /// # Returns
+    ///
+    /// The descriptions of the problems found; empty if the request is cache-friendly
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.messages(vec![
+    ///     Message::from_string(Role::System, "You are a helpful assistant."),
+    ///     Message::from_string(Role::User, "Hello!"),
+    /// ]);
+    /// assert!(chat.assert_cache_friendly().is_empty());
+    /// ```
+    pub fn assert_cache_friendly(&self) -> Vec<String> {
+        let issues = cache_ordering_issues(&self.request_body.messages);
+        for issue in &issues {
+            tracing::warn!("Prompt caching: {}", issue);
+        }
+        issues
+    }
+
+    /// Sends the chat request and keeps continuing while the output is truncated
+    ///
+    /// When the first choice finishes with [`FinishReason::Length`], the partial
+    /// assistant message is appended to the conversation and the request is sent again
+    /// so the model can continue where it left off. The text of all partial responses
+    /// is concatenated into the first choice of the returned response, and token usage
+    /// is summed across requests.
+    ///
+    /// The message history is restored to its original state once this method returns.
+    /// Only the first choice is continued, so this is intended for requests with `n = 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_continuations` - Maximum number of follow-up requests sent after the first one
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the combined response. Its `finish_reason` is
+    /// [`FinishReason::Length`] if the output was still truncated after `max_continuations` follow-ups.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>>
+    /// # {
+    /// let mut chat = ChatCompletion::new();
+    /// let response = chat
+    ///     .messages(vec![Message::from_string(Role::User, "Write a long story.")])
+    ///     .max_completion_tokens(256)
+    ///     .chat_until_complete(3)
+    ///     .await?;
+    ///
+    /// println!("{}", response.choices[0].message.content.as_ref().unwrap().text.as_ref().unwrap());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # }
+    /// ```
+    pub async fn chat_until_complete(&mut self, max_continuations: usize) -> Result<Response> {
+        // Restores the messages even if this future is dropped mid-request
+        let guard = RestoreMessages { original: Some(self.request_body.messages.clone()), chat: self };
+        let mut combined: Option<Response> = None;
+        let mut remaining = max_continuations;
+
+        loop {
+            let response = guard.chat.chat().await?;
+            if !guard.chat.absorb_continuation(&mut combined, response) || remaining == 0 {
+                return Ok(combined.take().unwrap());
+            }
+            remaining -= 1;
+        }
+    }
+
+    /// Sends the chat completion request, aborting it when `cancel` resolves
+    ///
+    /// Use this to let users stop a generation, e.g. with a "stop" button.
+    /// `cancel` can be any future, such as `CancellationToken::cancelled()` from
+    /// `tokio-util`, a `tokio::sync::oneshot::Receiver`, or `tokio::signal::ctrl_c()`.
+    /// When it resolves first, the in-flight HTTP request is dropped, which closes
+    /// the connection, and [`OpenAIToolError::Cancelled`] is returned.
+    ///
+    /// [`chat`](Self::chat) itself is cancellation-safe, so racing it in your own
+    /// `tokio::select!` is equivalent.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancel` - A future that resolves when the request should be aborted
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response, or `OpenAIToolError::Cancelled`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::chat::request::ChatCompletion;
+    /// use openai_tools::common::errors::OpenAIToolError;
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    /// // Hand `stop` to the UI; sending on it aborts the request
+    /// # drop(stop);
+    ///
+    /// let mut chat = ChatCompletion::new();
+    /// chat.messages(vec![Message::from_string(Role::User, "Write a long story.")]);
+    /// match chat.chat_cancellable(stopped).await {
+    ///     Ok(response) => println!("{:?}", response.texts()),
+    ///     Err(OpenAIToolError::Cancelled) => println!("Stopped by the user"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn chat_cancellable<C: Future>(&mut self, cancel: C) -> Result<Response> {
+        run_cancellable(self.chat(), cancel).await
+    }
+
+    /// Merges `response` into `combined` and prepares the next continuation request
+    ///
+    /// Returns `true` if the response was truncated and another request is needed,
+    /// in which case the partial assistant message has been appended to the messages.
+    fn absorb_continuation(&mut self, combined: &mut Option<Response>, response: Response) -> bool {
+        let partial = response.choices.first().and_then(|choice| choice.message.content.as_ref()).and_then(|c| c.text.clone()).unwrap_or_default();
+        let truncated = response.choices.first().map(|choice| choice.finish_reason == FinishReason::Length).unwrap_or(false);
+
+        match combined {
+            None => *combined = Some(response),
+            Some(acc) => {
+                if let (Some(acc_choice), Some(choice)) = (acc.choices.first_mut(), response.choices.into_iter().next()) {
+                    let text = acc_choice.message.content.as_ref().and_then(|c| c.text.clone()).unwrap_or_default() + &partial;
+                    acc_choice.message = Message::from_string(Role::Assistant, text);
+                    acc_choice.finish_reason = choice.finish_reason;
+                }
+                let sum = |a: Option<usize>, b: Option<usize>| match (a, b) {
+                    (None, None) => None,
+                    (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+                };
+                acc.usage.prompt_tokens = sum(acc.usage.prompt_tokens, response.usage.prompt_tokens);
+                acc.usage.completion_tokens = sum(acc.usage.completion_tokens, response.usage.completion_tokens);
+                acc.usage.total_tokens = sum(acc.usage.total_tokens, response.usage.total_tokens);
             }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
         }
 
-        serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
+        if truncated && !partial.is_empty() {
+            self.request_body.messages.push(Message::from_string(Role::Assistant, partial));
+            true
+        } else {
+            false
+        }
     }
 
     /// Creates a test-only ChatCompletion instance without authentication
@@ -1417,7 +2280,16 @@ impl ChatCompletion {
     #[cfg(test)]
     pub(crate) fn test_new_with_model(model: ChatModel) -> Self {
         use crate::common::auth::OpenAIAuth;
-        Self { auth: AuthProvider::OpenAI(OpenAIAuth::new("test-key")), request_body: Body { model, ..Default::default() }, timeout: None }
+        Self {
+            auth: AuthProvider::OpenAI(OpenAIAuth::new("test-key")),
+            request_body: Body { model, ..Default::default() },
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            cache: None,
+            transport: None,
+            parameter_support: ParameterSupportTable::new(),
+        }
     }
 }
 
@@ -1425,8 +2297,36 @@ impl ChatCompletion {
 mod tests {
     use super::*;
     use crate::common::models::ChatModel;
+    use crate::common::test_utils::null_paths;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_serialized_body_has_no_null_fields() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        let mut schema = Schema::chat_json_schema("answer");
+        schema.add_property("value", "string", "");
+        let mut image_only = Message::from_string(Role::User, "");
+        image_only.content = Some(Content::from_image_url("https://example.com/a.png"));
+        chat.messages(vec![
+            Message::from_string(Role::User, "Describe this image"),
+            image_only,
+            Message::from_message_array(Role::User, vec![Content { type_name: "input_text".into(), text: None, image_url: None, input_audio: None }]),
+        ])
+        .tools(vec![Tool::function(
+            "lookup",
+            "Look something up",
+            vec![("q", crate::common::parameters::ParameterProperty::from_string("Query"))],
+            false,
+        )])
+        .json_schema(schema)
+        .max_completion_tokens(100);
+
+        let value = serde_json::to_value(&chat.request_body).unwrap();
+        assert_eq!(null_paths(&value, "$"), Vec::<String>::new());
+        assert_eq!(value["messages"][1]["content"][0]["image_url"]["url"], "https://example.com/a.png");
+        assert!(value.get("temperature").is_none());
+    }
+
     // =============================================================================
     // Standard Model Parameter Tests
     // =============================================================================
@@ -1556,6 +2456,99 @@ mod tests {
         assert_eq!(chat.request_body.top_logprobs, None);
     }
 
+    #[test]
+    fn test_logit_bias_with_token_ids() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.logit_bias(HashMap::from([(1734u32, -100.0f32), (2085u32, 5.5f32)]));
+
+        let logit_bias = chat.request_body.logit_bias.clone().unwrap();
+        assert_eq!(logit_bias.get("1734"), Some(&-100.0));
+        assert_eq!(logit_bias.get("2085"), Some(&5.5));
+
+        let json = serde_json::to_value(&chat.request_body).unwrap();
+        assert_eq!(json["logit_bias"]["1734"], -100.0);
+    }
+
+    #[test]
+    fn test_logit_bias_ignores_out_of_range_values() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.logit_bias(HashMap::from([(1u32, 100.0f32), (2u32, -100.5f32), (3u32, 150.0f32)]));
+
+        let logit_bias = chat.request_body.logit_bias.clone().unwrap();
+        assert_eq!(logit_bias.len(), 1);
+        assert_eq!(logit_bias.get("1"), Some(&100.0));
+    }
+
+    #[test]
+    fn test_logit_bias_for_tokens() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.logit_bias_for_tokens(ChatModel::Gpt4oMini, HashMap::from([("Hello world".to_string(), -50.0)]));
+
+        let expected = tiktoken_rs::o200k_base().unwrap().encode_with_special_tokens("Hello world");
+        let logit_bias = chat.request_body.logit_bias.clone().unwrap();
+        assert_eq!(logit_bias.len(), expected.len());
+        for token in expected {
+            assert_eq!(logit_bias.get(&token.to_string()), Some(&-50.0));
+        }
+    }
+
+    #[test]
+    fn test_chat_until_complete_merges_length_then_stop_sequence() {
+        fn mock_response(id: &str, text: &str, finish_reason: &str, completion_tokens: usize) -> Response {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "object": "chat.completion",
+                "created": 0,
+                "model": "gpt-4o-mini",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": text},
+                    "logprobs": null,
+                    "finish_reason": finish_reason,
+                    "refusal": null,
+                    "annotations": null
+                }],
+                "usage": {"prompt_tokens": 10, "completion_tokens": completion_tokens, "total_tokens": 10 + completion_tokens},
+                "service_tier": null,
+                "system_fingerprint": null
+            }))
+            .unwrap()
+        }
+
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::User, "Tell me a story.")]);
+
+        let mut combined = None;
+        assert!(chat.absorb_continuation(&mut combined, mock_response("a", "Once upon ", "length", 2)));
+        assert_eq!(chat.request_body.messages.len(), 2);
+        assert_eq!(chat.request_body.messages[1].role, Role::Assistant);
+
+        assert!(chat.absorb_continuation(&mut combined, mock_response("b", "a time, ", "length", 3)));
+        assert_eq!(chat.request_body.messages.len(), 3);
+
+        assert!(!chat.absorb_continuation(&mut combined, mock_response("c", "the end.", "stop", 2)));
+        assert_eq!(chat.request_body.messages.len(), 3);
+
+        let combined = combined.unwrap();
+        assert_eq!(combined.id, "a");
+        assert_eq!(combined.choices[0].finish_reason, FinishReason::Stop);
+        assert_eq!(combined.choices[0].message.content.as_ref().unwrap().text.as_deref(), Some("Once upon a time, the end."));
+        assert_eq!(combined.usage.prompt_tokens, Some(30));
+        assert_eq!(combined.usage.completion_tokens, Some(7));
+        assert_eq!(combined.usage.total_tokens, Some(37));
+    }
+
+    #[test]
+    fn test_chat_preview() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::System, "Be brief."), Message::from_string(Role::User, "Hello, world!")]);
+
+        let preview = chat.preview();
+        assert_eq!(preview, RequestPreview::estimate(&ChatModel::Gpt4oMini, None, &chat.request_body.messages));
+        // 11 tokens for the user message and reply priming, plus the system message
+        assert!(preview.estimated_prompt_tokens > 11);
+    }
+
     #[test]
     fn test_o1_ignores_logit_bias() {
         let mut chat = ChatCompletion::test_new_with_model(ChatModel::O1);
@@ -1739,6 +2732,76 @@ mod tests {
         assert_eq!(chat.request_body.n, Some(2));
     }
 
+    // =============================================================================
+    // Model Validation Tests
+    // =============================================================================
+
+    #[test]
+    fn test_validate_for_model_rejects_parameters_set_before_model_change() {
+        type SetParameter = fn(&mut ChatCompletion);
+        let cases: [(&str, SetParameter); 7] = [
+            ("temperature", |chat| {
+                chat.temperature(0.5);
+            }),
+            ("frequency_penalty", |chat| {
+                chat.frequency_penalty(0.5);
+            }),
+            ("presence_penalty", |chat| {
+                chat.presence_penalty(0.5);
+            }),
+            ("logprobs", |chat| {
+                chat.logprobs(true);
+            }),
+            ("top_logprobs", |chat| {
+                chat.top_logprobs(3);
+            }),
+            ("logit_bias", |chat| {
+                chat.logit_bias(HashMap::from([(1234u32, 10.0f32)]));
+            }),
+            ("n", |chat| {
+                chat.n(2);
+            }),
+        ];
+        for (param, set) in cases {
+            let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+            chat.messages(vec![Message::from_string(Role::User, "Hi")]);
+            set(&mut chat);
+            assert!(chat.validate_for_model().is_ok(), "{} should be accepted by gpt-4o-mini", param);
+
+            chat.model(ChatModel::O1);
+            match chat.build_request_json() {
+                Err(OpenAIToolError::UnsupportedParameter { model, param: rejected }) => {
+                    assert_eq!((model.as_str(), rejected.as_str()), ("o1", param));
+                }
+                other => panic!("expected {} to be rejected, got {:?}", param, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_for_model_accepts_default_values() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]).temperature(1.0).frequency_penalty(0.0).n(1);
+        chat.model(ChatModel::O3Mini);
+        assert!(chat.validate_for_model().is_ok());
+    }
+
+    #[test]
+    fn test_parameter_support_table_overrides_builtin_support() {
+        use crate::common::models::{ParameterSupport, ParameterSupportTable};
+
+        let mut table = ParameterSupportTable::new();
+        table.set("my-reasoner", ParameterSupport::reasoning_model());
+
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::custom("my-reasoner"));
+        chat.parameter_support_table(table);
+        chat.temperature(0.5);
+        assert_eq!(chat.request_body.temperature, None);
+
+        chat.request_body.temperature = Some(0.5);
+        assert!(matches!(chat.validate_for_model(), Err(OpenAIToolError::UnsupportedParameter { .. })));
+    }
+
     // =============================================================================
     // Parameter Boundary Tests
     // =============================================================================
@@ -1804,6 +2867,138 @@ mod tests {
         assert_eq!(chat_gpt5.request_body.max_completion_tokens, Some(3000));
     }
 
+    #[test]
+    fn test_max_tokens_is_sent_as_max_completion_tokens() {
+        for model in [ChatModel::Gpt4oMini, ChatModel::O1, ChatModel::Gpt5Mini] {
+            let mut chat = ChatCompletion::test_new_with_model(model);
+            chat.messages(vec![Message::from_string(Role::User, "Hi")]).max_tokens(512);
+
+            let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+            assert_eq!(json["max_completion_tokens"], 512);
+            assert!(json.get("max_tokens").is_none());
+        }
+    }
+
+    #[test]
+    fn test_metadata_serialized_only_when_set() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]);
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert!(json.get("metadata").is_none());
+        assert!(json.get("store").is_none());
+
+        chat.store(true).metadata("run", "eval-1").metadata("run", "eval-2").metadata("team", "search");
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["store"], true);
+        assert_eq!(json["metadata"], serde_json::json!({ "run": "eval-2", "team": "search" }));
+
+        chat.metadata_map(HashMap::from([("env".to_string(), "prod".to_string())]));
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["metadata"], serde_json::json!({ "env": "prod" }));
+    }
+
+    #[test]
+    fn test_prompt_cache_key_serialized_only_when_set() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]);
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert!(json.get("prompt_cache_key").is_none());
+
+        chat.prompt_cache_key("support-bot-v3");
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["prompt_cache_key"], "support-bot-v3");
+    }
+
+    #[tokio::test]
+    async fn test_service_tier_is_sent_and_reported() {
+        let body = r#"{
+            "id": "chatcmpl-1", "object": "chat.completion", "created": 1700000000, "model": "gpt-4o-mini",
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hi"}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2},
+            "service_tier": "flex"
+        }"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, body.to_string())]).await;
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        let response = chat.messages(vec![Message::from_string(Role::User, "Hi")]).service_tier(ServiceTier::Flex).chat().await.unwrap();
+
+        let requests = server.await.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(sent["service_tier"], "flex");
+        assert_eq!(response.served_tier(), Some(ServiceTier::Flex));
+        assert_eq!("priority".parse::<ServiceTier>().unwrap(), ServiceTier::Priority);
+    }
+
+    #[tokio::test]
+    async fn test_audio_output_request_and_response() {
+        let body = r#"{
+            "id": "chatcmpl-1", "object": "chat.completion", "created": 1700000000, "model": "gpt-4o-audio-preview",
+            "choices": [{"index": 0, "finish_reason": "stop", "message": {
+                "role": "assistant", "content": null,
+                "audio": {"id": "audio_abc", "data": "UklGRg==", "transcript": "Hello there!", "expires_at": 1700003600}
+            }}],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+        }"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, body.to_string())]).await;
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        chat.model(ChatModel::Gpt4oAudioPreview).audio_output(Voice::Alloy, AudioOutputFormat::Wav);
+        let response = chat.messages(vec![Message::from_string(Role::User, "Say hello")]).chat().await.unwrap();
+
+        let requests = server.await.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(sent["modalities"], serde_json::json!(["text", "audio"]));
+        assert_eq!(sent["audio"], serde_json::json!({ "voice": "alloy", "format": "wav" }));
+
+        let message = &response.choices[0].message;
+        let audio = message.audio.as_ref().unwrap();
+        assert_eq!(audio.transcript, "Hello there!");
+        assert_eq!(audio.decode().unwrap(), b"RIFF");
+
+        // Follow-up turns refer to the earlier audio by ID only
+        chat.add_message(message.clone());
+        let follow_up: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(follow_up["messages"][1]["audio"], serde_json::json!({ "id": "audio_abc" }));
+        assert_eq!(serde_json::to_value(message).unwrap()["audio"], serde_json::json!({ "id": "audio_abc" }));
+
+        chat.modalities(vec![Modality::Audio]).audio_output(Voice::Nova, AudioOutputFormat::Pcm16);
+        assert_eq!(chat.request_body.modalities, Some(vec!["audio".to_string()]));
+    }
+
+    /// Starts a server that accepts connections but never responds
+    async fn serve_hanging() -> (String, tokio::task::JoinHandle<()>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                connections.push(stream);
+            }
+        });
+        (base_url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_chat_cancellable_aborts_in_flight_request() {
+        let (base_url, server) = serve_hanging().await;
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        chat.messages(vec![Message::from_string(Role::User, "Write a long story.")]);
+
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let _ = stop.send(());
+        });
+        let err = chat.chat_cancellable(stopped).await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::Cancelled), "unexpected error: {:?}", err);
+
+        // Dropping chat_until_complete mid-request still restores the messages
+        chat.add_message(Message::from_string(Role::Assistant, "Once upon a time"));
+        let before = chat.request_body.messages.len();
+        let result = tokio::time::timeout(Duration::from_millis(50), chat.chat_until_complete(2)).await;
+        assert!(result.is_err());
+        assert_eq!(chat.request_body.messages.len(), before);
+        server.abort();
+    }
+
     #[test]
     fn test_store_accepted_by_all_models() {
         let mut chat_standard = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
@@ -1844,6 +3039,18 @@ mod tests {
         assert_eq!(json["image_url"]["url"], "https://example.com/image.png");
     }
 
+    #[test]
+    fn test_chat_audio_content_serialization() {
+        use crate::common::message::{Content, Message};
+        use crate::common::role::Role;
+
+        let message =
+            Message::from_message_array(Role::User, vec![Content::from_text("Answer the question"), Content::from_audio_bytes(b"RIFF", "wav")]);
+        let json = serde_json::to_value(ChatMessageRef(&message)).unwrap();
+
+        assert_eq!(json["content"][1], serde_json::json!({ "type": "input_audio", "input_audio": { "data": "UklGRg==", "format": "wav" } }));
+    }
+
     #[test]
     fn test_chat_multimodal_message_serialization() {
         use crate::common::message::{Content, Message};
@@ -1939,4 +3146,236 @@ mod tests {
         let json = serde_json::to_value(&chat.request_body).unwrap();
         assert!(json.get("safety_identifier").is_none());
     }
+
+    #[test]
+    fn test_assert_cache_friendly_flags_system_after_dynamic_content() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![
+            Message::from_string(Role::User, "What's the weather in Tokyo?"),
+            Message::from_string(Role::System, "You are a helpful assistant."),
+        ]);
+
+        let issues = chat.assert_cache_friendly();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("index 1"));
+    }
+
+    #[test]
+    fn test_tool_parameters_serialize_in_a_stable_order() {
+        use crate::common::parameters::ParameterProperty;
+
+        let weather = || {
+            let properties = ["city", "unit", "days", "lang"].map(|name| (name, ParameterProperty::from_string(name)));
+            Tool::function("weather", "Get the weather", properties.to_vec(), false)
+        };
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::System, "You are a helpful assistant."), Message::from_string(Role::User, "Hi")])
+            .tools(vec![weather()]);
+        assert!(chat.assert_cache_friendly().is_empty());
+
+        // Tools built separately serialize byte-identically, so the cached prefix matches
+        let first = serde_json::to_string(&chat.request_body.tools).unwrap();
+        for _ in 0..10 {
+            chat.tools(vec![weather()]);
+            assert_eq!(serde_json::to_string(&chat.request_body.tools).unwrap(), first);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_api_error_carries_request_id() {
+        let body = r#"{"error": {"message": "Invalid model", "type": "invalid_request_error", "param": "model", "code": "model_not_found"}}"#;
+        let (base_url, server) =
+            crate::common::mock_server::serve_with_headers(vec![(404, vec![("x-request-id", "req_abc123".to_string())], body.to_string())]).await;
+
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]);
+        let err = chat.chat().await.unwrap_err();
+
+        assert_eq!(err.request_id(), Some("req_abc123"));
+        assert!(matches!(err, OpenAIToolError::ApiError { status: 404, .. }));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_chat_content_policy_violation() {
+        let transport = crate::common::transport::MockTransport::new();
+        transport.push_response(
+            400,
+            r#"{"error": {"message": "Your request was rejected as a result of our safety system.", "type": "invalid_request_error", "code": "content_policy_violation"}}"#,
+        );
+
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.with_transport(transport).messages(vec![Message::from_string(Role::User, "Hi")]);
+        let err = chat.chat().await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::ContentPolicyViolation { .. }), "unexpected error: {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_chat_timeout_returns_timeout_error() {
+        // Accept the connection but never answer
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            drop(socket);
+        });
+
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]).timeout(Duration::from_millis(200));
+        let err = chat.chat().await.unwrap_err();
+
+        assert!(matches!(err, OpenAIToolError::Timeout(_)), "unexpected error: {:?}", err);
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_chat_with_cache_serves_identical_requests_from_cache() {
+        let completion = |id: &str| {
+            format!(
+                r#"{{"id": "{}", "object": "chat.completion", "created": 1677652288, "model": "gpt-4o-mini",
+                    "choices": [{{"index": 0, "message": {{"role": "assistant", "content": "Hello!"}}, "finish_reason": "stop"}}],
+                    "usage": {{"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7}}}}"#,
+                id
+            )
+        };
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, completion("chatcmpl-1")), (200, completion("chatcmpl-2"))]).await;
+
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        chat.with_cache(crate::chat::cache::InMemoryResponseCache::new()).messages(vec![Message::from_string(Role::User, "Hi")]).temperature(0.0);
+
+        assert_eq!(chat.chat().await.unwrap().id, "chatcmpl-1");
+        assert_eq!(chat.chat().await.unwrap().id, "chatcmpl-1");
+
+        // A different request body is a cache miss
+        chat.temperature(0.5);
+        assert_eq!(chat.chat().await.unwrap().id, "chatcmpl-2");
+
+        assert_eq!(server.await.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_cache_key_ignores_map_iteration_order() {
+        let build = || {
+            let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+            chat.messages(vec![Message::from_string(Role::User, "Hi")]);
+            for (key, value) in [("user", "u1"), ("session", "s1"), ("topic", "demo"), ("tier", "free")] {
+                chat.metadata(key, value);
+            }
+            chat.logit_bias(HashMap::from([(50256, -100), (198, 5), (13, -5)]));
+            chat
+        };
+
+        let (first, second) = (build(), build());
+        let endpoint = "https://api.openai.com/v1/chat/completions";
+        assert_eq!(cache_key(endpoint, &first.request_body).unwrap(), cache_key(endpoint, &second.request_body).unwrap());
+    }
+
+    #[test]
+    fn test_build_request_json_matches_request_without_sending() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::O1);
+        assert!(matches!(chat.build_request_json(), Err(OpenAIToolError::MissingField("messages"))));
+
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]);
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+
+        assert_eq!(json["model"], "o1");
+        assert_eq!(json["messages"][0]["content"], "Hi");
+
+        // Model validation is applied, as in chat()
+        chat.request_body.temperature = Some(0.2);
+        assert!(matches!(chat.build_request_json(), Err(OpenAIToolError::UnsupportedParameter { .. })));
+        assert_eq!(chat.request_body.temperature, Some(0.2));
+    }
+
+    #[test]
+    fn test_strict_schema_is_sent_in_response_format() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        let mut schema = Schema::chat_json_schema("answer");
+        schema.add_property("answer", "string", "The answer");
+        schema.strict(true);
+        chat.messages(vec![Message::from_string(Role::User, "Hi")]).json_schema(schema);
+
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["response_format"]["type"], "json_schema");
+        assert_eq!(json["response_format"]["json_schema"]["strict"], true);
+        assert_eq!(json["response_format"]["json_schema"]["schema"]["required"], serde_json::json!(["answer"]));
+
+        chat.json_schema(Schema::responses_text_schema().strict(true).clone());
+        assert!(matches!(chat.build_request_json(), Err(OpenAIToolError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_messages_from_role_text_pairs() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages_from(vec![(Role::System, "Be brief.".to_string()), (Role::User, "Hello!".to_string())]);
+
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["messages"], serde_json::json!([{"role": "system", "content": "Be brief."}, {"role": "user", "content": "Hello!"}]));
+    }
+
+    #[test]
+    fn test_strict_tool_is_validated_before_sending() {
+        use crate::common::parameters::ParameterProperty;
+
+        let params = vec![("city", ParameterProperty::from_string("City name")), ("unit", ParameterProperty::from_string("Temperature unit"))];
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4oMini);
+        chat.messages(vec![Message::from_string(Role::User, "Weather in Tokyo?")]).tools(vec![Tool::function(
+            "get_weather",
+            "Get the weather",
+            params.clone(),
+            true,
+        )]);
+
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["tools"][0]["function"]["strict"], true);
+        assert_eq!(json["tools"][0]["function"]["parameters"]["additionalProperties"], false);
+
+        chat.tools(vec![Tool::function_with_required("get_weather", "Get the weather", params, vec!["city"], true)]);
+        let err = chat.build_request_json().unwrap_err();
+        assert!(err.to_string().contains("Strict tool 'get_weather' is invalid"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_chat_missing_fields_fail_before_sending() {
+        // No server is listening; a network attempt would yield a RequestError instead
+        let mut chat = ChatCompletion::with_url("http://127.0.0.1:9/v1", "sk-test");
+        let err = chat.chat().await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::MissingField("messages")), "unexpected error: {:?}", err);
+
+        chat.model(ChatModel::Custom(String::new())).messages(vec![Message::from_string(Role::User, "Hi")]);
+        let err = chat.chat().await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::MissingField("model")), "unexpected error: {:?}", err);
+        assert_eq!(err.to_string(), "Missing required field: model");
+    }
+
+    #[tokio::test]
+    async fn test_chat_n_returns_all_choice_texts() {
+        let body = r#"{"id": "chatcmpl-n", "object": "chat.completion", "created": 1677652288, "model": "gpt-4o-mini",
+            "choices": [
+                {"index": 0, "message": {"role": "assistant", "content": "Red"}, "finish_reason": "stop"},
+                {"index": 1, "message": {"role": "assistant", "content": "Green"}, "finish_reason": "stop"},
+                {"index": 2, "message": {"role": "assistant", "content": "Blue"}, "finish_reason": "stop"}
+            ],
+            "usage": {"prompt_tokens": 5, "completion_tokens": 3, "total_tokens": 8}}"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, body.to_string())]).await;
+
+        let mut chat = ChatCompletion::with_url(base_url, "sk-test".to_string());
+        chat.model(ChatModel::Gpt4oMini).messages(vec![Message::from_string(Role::User, "Name a color")]).n(3);
+        let response = chat.chat().await.unwrap();
+
+        assert_eq!(response.texts(), vec!["Red", "Green", "Blue"]);
+        let requests = server.await.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(sent["n"], 3);
+    }
+
+    #[test]
+    fn test_prediction_serialization() {
+        let mut chat = ChatCompletion::test_new_with_model(ChatModel::Gpt4o);
+        chat.messages(vec![Message::from_string(Role::User, "Rename x to count")]).prediction("let x = 0;");
+
+        let json: serde_json::Value = serde_json::from_str(&chat.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["prediction"], serde_json::json!({"type": "content", "content": "let x = 0;"}));
+    }
 }
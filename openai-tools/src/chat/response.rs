@@ -77,9 +77,14 @@
 //! assert_eq!(tool_calls[0].function.name, "get_weather");
 //! ```
 
+use crate::chat::request::ServiceTier;
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::structured_output::parse_structured_output;
 use crate::common::{message::Message, usage::Usage};
 use core::str;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Top log probability item for a token
 ///
@@ -115,6 +120,48 @@ pub struct LogProbs {
     pub content: Vec<LogProbItem>,
 }
 
+/// The reason why the model stopped generating tokens
+///
+/// Values the API adds in the future deserialize as [`FinishReason::Unknown`]
+/// and serialize back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishReason {
+    /// The model reached a natural stopping point or a stop sequence
+    Stop,
+    /// The output hit `max_completion_tokens` or the context window
+    Length,
+    /// The model called one or more tools
+    ToolCalls,
+    /// Content was omitted because of a content filter
+    ContentFilter,
+    /// The model called a function (deprecated `functions` API)
+    FunctionCall,
+    /// A reason not known to this library
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl FinishReason {
+    /// Returns the reason as sent by the API, e.g. `"tool_calls"`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Stop => "stop",
+            Self::Length => "length",
+            Self::ToolCalls => "tool_calls",
+            Self::ContentFilter => "content_filter",
+            Self::FunctionCall => "function_call",
+            Self::Unknown(reason) => reason,
+        }
+    }
+}
+
+impl std::fmt::Display for FinishReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Choice structure representing a single response option
 ///
 /// Each choice represents one possible completion generated by the model.
@@ -127,12 +174,36 @@ pub struct Choice {
     pub message: Message,
     /// Optional log probability information for this choice
     pub logprobs: Option<LogProbs>,
-    /// The reason why the generation finished (e.g., "stop", "length")
-    pub finish_reason: String,
+    /// The reason why the generation finished
+    pub finish_reason: FinishReason,
+    /// Optional refusal message if the model declined to respond
     pub refusal: Option<String>,
+    /// Optional list of annotations
     pub annotations: Option<Vec<String>>,
 }
 
+impl Choice {
+    /// Returns `true` if the model finished on its own, by stopping or by calling tools
+    ///
+    /// Returns `false` when the output was cut short by the token limit or a
+    /// content filter, or ended for an unknown reason. A common use is to
+    /// retry with a larger limit when this is `false` and the reason is
+    /// [`FinishReason::Length`].
+    pub fn finished_normally(&self) -> bool {
+        matches!(self.finish_reason, FinishReason::Stop | FinishReason::ToolCalls | FinishReason::FunctionCall)
+    }
+
+    /// Returns `true` if the model declined to respond
+    ///
+    /// A refused choice carries the explanation in
+    /// [`Message::refusal`](crate::common::message::Message::refusal) instead
+    /// of regular content, so it should be handled separately, e.g. instead of
+    /// parsing the content as structured output.
+    pub fn is_refusal(&self) -> bool {
+        self.message.refusal().is_some() || self.refusal.is_some()
+    }
+}
+
 /// Complete response structure from OpenAI Chat Completions API
 ///
 /// This structure contains all information returned by the OpenAI API,
@@ -155,4 +226,68 @@ pub struct Response {
     pub service_tier: Option<String>,
     /// Fingerprint representing the model configuration
     pub system_fingerprint: Option<String>,
+    /// Metadata attached to a stored completion (see [`StoredCompletions`](crate::chat::stored::StoredCompletions))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl Response {
+    /// Returns the service tier that actually processed the request
+    ///
+    /// This can differ from the tier requested with
+    /// [`ChatCompletion::service_tier`](crate::chat::request::ChatCompletion::service_tier),
+    /// e.g. `Auto` resolves to a concrete tier. Returns `None` if the response
+    /// has no tier or an unknown one; the raw value is in [`service_tier`](Self::service_tier).
+    pub fn served_tier(&self) -> Option<ServiceTier> {
+        self.service_tier.as_deref().and_then(|tier| tier.parse().ok())
+    }
+
+    /// Returns the content text of every choice, in the order returned by the API
+    ///
+    /// Useful with [`ChatCompletion::n`](crate::chat::request::ChatCompletion::n)
+    /// for best-of-n sampling. Choices without text content (e.g. tool calls
+    /// only) are skipped.
+    pub fn texts(&self) -> Vec<String> {
+        self.choices.iter().filter_map(|choice| choice.message.content.as_ref().and_then(|content| content.text.clone())).collect()
+    }
+
+    /// Deserializes the text of the first choice, e.g. a structured output
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The parsed content
+    /// * `Err(OpenAIToolError::SerdeJsonError)` - If the text does not match `T`; the message quotes the text
+    /// * `Err(OpenAIToolError::Error)` - If the first choice has no text, e.g. because the model refused
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use openai_tools::chat::response::Response;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Weather {
+    ///     city: String,
+    ///     celsius: f64,
+    /// }
+    ///
+    /// let response: Response = serde_json::from_str(r#"{
+    ///     "id": "chatcmpl-1", "object": "chat.completion", "created": 1677652288, "model": "gpt-4o-mini",
+    ///     "choices": [{"index": 0, "message": {"role": "assistant", "content": "{\"city\": \"Tokyo\", \"celsius\": 21.5}"}, "finish_reason": "stop"}],
+    ///     "usage": {"prompt_tokens": 10, "completion_tokens": 12, "total_tokens": 22}
+    /// }"#).unwrap();
+    ///
+    /// let weather: Weather = response.parse_content().unwrap();
+    /// assert_eq!(weather.city, "Tokyo");
+    /// ```
+    pub fn parse_content<T: DeserializeOwned>(&self) -> Result<T> {
+        let message = &self.choices.first().ok_or_else(|| OpenAIToolError::Error("Response has no choices.".into()))?.message;
+        match message.content.as_ref().and_then(|content| content.text.as_deref()) {
+            Some(text) => parse_structured_output(text),
+            None => match message.refusal() {
+                Some(refusal) => Err(OpenAIToolError::Error(format!("The model refused to respond: {}", refusal))),
+                None => Err(OpenAIToolError::Error("Response has no text content.".into())),
+            },
+        }
+    }
 }
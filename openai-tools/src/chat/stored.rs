@@ -0,0 +1,302 @@
+//! Retrieval of stored Chat Completions.
+//!
+//! Chat completions created with [`ChatCompletion::store`](crate::chat::request::ChatCompletion::store)
+//! set to `true` are kept by OpenAI and can later be fetched, listed, and deleted
+//! through `/v1/chat/completions`. This is useful for evaluation and analytics
+//! pipelines that work on past completions; tag them with
+//! [`ChatCompletion::metadata`](crate::chat::request::ChatCompletion::metadata)
+//! to find them again.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use openai_tools::chat::stored::StoredCompletions;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let stored = StoredCompletions::new()?;
+//!
+//!     let page = stored.list(Some(10), None, None).await?;
+//!     for completion in &page.data {
+//!         println!("{}: {:?}", completion.id, completion.metadata);
+//!     }
+//!
+//!     let completion = stored.retrieve("chatcmpl-abc123").await?;
+//!     println!("{:?}", completion.texts());
+//!
+//!     stored.delete("chatcmpl-abc123").await?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::chat::response::Response;
+use crate::common::auth::AuthProvider;
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::delete::DeleteResponse;
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
+use crate::common::pagination::{impl_paginated, Page};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default API path for stored Chat Completions
+const CHAT_COMPLETIONS_PATH: &str = "chat/completions";
+
+/// Response structure for listing stored chat completions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredCompletionListResponse {
+    /// Object type, always "list"
+    pub object: String,
+    /// The stored completions in this page
+    pub data: Vec<Response>,
+    /// ID of the first completion in this page
+    #[serde(default)]
+    pub first_id: Option<String>,
+    /// ID of the last completion in this page
+    #[serde(default)]
+    pub last_id: Option<String>,
+    /// Whether more completions are available
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl From<StoredCompletionListResponse> for Page<Response> {
+    fn from(response: StoredCompletionListResponse) -> Self {
+        Page { data: response.data, first_id: response.first_id, last_id: response.last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(StoredCompletionListResponse, Response);
+
+/// Response structure for stored chat completion deletion, with object type
+/// `"chat.completion.deleted"`.
+pub type DeleteStoredCompletionResponse = DeleteResponse;
+
+/// Client for retrieving, listing, and deleting stored chat completions.
+///
+/// Only completions created with `store(true)` are available.
+pub struct StoredCompletions {
+    /// Authentication provider (OpenAI or Azure)
+    auth: AuthProvider,
+    /// Optional request timeout duration
+    timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
+}
+
+impl StoredCompletions {
+    /// Creates a new StoredCompletions client for OpenAI API.
+    ///
+    /// Initializes the client by loading the OpenAI API key from
+    /// the environment variable `OPENAI_API_KEY`. Supports `.env` file loading
+    /// via dotenvy.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StoredCompletions)` - A new client ready for use
+    /// * `Err(OpenAIToolError)` - If the API key is not found in the environment
+    pub fn new() -> Result<Self> {
+        let auth = AuthProvider::openai_from_env()?;
+        Ok(Self { auth, timeout: None, http_client: None })
+    }
+
+    /// Creates a new StoredCompletions client with a custom authentication provider
+    pub fn with_auth(auth: AuthProvider) -> Self {
+        Self { auth, timeout: None, http_client: None }
+    }
+
+    /// Creates a new StoredCompletions client for Azure OpenAI API
+    pub fn azure() -> Result<Self> {
+        let auth = AuthProvider::azure_from_env()?;
+        Ok(Self { auth, timeout: None, http_client: None })
+    }
+
+    /// Creates a new StoredCompletions client by auto-detecting the provider
+    pub fn detect_provider() -> Result<Self> {
+        let auth = AuthProvider::from_env()?;
+        Ok(Self { auth, timeout: None, http_client: None })
+    }
+
+    /// Creates a new StoredCompletions client with URL-based provider detection
+    pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
+        let auth = AuthProvider::from_url_with_key(base_url, api_key);
+        Self { auth, timeout: None, http_client: None }
+    }
+
+    /// Creates a new StoredCompletions client from URL using environment variables
+    pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
+        let auth = AuthProvider::from_url(url)?;
+        Ok(Self { auth, timeout: None, http_client: None })
+    }
+
+    /// Returns the authentication provider
+    pub fn auth(&self) -> &AuthProvider {
+        &self.auth
+    }
+
+    /// Sets the request timeout duration.
+    ///
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The maximum time to wait for a response
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Creates the HTTP client with default headers.
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
+        let mut headers = request::header::HeaderMap::new();
+        self.auth.apply_headers(&mut headers)?;
+        headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
+        headers.insert("User-Agent", request::header::HeaderValue::from_static("openai-tools-rust"));
+        Ok((client, headers))
+    }
+
+    /// Sends a request and deserializes a successful response body.
+    async fn send<T: DeserializeOwned>(&self, request: request::RequestBuilder) -> Result<T> {
+        let response = request.send().await.map_err(OpenAIToolError::from)?;
+
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
+
+        if cfg!(test) {
+            tracing::info!("Response content: {}", content);
+        }
+
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
+        serde_json::from_str::<T>(&content).map_err(OpenAIToolError::SerdeJsonError)
+    }
+
+    /// Retrieves a stored chat completion.
+    ///
+    /// # Arguments
+    ///
+    /// * `completion_id` - The ID of the completion, e.g. `chatcmpl-abc123`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Response)` - The stored completion
+    /// * `Err(OpenAIToolError)` - If the completion is not found or the request fails
+    pub async fn retrieve(&self, completion_id: &str) -> Result<Response> {
+        let (client, headers) = self.create_client()?;
+        let url = format!("{}/{}", self.auth.endpoint(CHAT_COMPLETIONS_PATH), completion_id);
+        self.send(client.get(&url).headers(headers)).await
+    }
+
+    /// Lists stored chat completions.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of completions to return (default 20)
+    /// * `after` - Cursor for pagination (completion ID to start after)
+    /// * `model` - Only list completions generated by this model
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StoredCompletionListResponse)` - The list of completions
+    /// * `Err(OpenAIToolError)` - If the request fails
+    pub async fn list(&self, limit: Option<u32>, after: Option<&str>, model: Option<&str>) -> Result<StoredCompletionListResponse> {
+        let (client, headers) = self.create_client()?;
+
+        // Build query parameters
+        let mut params = Vec::new();
+        if let Some(l) = limit {
+            params.push(format!("limit={}", l));
+        }
+        if let Some(a) = after {
+            params.push(format!("after={}", a));
+        }
+        if let Some(m) = model {
+            params.push(format!("model={}", m));
+        }
+
+        let url = if params.is_empty() {
+            self.auth.endpoint(CHAT_COMPLETIONS_PATH)
+        } else {
+            format!("{}?{}", self.auth.endpoint(CHAT_COMPLETIONS_PATH), params.join("&"))
+        };
+
+        self.send(client.get(&url).headers(headers)).await
+    }
+
+    /// Deletes a stored chat completion.
+    ///
+    /// # Arguments
+    ///
+    /// * `completion_id` - The ID of the completion to delete
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DeleteStoredCompletionResponse)` - Confirmation of deletion
+    /// * `Err(OpenAIToolError)` - If the request fails
+    pub async fn delete(&self, completion_id: &str) -> Result<DeleteStoredCompletionResponse> {
+        let (client, headers) = self.create_client()?;
+        let url = format!("{}/{}", self.auth.endpoint(CHAT_COMPLETIONS_PATH), completion_id);
+        self.send(client.delete(&url).headers(headers)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::mock_server;
+
+    const COMPLETION_JSON: &str = r#"{
+        "id": "chatcmpl-abc123",
+        "object": "chat.completion",
+        "created": 1700000000,
+        "model": "gpt-4o-mini-2024-07-18",
+        "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hello!"}, "finish_reason": "stop"}],
+        "usage": {"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7},
+        "metadata": {"run": "eval-1"}
+    }"#;
+
+    #[tokio::test]
+    async fn test_retrieve_list_and_delete_stored_completions() {
+        let list = format!(
+            r#"{{"object": "list", "data": [{}], "first_id": "chatcmpl-abc123", "last_id": "chatcmpl-abc123", "has_more": true}}"#,
+            COMPLETION_JSON
+        );
+        let deleted = r#"{"id": "chatcmpl-abc123", "object": "chat.completion.deleted", "deleted": true}"#;
+        let (base_url, server) = mock_server::serve(vec![(200, COMPLETION_JSON.to_string()), (200, list), (200, deleted.to_string())]).await;
+        let stored = StoredCompletions::with_url(base_url, "sk-test".to_string());
+
+        let completion = stored.retrieve("chatcmpl-abc123").await.unwrap();
+        assert_eq!(completion.texts(), vec!["Hello!"]);
+        assert_eq!(completion.metadata.as_ref().unwrap()["run"], "eval-1");
+
+        let page = Page::from(stored.list(Some(1), Some("chatcmpl-xyz"), Some("gpt-4o-mini")).await.unwrap());
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.next_cursor(), Some("chatcmpl-abc123"));
+
+        assert!(stored.delete("chatcmpl-abc123").await.unwrap().deleted);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /v1/chat/completions/chatcmpl-abc123 HTTP/1.1");
+        assert_eq!(requests[1].request_line, "GET /v1/chat/completions?limit=1&after=chatcmpl-xyz&model=gpt-4o-mini HTTP/1.1");
+        assert_eq!(requests[2].request_line, "DELETE /v1/chat/completions/chatcmpl-abc123 HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_missing_completion_returns_api_error() {
+        let error = r#"{"error": {"message": "No completion found", "type": "invalid_request_error", "code": null}}"#;
+        let (base_url, _server) = mock_server::serve(vec![(404, error.to_string())]).await;
+        let stored = StoredCompletions::with_url(base_url, "sk-test".to_string());
+
+        let err = stored.retrieve("chatcmpl-missing").await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::ApiError { status: 404, .. }), "unexpected error: {:?}", err);
+    }
+}
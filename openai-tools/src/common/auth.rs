@@ -124,6 +124,10 @@ pub struct OpenAIAuth {
     api_key: String,
     /// Base URL for API requests (default: https://api.openai.com/v1)
     base_url: String,
+    /// Organization ID sent in the `OpenAI-Organization` header
+    organization: Option<String>,
+    /// Project ID sent in the `OpenAI-Project` header
+    project: Option<String>,
 }
 
 impl OpenAIAuth {
@@ -145,7 +149,7 @@ impl OpenAIAuth {
     /// let auth = OpenAIAuth::new("sk-your-api-key");
     /// ```
     pub fn new<T: Into<String>>(api_key: T) -> Self {
-        Self { api_key: api_key.into(), base_url: OPENAI_DEFAULT_BASE_URL.to_string() }
+        Self { api_key: api_key.into(), base_url: OPENAI_DEFAULT_BASE_URL.to_string(), organization: None, project: None }
     }
 
     /// Sets a custom base URL
@@ -173,6 +177,44 @@ impl OpenAIAuth {
         self
     }
 
+    /// Sets the organization used for usage attribution
+    ///
+    /// Sent as the `OpenAI-Organization` header on every request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::auth::OpenAIAuth;
+    ///
+    /// let auth = OpenAIAuth::new("sk-key")
+    ///     .with_organization("org-123")
+    ///     .with_project("proj_456");
+    /// assert_eq!(auth.organization(), Some("org-123"));
+    /// ```
+    pub fn with_organization<T: Into<String>>(mut self, organization: T) -> Self {
+        self.organization = Some(organization.into());
+        self
+    }
+
+    /// Sets the project used for usage attribution and access scoping
+    ///
+    /// Sent as the `OpenAI-Project` header on every request.
+    pub fn with_project<T: Into<String>>(mut self, project: T) -> Self {
+        self.project = Some(project.into());
+        self
+    }
+
+    /// Applies `OPENAI_ORG_ID` and `OPENAI_PROJECT_ID` from the environment, if set
+    fn with_env_scopes(mut self) -> Self {
+        if let Ok(organization) = env::var("OPENAI_ORG_ID") {
+            self.organization = Some(organization);
+        }
+        if let Ok(project) = env::var("OPENAI_PROJECT_ID") {
+            self.project = Some(project);
+        }
+        self
+    }
+
     /// Returns the API key
     pub fn api_key(&self) -> &str {
         &self.api_key
@@ -183,6 +225,16 @@ impl OpenAIAuth {
         &self.base_url
     }
 
+    /// Returns the organization ID, if set
+    pub fn organization(&self) -> Option<&str> {
+        self.organization.as_deref()
+    }
+
+    /// Returns the project ID, if set
+    pub fn project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
     /// Constructs the full endpoint URL for a given path
     ///
     /// # Arguments
@@ -198,12 +250,25 @@ impl OpenAIAuth {
 
     /// Applies authentication headers to a request
     ///
-    /// Adds the `Authorization: Bearer {key}` header.
+    /// Adds the `Authorization: Bearer {key}` header, plus the `OpenAI-Organization`
+    /// and `OpenAI-Project` headers when set.
     fn apply_headers(&self, headers: &mut HeaderMap) -> Result<()> {
         headers.insert(
             "Authorization",
             HeaderValue::from_str(&format!("Bearer {}", self.api_key)).map_err(|e| OpenAIToolError::Error(format!("Invalid header value: {}", e)))?,
         );
+        if let Some(organization) = &self.organization {
+            headers.insert(
+                "OpenAI-Organization",
+                HeaderValue::from_str(organization).map_err(|e| OpenAIToolError::Error(format!("Invalid header value: {}", e)))?,
+            );
+        }
+        if let Some(project) = &self.project {
+            headers.insert(
+                "OpenAI-Project",
+                HeaderValue::from_str(project).map_err(|e| OpenAIToolError::Error(format!("Invalid header value: {}", e)))?,
+            );
+        }
         Ok(())
     }
 }
@@ -323,6 +388,8 @@ impl AuthProvider {
     /// | Variable | Required | Description |
     /// |----------|----------|-------------|
     /// | `OPENAI_API_KEY` | Yes | OpenAI API key |
+    /// | `OPENAI_ORG_ID` | No | Organization sent as `OpenAI-Organization` |
+    /// | `OPENAI_PROJECT_ID` | No | Project sent as `OpenAI-Project` |
     ///
     /// # Example
     ///
@@ -335,7 +402,7 @@ impl AuthProvider {
     pub fn openai_from_env() -> Result<Self> {
         dotenv().ok();
         let api_key = env::var("OPENAI_API_KEY").map_err(|_| OpenAIToolError::Error("OPENAI_API_KEY environment variable not set".into()))?;
-        Ok(Self::OpenAI(OpenAIAuth::new(api_key)))
+        Ok(Self::OpenAI(OpenAIAuth::new(api_key).with_env_scopes()))
     }
 
     /// Creates an Azure OpenAI authentication provider from environment variables
@@ -571,6 +638,7 @@ impl AuthProvider {
     ///
     /// For other URLs:
     /// - `OPENAI_API_KEY` (required)
+    /// - `OPENAI_ORG_ID`, `OPENAI_PROJECT_ID` (optional)
     ///
     /// # Example
     ///
@@ -600,7 +668,7 @@ impl AuthProvider {
             // OpenAI: get credentials from OpenAI env var
             let api_key = env::var("OPENAI_API_KEY").map_err(|_| OpenAIToolError::Error("OPENAI_API_KEY environment variable not set".into()))?;
 
-            Ok(Self::OpenAI(OpenAIAuth::new(api_key).with_base_url(url_str)))
+            Ok(Self::OpenAI(OpenAIAuth::new(api_key).with_base_url(url_str).with_env_scopes()))
         }
     }
 }
@@ -640,6 +708,21 @@ mod tests {
         assert_eq!(headers.get("Authorization").unwrap(), "Bearer sk-test-key");
     }
 
+    #[test]
+    fn test_openai_apply_headers_with_organization_and_project() {
+        let auth = OpenAIAuth::new("sk-test-key").with_organization("org-123").with_project("proj_456");
+        let mut headers = HeaderMap::new();
+        auth.apply_headers(&mut headers).unwrap();
+
+        assert_eq!(headers.get("OpenAI-Organization").unwrap(), "org-123");
+        assert_eq!(headers.get("OpenAI-Project").unwrap(), "proj_456");
+
+        let mut headers = HeaderMap::new();
+        OpenAIAuth::new("sk-test-key").apply_headers(&mut headers).unwrap();
+        assert!(headers.get("OpenAI-Organization").is_none());
+        assert!(headers.get("OpenAI-Project").is_none());
+    }
+
     #[test]
     fn test_openai_endpoint_trailing_slash_handling() {
         let auth = OpenAIAuth::new("key").with_base_url("https://example.com/v1/");
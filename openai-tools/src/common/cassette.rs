@@ -0,0 +1,308 @@
+//! Record/replay transport for deterministic integration tests.
+//!
+//! A [`CassetteTransport`] in record mode forwards every request to an inner
+//! [`Transport`] (usually the real API) and saves the request/response pairs to
+//! a JSON "cassette" file. In replay mode it serves the saved responses back
+//! without network access, so tests exercise real response shapes
+//! deterministically. Re-recording a cassette and diffing it is a cheap way to
+//! spot API schema drift.
+//!
+//! Credentials are never written to the cassette: the `Authorization` and
+//! `api-key` headers are redacted when recording.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use openai_tools::chat::request::ChatCompletion;
+//! use openai_tools::common::cassette::CassetteTransport;
+//! use openai_tools::common::client::create_http_client;
+//! use openai_tools::common::message::Message;
+//! use openai_tools::common::role::Role;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let path = "tests/cassettes/chat_hello.json";
+//! let transport = if std::env::var("OPENAI_TOOLS_RECORD").is_ok() {
+//!     CassetteTransport::record(path, create_http_client(None)?)
+//! } else {
+//!     CassetteTransport::replay(path)?
+//! };
+//!
+//! let mut chat = ChatCompletion::new();
+//! chat.with_transport(transport).messages(vec![Message::from_string(Role::User, "Hello")]);
+//! let response = chat.chat().await?;
+//! assert!(!response.texts().is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::transport::{HttpRequest, HttpResponse, Transport};
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Headers whose values are replaced by [`REDACTED`] when recording.
+const SENSITIVE_HEADERS: [&str; 2] = ["authorization", "api-key"];
+
+/// Placeholder written in place of sensitive header values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The HTTP method, e.g. "POST"
+    pub method: String,
+    /// The full request URL
+    pub url: String,
+    /// The request headers, with credentials redacted
+    #[serde(default)]
+    pub request_headers: BTreeMap<String, String>,
+    /// The request body, if any
+    #[serde(default)]
+    pub request_body: Option<String>,
+    /// The HTTP status code of the response
+    pub status: u16,
+    /// The response headers
+    #[serde(default)]
+    pub response_headers: BTreeMap<String, String>,
+    /// The response body
+    pub response_body: String,
+}
+
+impl Interaction {
+    /// Returns `true` if this interaction was recorded for `request`.
+    ///
+    /// The method and URL must be equal. Bodies are compared as JSON when both
+    /// parse, so formatting and key order do not matter, and as text otherwise.
+    fn matches(&self, request: &HttpRequest) -> bool {
+        if self.method != request.method.as_str() || self.url != request.url {
+            return false;
+        }
+        match (self.request_body.as_deref(), request.body.as_deref()) {
+            (Some(recorded), Some(sent)) => {
+                match (serde_json::from_str::<serde_json::Value>(recorded), serde_json::from_str::<serde_json::Value>(sent)) {
+                    (Ok(recorded), Ok(sent)) => recorded == sent,
+                    _ => recorded == sent,
+                }
+            }
+            (recorded, sent) => recorded == sent,
+        }
+    }
+
+    /// Converts the recorded response back into an [`HttpResponse`].
+    fn to_response(&self) -> Result<HttpResponse> {
+        let mut response = HttpResponse::new(self.status, self.response_body.clone())?;
+        for (name, value) in &self.response_headers {
+            if let (Ok(name), Ok(value)) = (request::header::HeaderName::from_bytes(name.as_bytes()), request::header::HeaderValue::from_str(value)) {
+                response.headers.insert(name, value);
+            }
+        }
+        Ok(response)
+    }
+}
+
+/// Converts headers to a sorted map, redacting credentials.
+fn header_map(headers: &request::header::HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// Whether a [`CassetteTransport`] records or replays.
+#[derive(Debug)]
+enum Mode {
+    /// Forwards requests to the inner transport and saves the interactions
+    Record(Box<dyn Transport>),
+    /// Serves responses from the cassette
+    Replay,
+}
+
+/// A [`Transport`] that records interactions to, or replays them from, a JSON file.
+///
+/// Clones share the same cassette.
+#[derive(Debug, Clone)]
+pub struct CassetteTransport {
+    inner: Arc<CassetteState>,
+}
+
+#[derive(Debug)]
+struct CassetteState {
+    path: PathBuf,
+    mode: Mode,
+    /// The interactions, and in replay mode whether each one has been served
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl CassetteTransport {
+    /// Creates a transport that forwards requests to `inner` and records them to `path`.
+    ///
+    /// The cassette is (re)written after every request, replacing any existing file;
+    /// missing parent directories are created.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The cassette file to write
+    /// * `inner` - The transport performing the real requests, e.g. a `reqwest::Client`
+    pub fn record<P: AsRef<Path>, T: Transport + 'static>(path: P, inner: T) -> Self {
+        Self::with_state(path.as_ref().to_path_buf(), Mode::Record(Box::new(inner)), Vec::new())
+    }
+
+    /// Creates a transport that serves the responses recorded in `path`.
+    ///
+    /// Each request is answered by the first not yet served interaction with
+    /// the same method, URL and body. If every matching interaction has
+    /// already been served, the last one is served again.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CassetteTransport)` - The replaying transport
+    /// * `Err(OpenAIToolError)` - If the cassette cannot be read or parsed
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let content =
+            std::fs::read_to_string(&path).map_err(|e| OpenAIToolError::Error(format!("Failed to read cassette {}: {}", path.display(), e)))?;
+        let interactions = serde_json::from_str::<Vec<Interaction>>(&content)?;
+        Ok(Self::with_state(path, Mode::Replay, interactions))
+    }
+
+    fn with_state(path: PathBuf, mode: Mode, interactions: Vec<Interaction>) -> Self {
+        let interactions = interactions.into_iter().map(|interaction| (interaction, false)).collect();
+        Self { inner: Arc::new(CassetteState { path, mode, interactions: Mutex::new(interactions) }) }
+    }
+
+    /// Returns the path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Returns the recorded (or loaded) interactions.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.inner.interactions.lock().unwrap_or_else(|e| e.into_inner()).iter().map(|(interaction, _)| interaction.clone()).collect()
+    }
+
+    /// Writes the interactions to the cassette file.
+    fn save(&self) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.interactions())?;
+        if let Some(parent) = self.inner.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| OpenAIToolError::Error(format!("Failed to create {}: {}", parent.display(), e)))?;
+        }
+        std::fs::write(&self.inner.path, json)
+            .map_err(|e| OpenAIToolError::Error(format!("Failed to write cassette {}: {}", self.inner.path.display(), e)))
+    }
+
+    /// Serves the recorded response for `request`.
+    fn replay_response(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let mut interactions = self.inner.interactions.lock().unwrap_or_else(|e| e.into_inner());
+        let index = interactions
+            .iter()
+            .position(|(interaction, served)| !served && interaction.matches(request))
+            .or_else(|| interactions.iter().rposition(|(interaction, _)| interaction.matches(request)))
+            .ok_or_else(|| {
+                OpenAIToolError::Error(format!("Cassette {} has no interaction for {} {}", self.inner.path.display(), request.method, request.url))
+            })?;
+        interactions[index].1 = true;
+        interactions[index].0.to_response()
+    }
+}
+
+impl Transport for CassetteTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let inner = match &self.inner.mode {
+                Mode::Replay => return self.replay_response(&request),
+                Mode::Record(inner) => inner,
+            };
+
+            let method = request.method.to_string();
+            let url = request.url.clone();
+            let request_headers = header_map(&request.headers);
+            let request_body = request.body.clone();
+            let response = inner.send(request).await?;
+
+            let interaction = Interaction {
+                method,
+                url,
+                request_headers,
+                request_body,
+                status: response.status.as_u16(),
+                response_headers: header_map(&response.headers),
+                response_body: response.body.clone(),
+            };
+            self.inner.interactions.lock().unwrap_or_else(|e| e.into_inner()).push((interaction, true));
+            self.save()?;
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::mock_server;
+    use crate::responses::request::Responses;
+
+    const COMPLETED: &str = r#"{"id": "resp_1", "object": "response", "status": "completed", "output": [
+        {"type": "message", "id": "msg_1", "role": "assistant", "content": [{"type": "output_text", "text": "Hello!"}]}
+    ]}"#;
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let path = std::env::temp_dir().join(format!("openai-tools-cassette-{}.json", std::process::id()));
+        let (base_url, _server) = mock_server::serve(vec![(200, COMPLETED.to_string())]).await;
+
+        let mut responses = Responses::with_url(base_url.clone(), "sk-secret".to_string());
+        responses.with_transport(CassetteTransport::record(&path, request::Client::new())).str_message("Hi");
+        assert_eq!(responses.complete().await.unwrap().output_text().as_deref(), Some("Hello!"));
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(!saved.contains("sk-secret"));
+        assert!(saved.contains(REDACTED));
+
+        // The mock server has shut down, so only the cassette can answer
+        let transport = CassetteTransport::replay(&path).unwrap();
+        let mut responses = Responses::with_url(base_url, "sk-other".to_string());
+        responses.with_transport(transport.clone()).str_message("Hi");
+        assert_eq!(responses.complete().await.unwrap().output_text().as_deref(), Some("Hello!"));
+        assert_eq!(transport.interactions().len(), 1);
+
+        responses.str_message("Something else");
+        let err = responses.complete().await.unwrap_err();
+        assert!(err.to_string().contains("has no interaction for POST"), "unexpected error: {}", err);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_interaction_matches_json_bodies_regardless_of_formatting() {
+        let interaction = Interaction {
+            method: "POST".into(),
+            url: "https://api.openai.com/v1/responses".into(),
+            request_headers: BTreeMap::new(),
+            request_body: Some(r#"{"model": "gpt-4o-mini", "input": "Hi"}"#.into()),
+            status: 200,
+            response_headers: BTreeMap::new(),
+            response_body: "{}".into(),
+        };
+        let request = |method: request::Method, body: &str| HttpRequest {
+            method,
+            url: "https://api.openai.com/v1/responses".into(),
+            headers: request::header::HeaderMap::new(),
+            body: Some(body.into()),
+        };
+
+        assert!(interaction.matches(&request(request::Method::POST, r#"{"input":"Hi","model":"gpt-4o-mini"}"#)));
+        assert!(!interaction.matches(&request(request::Method::POST, r#"{"input":"Bye","model":"gpt-4o-mini"}"#)));
+        assert!(!interaction.matches(&request(request::Method::GET, r#"{"input":"Hi","model":"gpt-4o-mini"}"#)));
+    }
+}
@@ -0,0 +1,39 @@
+//! Shared response type for delete endpoints.
+//!
+//! Most OpenAI delete endpoints (files, models, responses, stored chat
+//! completions, conversations) return the same
+//! `{"id": ..., "object": ..., "deleted": ...}` payload, which is represented
+//! by [`DeleteResponse`].
+
+use serde::{Deserialize, Serialize};
+
+/// Response structure for delete operations.
+///
+/// Returned when a resource is successfully deleted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeleteResponse {
+    /// The identifier of the deleted resource
+    pub id: String,
+    /// Object type, e.g. "file", "model" or "response.deleted"
+    pub object: String,
+    /// Whether the resource was successfully deleted
+    pub deleted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delete_response_deserialization() {
+        for (json, object) in [
+            (r#"{"id": "resp_abc123", "object": "response.deleted", "deleted": true}"#, "response.deleted"),
+            (r#"{"id": "file-abc123", "object": "file", "deleted": true}"#, "file"),
+            (r#"{"id": "ft:gpt-4o-mini:org:custom:abc", "object": "model", "deleted": false}"#, "model"),
+        ] {
+            let response: DeleteResponse = serde_json::from_str(json).expect("Should deserialize DeleteResponse");
+            assert_eq!(response.object, object);
+            assert_eq!(response.deleted, object != "model");
+        }
+    }
+}
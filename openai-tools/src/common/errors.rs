@@ -2,9 +2,12 @@ use serde::Deserialize;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum OpenAIToolError {
     #[error("Request error: {0}")]
-    RequestError(#[from] request::Error),
+    RequestError(request::Error),
+    #[error("Request timed out: {0}")]
+    Timeout(request::Error),
     #[error("JSON serialization/deserialization error: {0}")]
     SerdeJsonError(#[from] serde_json::Error),
     #[error("Error from anyhow: {0}")]
@@ -13,15 +16,109 @@ pub enum OpenAIToolError {
     WebSocketError(String),
     #[error("Realtime API error: {code} - {message}")]
     RealtimeError { code: String, message: String },
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+    /// A required request field was not set; raised before any HTTP call is made
+    #[error("Missing required field: {0}")]
+    MissingField(&'static str),
+    /// The model does not accept a parameter set on the request; raised before any HTTP call is made
+    #[error("Model '{model}' does not support the '{param}' parameter with this value")]
+    UnsupportedParameter {
+        /// The model ID of the request
+        model: String,
+        /// The name of the rejected parameter, e.g. `temperature`
+        param: String,
+    },
+    #[error("API error ({status}): {message}{}", request_id.as_ref().map(|id| format!(" (request id: {})", id)).unwrap_or_default())]
+    ApiError {
+        /// HTTP status code of the response
+        status: u16,
+        /// Error message returned by the API, or the raw body if it was not JSON
+        message: String,
+        /// Machine-readable error code, e.g. `context_length_exceeded`
+        code: Option<String>,
+        /// The request parameter the error relates to, if any
+        param: Option<String>,
+        /// The error type, e.g. `invalid_request_error`
+        type_: Option<String>,
+        /// Value of the `x-request-id` header, to quote when contacting OpenAI support
+        request_id: Option<String>,
+    },
+    /// The request was rejected by OpenAI's safety system, e.g. an image prompt
+    /// that violates the content policy; retrying with a different prompt may succeed
+    #[error("Content policy violation: {message}")]
+    ContentPolicyViolation {
+        /// Error message returned by the API
+        message: String,
+        /// Value of the `x-request-id` header, to quote when contacting OpenAI support
+        request_id: Option<String>,
+    },
+    /// The request was aborted by a cancellation signal before it completed
+    #[error("Request cancelled")]
+    Cancelled,
     #[error("Error: {0}")]
     Error(String),
 }
 
 pub type Result<T> = std::result::Result<T, OpenAIToolError>;
 
+impl From<request::Error> for OpenAIToolError {
+    fn from(error: request::Error) -> Self {
+        if error.is_timeout() {
+            Self::Timeout(error)
+        } else {
+            Self::RequestError(error)
+        }
+    }
+}
+
+/// Response header carrying the ID OpenAI assigns to each request.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Error codes reported when a request is rejected by the safety system
+const CONTENT_POLICY_CODES: [&str; 2] = ["content_policy_violation", "moderation_blocked"];
+
+impl OpenAIToolError {
+    /// Builds an [`OpenAIToolError::ApiError`] from a failed HTTP response.
+    ///
+    /// The message, code, param and type are taken from the JSON error body when
+    /// it can be parsed; otherwise the raw body is used as the message. Content
+    /// policy rejections become [`OpenAIToolError::ContentPolicyViolation`].
+    pub(crate) fn from_api_response(status: request::StatusCode, request_id: Option<String>, content: &str) -> Self {
+        match serde_json::from_str::<ErrorResponse>(content) {
+            Ok(ErrorResponse { error }) if error.code.as_deref().is_some_and(|code| CONTENT_POLICY_CODES.contains(&code)) => {
+                Self::ContentPolicyViolation { message: error.message.unwrap_or_default(), request_id }
+            }
+            Ok(ErrorResponse { error }) => Self::ApiError {
+                status: status.as_u16(),
+                message: error.message.unwrap_or_default(),
+                code: error.code,
+                param: error.param,
+                type_: error.type_name,
+                request_id,
+            },
+            Err(_) => Self::ApiError { status: status.as_u16(), message: content.to_string(), code: None, param: None, type_: None, request_id },
+        }
+    }
+
+    /// Returns the `x-request-id` of the failed request, if the error came from the API.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiError { request_id, .. } | Self::ContentPolicyViolation { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the `x-request-id` header from response headers.
+pub(crate) fn request_id_header(headers: &request::header::HeaderMap) -> Option<String> {
+    headers.get(REQUEST_ID_HEADER).and_then(|value| value.to_str().ok()).map(str::to_string)
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct ErrorMessage {
     pub message: Option<String>,
+    #[serde(rename = "type")]
     pub type_name: Option<String>,
     pub param: Option<String>,
     pub code: Option<String>,
@@ -49,6 +146,7 @@ mod tests {
 
         let error_resp: ErrorResponse = serde_json::from_str(json).unwrap();
         assert_eq!(error_resp.error.message, Some("Incorrect API key provided".to_string()));
+        assert_eq!(error_resp.error.type_name, Some("invalid_request_error".to_string()));
         assert_eq!(error_resp.error.code, Some("invalid_api_key".to_string()));
     }
 
@@ -119,4 +217,77 @@ mod tests {
         let message = error_resp.error.message.unwrap_or_default();
         assert_eq!(message, "Test error");
     }
+
+    #[test]
+    fn test_api_error_from_response_keeps_request_id() {
+        let body = r#"{"error": {"message": "Rate limit exceeded", "type": "rate_limit_error", "param": null, "code": "rate_limit_exceeded"}}"#;
+        let err = OpenAIToolError::from_api_response(request::StatusCode::TOO_MANY_REQUESTS, Some("req_123".to_string()), body);
+
+        assert_eq!(err.request_id(), Some("req_123"));
+        assert_eq!(err.to_string(), "API error (429): Rate limit exceeded (request id: req_123)");
+        match err {
+            OpenAIToolError::ApiError { status, code, .. } => {
+                assert_eq!(status, 429);
+                assert_eq!(code.as_deref(), Some("rate_limit_exceeded"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        let err = OpenAIToolError::from_api_response(request::StatusCode::BAD_GATEWAY, None, "Bad gateway");
+        assert_eq!(err.request_id(), None);
+        assert_eq!(err.to_string(), "API error (502): Bad gateway");
+    }
+
+    #[test]
+    fn test_api_error_from_context_length_body() {
+        let body = r#"{
+            "error": {
+                "message": "This model's maximum context length is 128000 tokens. However, your messages resulted in 130412 tokens. Please reduce the length of the messages.",
+                "type": "invalid_request_error",
+                "param": "messages",
+                "code": "context_length_exceeded"
+            }
+        }"#;
+        let err = OpenAIToolError::from_api_response(request::StatusCode::BAD_REQUEST, None, body);
+
+        match &err {
+            OpenAIToolError::ApiError { status, code, param, type_, message, .. } => {
+                assert_eq!(*status, 400);
+                assert_eq!(code.as_deref(), Some("context_length_exceeded"));
+                assert_eq!(param.as_deref(), Some("messages"));
+                assert_eq!(type_.as_deref(), Some("invalid_request_error"));
+                assert!(message.starts_with("This model's maximum context length is 128000 tokens."));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(err.to_string().starts_with("API error (400): This model's maximum context length"));
+        assert!(std::error::Error::source(&err).is_none());
+    }
+
+    #[test]
+    fn test_content_policy_violation_from_image_error_body() {
+        let body = r#"{
+            "error": {
+                "code": "content_policy_violation",
+                "message": "Your request was rejected as a result of our safety system. Your prompt may contain text that is not allowed by our safety system.",
+                "param": null,
+                "type": "invalid_request_error"
+            }
+        }"#;
+        let err = OpenAIToolError::from_api_response(request::StatusCode::BAD_REQUEST, Some("req_456".to_string()), body);
+
+        match &err {
+            OpenAIToolError::ContentPolicyViolation { message, .. } => {
+                assert!(message.starts_with("Your request was rejected as a result of our safety system."));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(err.request_id(), Some("req_456"));
+        assert!(err.to_string().starts_with("Content policy violation: Your request was rejected"));
+
+        // gpt-image-1 reports the same rejection as `moderation_blocked`
+        let body = r#"{"error": {"code": "moderation_blocked", "message": "Your request was rejected by the safety system.", "type": "image_generation_user_error"}}"#;
+        let err = OpenAIToolError::from_api_response(request::StatusCode::BAD_REQUEST, None, body);
+        assert!(matches!(err, OpenAIToolError::ContentPolicyViolation { .. }));
+    }
 }
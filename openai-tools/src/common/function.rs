@@ -30,7 +30,7 @@ use crate::common::{
     errors::{OpenAIToolError, Result as OpenAIToolResult},
     parameters::Parameters,
 };
-use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -60,12 +60,17 @@ impl Function {
     /// * `name` - The name of the function
     /// * `description` - A description of what the function does
     /// * `parameters` - The parameters that the function accepts
-    /// * `strict` - Whether the function should be executed in strict mode
+    /// * `strict` - Whether the function should be executed in strict mode; if set,
+    ///   objects in `parameters` that do not set `additionalProperties` are closed
+    ///   with `additionalProperties: false`
     ///
     /// # Returns
     ///
     /// A new Function instance
-    pub fn new<T: AsRef<str>, U: AsRef<str>>(name: T, description: U, parameters: Parameters, strict: bool) -> Self {
+    pub fn new<T: AsRef<str>, U: AsRef<str>>(name: T, description: U, mut parameters: Parameters, strict: bool) -> Self {
+        if strict {
+            parameters.disallow_additional_properties();
+        }
         Self {
             name: name.as_ref().to_string(),
             description: Some(description.as_ref().to_string()),
@@ -92,12 +97,42 @@ impl Function {
             Err(OpenAIToolError::from(anyhow::anyhow!("Function arguments are not set")))
         }
     }
+
+    /// Deserializes the function arguments into a user-defined type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The arguments deserialized into `T`
+    /// * `Err(OpenAIToolError::SerdeJsonError)` - If the arguments do not match `T`
+    /// * `Err(OpenAIToolError)` - If the arguments are not set
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::ToolCall;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Weather {
+    ///     city: String,
+    /// }
+    ///
+    /// let call = ToolCall::function("call_1", "get_weather", r#"{"city": "Tokyo"}"#).unwrap();
+    /// let args: Weather = call.function.arguments_as().unwrap();
+    /// assert_eq!(args.city, "Tokyo");
+    /// ```
+    pub fn arguments_as<T: DeserializeOwned>(&self) -> OpenAIToolResult<T> {
+        let arguments = self.arguments.as_ref().ok_or_else(|| OpenAIToolError::from(anyhow::anyhow!("Function arguments are not set")))?;
+        let value = Value::Object(arguments.iter().map(|(k, v)| (k.clone(), v.clone())).collect());
+        serde_json::from_value(value).map_err(OpenAIToolError::SerdeJsonError)
+    }
 }
 
 /// Custom serialization implementation for Function.
 ///
 /// This implementation ensures that only non-None optional fields are serialized,
 /// keeping the JSON output clean and avoiding null values for optional fields.
+/// Arguments are serialized as a JSON string, as the API expects for function calls.
 impl Serialize for Function {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -111,15 +146,15 @@ impl Serialize for Function {
         if let Some(parameters) = &self.parameters {
             state.serialize_field("parameters", parameters)?;
         }
-        if let Some(arguments) = &self.arguments {
-            state.serialize_field("arguments", arguments)?;
-        }
-        state.serialize_field("strict", &self.strict)?;
-
-        if let Some(arguments) = &self.arguments {
-            if !arguments.is_empty() {
-                state.serialize_field("arguments", &serde_json::to_string(arguments).expect("Failed to serialize arguments in Function"))?;
+        // Function calls carry their arguments as a JSON string and have no `strict` flag
+        match &self.arguments {
+            Some(arguments) => {
+                state.serialize_field("arguments", &serde_json::to_string(arguments).map_err(serde::ser::Error::custom)?)?;
+                if self.strict {
+                    state.serialize_field("strict", &self.strict)?;
+                }
             }
+            None => state.serialize_field("strict", &self.strict)?,
         }
         state.end()
     }
@@ -164,3 +199,46 @@ impl<'de> Deserialize<'de> for Function {
         Ok(function)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Operation {
+        Add,
+        Multiply,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Calculator {
+        operation: Operation,
+        a: f64,
+        b: f64,
+    }
+
+    fn function_with_arguments(arguments: &str) -> Function {
+        serde_json::from_value(serde_json::json!({"name": "calculator", "arguments": arguments})).unwrap()
+    }
+
+    #[test]
+    fn test_arguments_as_typed_struct() {
+        let function = function_with_arguments(r#"{"operation": "multiply", "a": 6, "b": 7.5}"#);
+        let args: Calculator = function.arguments_as().unwrap();
+        assert_eq!(args, Calculator { operation: Operation::Multiply, a: 6.0, b: 7.5 });
+    }
+
+    #[test]
+    fn test_arguments_as_reports_schema_mismatch() {
+        let function = function_with_arguments(r#"{"operation": "divide", "a": 1, "b": 2}"#);
+        let err = function.arguments_as::<Calculator>().unwrap_err();
+        assert!(matches!(err, OpenAIToolError::SerdeJsonError(_)));
+        assert!(err.to_string().contains("divide"));
+
+        let function = function_with_arguments(r#"{"operation": "add", "a": 1}"#);
+        assert!(function.arguments_as::<Calculator>().unwrap_err().to_string().contains("missing field `b`"));
+
+        assert!(Function::default().arguments_as::<Calculator>().is_err());
+    }
+}
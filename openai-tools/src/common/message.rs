@@ -55,9 +55,45 @@
 //! ```
 //!
 
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::template::PromptTemplate;
 use crate::common::{function::Function, role::Role};
 use base64::prelude::*;
 use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Audio generated by the model in a Chat Completions response.
+///
+/// Returned in `message.audio` when audio output is requested with
+/// [`ChatCompletion::audio_output`](crate::chat::request::ChatCompletion::audio_output).
+/// To continue the conversation, pass the assistant message back as is; only
+/// the `id` is sent to refer to the earlier audio.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct AudioOutput {
+    /// Unique identifier of the audio response
+    pub id: String,
+    /// Base64-encoded audio data in the requested format
+    #[serde(default)]
+    pub data: String,
+    /// Transcript of the generated audio
+    #[serde(default)]
+    pub transcript: String,
+    /// Unix timestamp after which the audio can no longer be referenced in multi-turn conversations
+    #[serde(default)]
+    pub expires_at: u64,
+}
+
+impl AudioOutput {
+    /// Decodes the base64 audio data into raw bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The audio bytes, e.g. to write to a `.wav` file
+    /// * `Err(OpenAIToolError)` - If the data is not valid base64
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        BASE64_STANDARD.decode(&self.data).map_err(|e| OpenAIToolError::Error(format!("Invalid base64 audio data: {}", e)))
+    }
+}
 
 /// Represents a tool call made by an OpenAI model.
 ///
@@ -91,6 +127,40 @@ pub struct ToolCall {
     pub function: Function,
 }
 
+impl ToolCall {
+    /// Creates a function tool call from its ID, function name and JSON arguments.
+    ///
+    /// Use this to rebuild tool calls persisted from earlier responses, where the
+    /// arguments were stored as the JSON string returned by the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the tool call, e.g. `call_abc123`
+    /// * `name` - The name of the called function
+    /// * `arguments` - The function arguments as a JSON object string
+    ///
+    /// # Returns
+    ///
+    /// The tool call, or an error if `arguments` is not a JSON object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::ToolCall;
+    ///
+    /// let call = ToolCall::function("call_abc123", "get_weather", r#"{"city": "Tokyo"}"#).unwrap();
+    /// assert_eq!(call.function.name, "get_weather");
+    /// ```
+    pub fn function<T: AsRef<str>, U: AsRef<str>>(id: T, name: U, arguments: &str) -> Result<Self> {
+        let arguments = serde_json::from_str(arguments).map_err(OpenAIToolError::SerdeJsonError)?;
+        Ok(Self {
+            id: id.as_ref().to_string(),
+            type_name: "function".to_string(),
+            function: Function { name: name.as_ref().to_string(), arguments: Some(arguments), ..Default::default() },
+        })
+    }
+}
+
 /// Represents different types of content that can be included in a message.
 ///
 /// Content can be either text or images, supporting multi-modal interactions
@@ -134,6 +204,18 @@ pub struct Content {
     /// Optional image URL or base64 data URL
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
+    /// Optional base64-encoded audio input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_audio: Option<InputAudio>,
+}
+
+/// Base64-encoded audio in an `input_audio` content part.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct InputAudio {
+    /// Base64-encoded audio data
+    pub data: String,
+    /// The audio format, `"wav"` or `"mp3"`
+    pub format: String,
 }
 
 impl Content {
@@ -156,7 +238,7 @@ impl Content {
     /// assert_eq!(content.type_name, "input_text");
     /// ```
     pub fn from_text<T: AsRef<str>>(text: T) -> Self {
-        Self { type_name: "input_text".to_string(), text: Some(text.as_ref().to_string()), image_url: None }
+        Self { type_name: "input_text".to_string(), text: Some(text.as_ref().to_string()), image_url: None, input_audio: None }
     }
 
     /// Creates a new Content instance with an image URL.
@@ -178,7 +260,7 @@ impl Content {
     /// assert_eq!(content.type_name, "input_image");
     /// ```
     pub fn from_image_url<T: AsRef<str>>(image_url: T) -> Self {
-        Self { type_name: "input_image".to_string(), text: None, image_url: Some(image_url.as_ref().to_string()) }
+        Self { type_name: "input_image".to_string(), text: None, image_url: Some(image_url.as_ref().to_string()), input_audio: None }
     }
 
     /// Creates a new Content instance from a local image file.
@@ -230,7 +312,67 @@ impl Content {
         img.write_to(&mut buf, img_fmt).expect("Failed to write image to buffer");
         let base64_string = BASE64_STANDARD.encode(buf.into_inner());
         let image_url = format!("data:image/{ext};base64,{base64_string}");
-        Self { type_name: "input_image".to_string(), text: None, image_url: Some(image_url) }
+        Self { type_name: "input_image".to_string(), text: None, image_url: Some(image_url), input_audio: None }
+    }
+
+    /// Creates a new Content instance with audio input.
+    ///
+    /// The audio is base64-encoded and sent as an `input_audio` content part,
+    /// which audio-capable Chat Completions models (e.g. `gpt-4o-audio-preview`) accept.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The raw audio data
+    /// * `format` - The audio format, `"wav"` or `"mp3"`
+    ///
+    /// # Returns
+    ///
+    /// A new Content instance with type "input_audio"
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::Content;
+    ///
+    /// let content = Content::from_audio_bytes(b"RIFF", "wav");
+    /// assert_eq!(content.type_name, "input_audio");
+    /// assert_eq!(content.input_audio.unwrap().data, "UklGRg==");
+    /// ```
+    pub fn from_audio_bytes<T: AsRef<str>>(bytes: &[u8], format: T) -> Self {
+        let input_audio = InputAudio { data: BASE64_STANDARD.encode(bytes), format: format.as_ref().to_string() };
+        Self { type_name: "input_audio".to_string(), text: None, image_url: None, input_audio: Some(input_audio) }
+    }
+
+    /// Creates a new Content instance from a local audio file.
+    ///
+    /// The format is taken from the file extension (`.wav` or `.mp3`).
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - Path to the audio file
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Content)` - Content with type "input_audio" and base64-encoded audio data
+    /// * `Err(OpenAIToolError)` - If the file cannot be read or its format is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::common::message::Content;
+    ///
+    /// let content = Content::from_audio_file("path/to/question.wav").unwrap();
+    /// assert_eq!(content.type_name, "input_audio");
+    /// ```
+    pub fn from_audio_file<P: AsRef<std::path::Path>>(file_path: P) -> Result<Self> {
+        let path = file_path.as_ref();
+        let format = match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("wav") => "wav",
+            Some("mp3") => "mp3",
+            _ => return Err(OpenAIToolError::InvalidParameter(format!("Unsupported audio format for {}; expected .wav or .mp3", path.display()))),
+        };
+        let bytes = std::fs::read(path).map_err(|e| OpenAIToolError::Error(format!("Failed to read audio file {}: {}", path.display(), e)))?;
+        Ok(Self::from_audio_bytes(&bytes, format))
     }
 }
 
@@ -288,6 +430,35 @@ pub struct Message {
     pub tool_call_id: Option<String>,
     /// Optional list of annotations or metadata
     pub annotations: Option<Vec<String>>,
+    /// Optional audio generated by the model (Chat Completions audio output)
+    pub audio: Option<AudioOutput>,
+}
+
+/// Creates a **user** message from text.
+///
+/// The role is always [`Role::User`]; use [`Message::from_string`] for
+/// system, assistant or other roles.
+///
+/// # Examples
+///
+/// ```rust
+/// use openai_tools::common::message::Message;
+/// use openai_tools::common::role::Role;
+///
+/// let message: Message = "Hello!".into();
+/// assert_eq!(message.role, Role::User);
+/// ```
+impl From<&str> for Message {
+    fn from(text: &str) -> Self {
+        Self::from_string(Role::User, text)
+    }
+}
+
+/// Creates a **user** message from text, like `From<&str>`.
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Self::from_string(Role::User, text)
+    }
 }
 
 /// Custom serialization implementation for Message.
@@ -297,7 +468,7 @@ pub struct Message {
 /// Either `content` or `content_list` must be present, but not both.
 /// Additionally, it handles optional fields like `tool_call_id` for tool call responses.
 impl Serialize for Message {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -313,7 +484,10 @@ impl Serialize for Message {
 
         // Serialize optional fields
         if let Some(content) = &self.content {
-            state.serialize_field("content", &content.text)?;
+            match &content.text {
+                Some(text) => state.serialize_field("content", text)?,
+                None => state.serialize_field("content", std::slice::from_ref(content))?,
+            }
         }
         if let Some(contents) = &self.content_list {
             state.serialize_field("content", contents)?;
@@ -324,6 +498,10 @@ impl Serialize for Message {
         if let Some(tool_calls) = &self.tool_calls {
             state.serialize_field("tool_calls", tool_calls)?;
         }
+        if let Some(audio) = &self.audio {
+            // Earlier audio responses are referenced by ID only
+            state.serialize_field("audio", &serde_json::json!({ "id": audio.id }))?;
+        }
 
         state.end()
     }
@@ -335,7 +513,7 @@ impl Serialize for Message {
 /// converting string content to Content objects and handling optional fields including
 /// `tool_call_id` for tool call tracking.
 impl<'de> Deserialize<'de> for Message {
-    fn deserialize<D>(deserializer: D) -> Result<Message, D::Error>
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Message, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -346,6 +524,7 @@ impl<'de> Deserialize<'de> for Message {
             tool_calls: Option<Vec<ToolCall>>,
             refusal: Option<String>,
             annotations: Option<Vec<String>>,
+            audio: Option<AudioOutput>,
         }
 
         let data = MessageData::deserialize(deserializer)?;
@@ -359,6 +538,7 @@ impl<'de> Deserialize<'de> for Message {
             tool_call_id: None,
             refusal: data.refusal,
             annotations: data.annotations,
+            audio: data.audio,
         })
     }
 }
@@ -394,9 +574,38 @@ impl Message {
             tool_call_id: None,
             refusal: None,
             annotations: None,
+            audio: None,
         }
     }
 
+    /// Creates a new text message by rendering a prompt template.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role of the message sender
+    /// * `template` - The template with `{variable}` placeholders
+    /// * `vars` - The values of the placeholders
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Message)` - The message containing the rendered text
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If a variable is missing or the template is malformed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    /// use openai_tools::common::template::PromptTemplate;
+    ///
+    /// let template = PromptTemplate::new("Summarize this in {words} words: {text}");
+    /// let message = Message::from_template(Role::User, &template, &HashMap::from([("words", "10"), ("text", "...")])).unwrap();
+    /// ```
+    pub fn from_template(role: Role, template: &PromptTemplate, vars: &HashMap<&str, &str>) -> Result<Self> {
+        Ok(Self::from_string(role, template.render(vars)?))
+    }
+
     /// Creates a new Message with multiple content items.
     ///
     /// This method is used for multi-modal messages that contain multiple
@@ -424,7 +633,16 @@ impl Message {
     /// let message = Message::from_message_array(Role::User, contents);
     /// ```
     pub fn from_message_array(role: Role, contents: Vec<Content>) -> Self {
-        Self { role, content: None, content_list: Some(contents), tool_calls: None, tool_call_id: None, refusal: None, annotations: None }
+        Self {
+            role,
+            content: None,
+            content_list: Some(contents),
+            tool_calls: None,
+            tool_call_id: None,
+            refusal: None,
+            annotations: None,
+            audio: None,
+        }
     }
 
     /// Creates a new Message as a response to a specific tool call.
@@ -461,8 +679,50 @@ impl Message {
             tool_call_id: Some(tool_call_id.as_ref().to_string()),
             refusal: None,
             annotations: None,
+            audio: None,
         }
     }
+
+    /// Creates an assistant message containing tool calls.
+    ///
+    /// This rebuilds the assistant turn that requested the tool calls, e.g. when
+    /// replaying a conversation loaded from storage. It must precede the
+    /// [`from_tool_call_response`](Self::from_tool_call_response) messages that
+    /// answer the calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Optional text the assistant sent alongside the calls
+    /// * `calls` - The tool calls requested by the assistant
+    ///
+    /// # Returns
+    ///
+    /// A new assistant Message that serializes to `{"role": "assistant", "tool_calls": [...]}`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::{Message, ToolCall};
+    ///
+    /// let call = ToolCall::function("call_abc123", "get_weather", r#"{"city": "Tokyo"}"#).unwrap();
+    /// let history = vec![
+    ///     Message::assistant_with_tool_calls(None, vec![call]),
+    ///     Message::from_tool_call_response("25°C and sunny", "call_abc123"),
+    /// ];
+    /// ```
+    pub fn assistant_with_tool_calls(content: Option<String>, calls: Vec<ToolCall>) -> Self {
+        Self {
+            role: Role::Assistant,
+            content: content.map(Content::from_text),
+            content_list: None,
+            tool_calls: Some(calls),
+            tool_call_id: None,
+            refusal: None,
+            annotations: None,
+            audio: None,
+        }
+    }
+
     /// Calculates the approximate token count for the message content.
     ///
     /// This method uses the tiktoken library to estimate the number of tokens
@@ -498,4 +758,278 @@ impl Message {
             0 // No content to count tokens for
         }
     }
+
+    /// Returns the content parts of the message, whether it holds a single
+    /// content item or a list of them.
+    fn parts(&self) -> impl Iterator<Item = &Content> {
+        self.content.iter().chain(self.content_list.iter().flatten())
+    }
+
+    /// Returns the text of every text part, in order.
+    ///
+    /// Works the same for messages built with [`Message::from_string`] and
+    /// [`Message::from_message_array`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::{Content, Message};
+    /// use openai_tools::common::role::Role;
+    ///
+    /// let message = Message::from_message_array(
+    ///     Role::User,
+    ///     vec![Content::from_text("Compare"), Content::from_image_url("https://example.com/a.png"), Content::from_text("and explain.")],
+    /// );
+    /// assert_eq!(message.text_parts(), vec!["Compare", "and explain."]);
+    /// assert_eq!(message.image_parts(), vec!["https://example.com/a.png"]);
+    /// assert_eq!(message.as_text().as_deref(), Some("Compare\nand explain."));
+    /// ```
+    pub fn text_parts(&self) -> Vec<&str> {
+        self.parts().filter_map(|content| content.text.as_deref()).collect()
+    }
+
+    /// Returns the URL (or base64 data URL) of every image part, in order.
+    pub fn image_parts(&self) -> Vec<&str> {
+        self.parts().filter_map(|content| content.image_url.as_deref()).collect()
+    }
+
+    /// Returns the text of the message, with multiple text parts joined by newlines.
+    ///
+    /// Returns `None` if the message has no text, e.g. an assistant message
+    /// with only tool calls or a user message with only images.
+    pub fn as_text(&self) -> Option<String> {
+        let parts = self.text_parts();
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
+
+    /// Checks that a conversation follows a sensible message order.
+    ///
+    /// Instructions (`system` or `developer` messages) usually belong at the
+    /// start of the conversation. The API accepts them anywhere, so the
+    /// following orderings are logged with a warning rather than rejected:
+    ///
+    /// - an instruction message placed after user, assistant or tool messages,
+    ///   which is valid for mid-conversation instructions but often a mistake
+    /// - several leading instruction messages, e.g. when a template and the
+    ///   caller both add a system prompt
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The messages in the order they will be sent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Ordering issues are only logged, never returned as errors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    ///
+    /// let messages = vec![Message::from_string(Role::Developer, "Answer in French."), Message::from_string(Role::User, "Hello!")];
+    /// assert!(Message::validate_conversation(&messages).is_ok());
+    ///
+    /// // Logs a warning: the system message follows a user message
+    /// let messages = vec![Message::from_string(Role::User, "Hello!"), Message::from_string(Role::System, "Answer in French.")];
+    /// assert!(Message::validate_conversation(&messages).is_ok());
+    /// ```
+    pub fn validate_conversation(messages: &[Message]) -> Result<()> {
+        let leading = messages.iter().take_while(|message| message.role.is_instruction()).count();
+        for (index, message) in messages.iter().enumerate().skip(leading).filter(|(_, message)| message.role.is_instruction()) {
+            tracing::warn!(
+                "{} message at index {} is not at the start of the conversation; move it before other messages unless it is a mid-conversation instruction",
+                message.role.as_str(),
+                index
+            );
+        }
+        if leading > 1 {
+            tracing::warn!("Conversation starts with {} system/developer messages; consider merging them into one", leading);
+        }
+        Ok(())
+    }
+
+    /// Returns the refusal message, if the model declined to respond.
+    ///
+    /// Refusals are reported separately from the content, e.g. when a
+    /// request with structured output is refused for safety reasons, so check
+    /// this before parsing the content.
+    pub fn refusal(&self) -> Option<&str> {
+        self.refusal.as_deref()
+    }
+}
+
+/// Returns descriptions of message orderings that defeat automatic prompt caching.
+///
+/// Prompt caching only applies to a byte-identical prefix of the request, so
+/// static content (system and developer instructions) must come before dynamic
+/// content (user, assistant and tool messages). An instruction message that
+/// follows dynamic content splits the static prefix and is reported here.
+///
+/// # Arguments
+///
+/// * `messages` - The messages in the order they will be sent
+///
+/// # Returns
+///
+/// One description per misplaced message; empty if the ordering is cache-friendly
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::common::message::{cache_ordering_issues, Message};
+/// use openai_tools::common::role::Role;
+///
+/// let messages = vec![
+///     Message::from_string(Role::User, "What's the weather?"),
+///     Message::from_string(Role::System, "You are a helpful assistant."),
+/// ];
+/// assert_eq!(cache_ordering_issues(&messages).len(), 1);
+/// ```
+pub fn cache_ordering_issues(messages: &[Message]) -> Vec<String> {
+    let Some(first_dynamic) = messages.iter().position(|message| !message.role.is_instruction()) else {
+        return Vec::new();
+    };
+    messages
+        .iter()
+        .enumerate()
+        .skip(first_dynamic + 1)
+        .filter(|(_, message)| message.role.is_instruction())
+        .map(|(index, message)| {
+            format!(
+                "{} message at index {} follows dynamic content (first {} message at index {}); move static instructions to the start of the conversation so the cached prefix is reused",
+                if message.role == Role::System { "System" } else { "Developer" },
+                index,
+                messages[first_dynamic].role.as_str(),
+                first_dynamic
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_part_helpers_on_single_content_message() {
+        let message = Message::from_string(Role::Assistant, "Hello!");
+        assert_eq!(message.text_parts(), vec!["Hello!"]);
+        assert!(message.image_parts().is_empty());
+        assert_eq!(message.as_text().as_deref(), Some("Hello!"));
+
+        let message = Message::assistant_with_tool_calls(None, Vec::new());
+        assert!(message.text_parts().is_empty());
+        assert_eq!(message.as_text(), None);
+    }
+
+    #[test]
+    fn test_content_part_helpers_on_multi_part_message() {
+        let message = Message::from_message_array(
+            Role::User,
+            vec![
+                Content::from_text("What is in these images?"),
+                Content::from_image_url("https://example.com/a.png"),
+                Content::from_audio_bytes(b"RIFF", "wav"),
+                Content::from_image_url("data:image/png;base64,iVBORw0KGgo="),
+                Content::from_text("Answer briefly."),
+            ],
+        );
+        assert_eq!(message.text_parts(), vec!["What is in these images?", "Answer briefly."]);
+        assert_eq!(message.image_parts(), vec!["https://example.com/a.png", "data:image/png;base64,iVBORw0KGgo="]);
+        assert_eq!(message.as_text().as_deref(), Some("What is in these images?\nAnswer briefly."));
+
+        let images_only = Message::from_message_array(Role::User, vec![Content::from_image_url("https://example.com/a.png")]);
+        assert_eq!(images_only.as_text(), None);
+    }
+
+    #[test]
+    fn test_validate_conversation_order() {
+        let system = || Message::from_string(Role::System, "Be brief.");
+        let developer = || Message::from_string(Role::Developer, "Answer in French.");
+        let user = || Message::from_string(Role::User, "Hello!");
+        let assistant = || Message::from_string(Role::Assistant, "Bonjour !");
+
+        assert!(Message::validate_conversation(&[]).is_ok());
+        assert!(Message::validate_conversation(&[user(), assistant(), user()]).is_ok());
+        assert!(Message::validate_conversation(&[developer(), user(), assistant()]).is_ok());
+        // Several leading instructions are only warned about
+        assert!(Message::validate_conversation(&[system(), developer(), user()]).is_ok());
+
+        // A mid-conversation instruction is valid and only warned about
+        assert!(Message::validate_conversation(&[developer(), user(), system(), assistant()]).is_ok());
+    }
+
+    #[test]
+    fn test_developer_message_serialization() {
+        let message = Message::from_string(Role::Developer, "Answer in French.");
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(json["role"], "developer");
+
+        let issues = cache_ordering_issues(&[Message::from_string(Role::User, "Hi"), message]);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].starts_with("Developer message at index 1"), "{}", issues[0]);
+    }
+
+    #[test]
+    fn test_text_converts_into_user_message() {
+        let expected = serde_json::to_value(Message::from_string(Role::User, "Hello")).unwrap();
+
+        let from_str: Message = "Hello".into();
+        let from_string = Message::from(String::from("Hello"));
+        for message in [from_str, from_string] {
+            assert_eq!(message.role, Role::User);
+            assert_eq!(serde_json::to_value(&message).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_assistant_with_tool_calls_round_trip() {
+        let call = ToolCall::function("call_abc123", "get_weather", r#"{"city": "Tokyo"}"#).unwrap();
+        let message = Message::assistant_with_tool_calls(None, vec![call]);
+
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "role": "assistant",
+                "tool_calls": [{
+                    "id": "call_abc123",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"city\":\"Tokyo\"}"}
+                }]
+            })
+        );
+
+        let restored: Message = serde_json::from_value(json).unwrap();
+        let calls = restored.tool_calls.unwrap();
+        assert_eq!(calls[0].id, "call_abc123");
+        assert_eq!(calls[0].function.arguments_as_map().unwrap()["city"], "Tokyo");
+    }
+
+    #[test]
+    fn test_assistant_with_tool_calls_keeps_content() {
+        let call = ToolCall::function("call_1", "lookup", "{}").unwrap();
+        let json = serde_json::to_value(Message::assistant_with_tool_calls(Some("Let me check.".to_string()), vec![call])).unwrap();
+        assert_eq!(json["content"], "Let me check.");
+        assert_eq!(json["tool_calls"][0]["function"]["arguments"], "{}");
+
+        assert!(ToolCall::function("call_2", "lookup", "not json").is_err());
+    }
+
+    #[test]
+    fn test_from_audio_file() {
+        let dir = std::env::temp_dir().join(format!("openai-tools-audio-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("question.MP3");
+        std::fs::write(&path, b"ID3").unwrap();
+
+        let content = Content::from_audio_file(&path).unwrap();
+        assert_eq!(content.input_audio, Some(InputAudio { data: "SUQz".to_string(), format: "mp3".to_string() }));
+
+        let err = Content::from_audio_file(dir.join("question.ogg")).unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)));
+        assert!(Content::from_audio_file(dir.join("missing.wav")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
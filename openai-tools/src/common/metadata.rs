@@ -0,0 +1,205 @@
+//! Validated metadata for API objects.
+//!
+//! Responses, conversations, batches and fine-tuning jobs accept up to 16
+//! key-value pairs of metadata. The API rejects keys longer than 64 characters
+//! and values longer than 512 characters. [`Metadata`] enforces these limits
+//! when entries are inserted, so an invalid map fails early with a clear error
+//! instead of an opaque API rejection. Every metadata argument of the clients
+//! accepts either a `Metadata` or a plain `HashMap`; maps are checked against
+//! the limits before the request is sent.
+//!
+//! # Example
+//!
+//! ```rust
+//! use openai_tools::common::metadata::Metadata;
+//!
+//! let mut metadata = Metadata::new();
+//! metadata.insert("user_id", "user123").unwrap();
+//! metadata.insert("session_id", "sess456").unwrap();
+//! assert_eq!(metadata.get("user_id"), Some("user123"));
+//!
+//! assert!(metadata.insert("k".repeat(65), "too long a key").is_err());
+//! ```
+
+use crate::common::errors::{OpenAIToolError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Maximum number of metadata entries
+pub const MAX_METADATA_ENTRIES: usize = 16;
+
+/// Maximum length of a metadata key, in characters
+pub const MAX_METADATA_KEY_LENGTH: usize = 64;
+
+/// Maximum length of a metadata value, in characters
+pub const MAX_METADATA_VALUE_LENGTH: usize = 512;
+
+/// Key-value metadata that satisfies the API limits.
+///
+/// Serializes as a plain JSON object.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Metadata(HashMap<String, String>);
+
+impl Metadata {
+    /// Creates empty metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an entry, replacing the value of an existing key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key, at most [`MAX_METADATA_KEY_LENGTH`] characters
+    /// * `value` - The value, at most [`MAX_METADATA_VALUE_LENGTH`] characters
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the entry was inserted
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the key or value is too long, or
+    ///   a new key would exceed [`MAX_METADATA_ENTRIES`]; the metadata is left unchanged
+    pub fn insert<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> Result<()> {
+        let (key, value) = (key.into(), value.into());
+        validate_entry(&key, &value)?;
+        if !self.0.contains_key(&key) {
+            validate_entry_count(self.0.len() + 1)?;
+        }
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    /// Returns the value of `key`, if set.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Removes `key`, returning its value if it was set.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the entries, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Returns the entries as a map.
+    pub fn as_map(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for Metadata {
+    type Error = OpenAIToolError;
+
+    /// Validates every entry of `map`.
+    fn try_from(map: HashMap<String, String>) -> Result<Self> {
+        validate_map(&map)?;
+        Ok(Self(map))
+    }
+}
+
+impl From<Metadata> for HashMap<String, String> {
+    fn from(metadata: Metadata) -> Self {
+        metadata.0
+    }
+}
+
+impl From<Metadata> for Option<HashMap<String, String>> {
+    /// Lets optional metadata arguments take a `Metadata` without wrapping it in `Some`.
+    fn from(metadata: Metadata) -> Self {
+        Some(metadata.0)
+    }
+}
+
+/// Checks a metadata map against the API limits.
+pub(crate) fn validate_map(map: &HashMap<String, String>) -> Result<()> {
+    validate_entry_count(map.len())?;
+    map.iter().try_for_each(|(key, value)| validate_entry(key, value))
+}
+
+/// Checks a metadata key and value against the length limits.
+pub(crate) fn validate_entry(key: &str, value: &str) -> Result<()> {
+    if key.chars().count() > MAX_METADATA_KEY_LENGTH {
+        return Err(OpenAIToolError::InvalidParameter(format!("metadata key '{}' is longer than {} characters", key, MAX_METADATA_KEY_LENGTH)));
+    }
+    let length = value.chars().count();
+    if length > MAX_METADATA_VALUE_LENGTH {
+        return Err(OpenAIToolError::InvalidParameter(format!(
+            "metadata value of key '{}' is {} characters long; the maximum is {}",
+            key, length, MAX_METADATA_VALUE_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+/// Checks the number of metadata entries against the limit.
+pub(crate) fn validate_entry_count(count: usize) -> Result<()> {
+    if count > MAX_METADATA_ENTRIES {
+        return Err(OpenAIToolError::InvalidParameter(format!("metadata has {} entries; the maximum is {}", count, MAX_METADATA_ENTRIES)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_enforces_entry_limit() {
+        let mut metadata = Metadata::new();
+        for i in 0..MAX_METADATA_ENTRIES {
+            metadata.insert(format!("key{}", i), "value").unwrap();
+        }
+
+        let err = metadata.insert("one_too_many", "value").unwrap_err();
+        assert!(matches!(&err, OpenAIToolError::InvalidParameter(message) if message.contains("17 entries")), "unexpected error: {}", err);
+        assert_eq!(metadata.len(), MAX_METADATA_ENTRIES);
+
+        // Replacing an existing key does not add an entry
+        metadata.insert("key0", "replaced").unwrap();
+        assert_eq!(metadata.get("key0"), Some("replaced"));
+    }
+
+    #[test]
+    fn test_insert_enforces_key_length() {
+        let mut metadata = Metadata::new();
+        metadata.insert("k".repeat(MAX_METADATA_KEY_LENGTH), "value").unwrap();
+        // Lengths are counted in characters, not bytes
+        metadata.insert("é".repeat(MAX_METADATA_KEY_LENGTH), "value").unwrap();
+
+        let err = metadata.insert("k".repeat(MAX_METADATA_KEY_LENGTH + 1), "value").unwrap_err();
+        assert!(err.to_string().contains("longer than 64 characters"), "unexpected error: {}", err);
+        assert_eq!(metadata.len(), 2);
+    }
+
+    #[test]
+    fn test_insert_enforces_value_length() {
+        let mut metadata = Metadata::new();
+        metadata.insert("key", "v".repeat(MAX_METADATA_VALUE_LENGTH)).unwrap();
+
+        let err = metadata.insert("key", "v".repeat(MAX_METADATA_VALUE_LENGTH + 1)).unwrap_err();
+        assert!(err.to_string().contains("513 characters long"), "unexpected error: {}", err);
+        assert_eq!(metadata.get("key").map(str::len), Some(MAX_METADATA_VALUE_LENGTH));
+    }
+
+    #[test]
+    fn test_try_from_map_and_serialization() {
+        let metadata = Metadata::try_from(HashMap::from([("topic".to_string(), "demo".to_string())])).unwrap();
+        assert_eq!(serde_json::to_value(&metadata).unwrap(), serde_json::json!({"topic": "demo"}));
+
+        let too_many: HashMap<String, String> = (0..=MAX_METADATA_ENTRIES).map(|i| (format!("key{}", i), "value".to_string())).collect();
+        assert!(Metadata::try_from(too_many).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+//! Minimal HTTP server for exercising API clients in unit tests without network access.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// A request captured by the mock server.
+#[derive(Debug, Clone)]
+pub(crate) struct CapturedRequest {
+    /// The request line, e.g. `POST /v1/conversations HTTP/1.1`
+    pub(crate) request_line: String,
+    /// The request headers as `(lowercase name, value)` pairs
+    pub(crate) headers: Vec<(String, String)>,
+    /// The request body
+    pub(crate) body: String,
+}
+
+impl CapturedRequest {
+    /// Returns the value of the header `name` (case-insensitive), if present.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers.iter().find(|(header, _)| *header == name).map(|(_, value)| value.as_str())
+    }
+}
+
+/// A canned response: status code, extra `(name, value)` headers and body.
+pub(crate) type MockResponse = (u16, Vec<(&'static str, String)>, String);
+
+/// Serves one canned response per entry of `responses`, in order, on a local port.
+///
+/// Returns the base URL to point a client at (e.g. via `with_url`) and a handle
+/// that resolves to the captured requests once all responses have been served.
+pub(crate) async fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<CapturedRequest>>) {
+    serve_with_headers(responses.into_iter().map(|(status, body)| (status, Vec::new(), body)).collect()).await
+}
+
+/// Like [`serve`], but each response also carries extra `(name, value)` headers.
+pub(crate) async fn serve_with_headers(responses: Vec<MockResponse>) -> (String, JoinHandle<Vec<CapturedRequest>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind mock server");
+    let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut captured = Vec::new();
+        for (status, headers, body) in responses {
+            let (mut socket, _) = listener.accept().await.expect("accept connection");
+            captured.push(read_request(&mut socket).await);
+
+            let extra_headers: String = headers.iter().map(|(name, value)| format!("{}: {}\r\n", name, value)).collect();
+            let response = format!(
+                "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                extra_headers,
+                body
+            );
+            socket.write_all(response.as_bytes()).await.expect("write response");
+            socket.shutdown().await.ok();
+        }
+        captured
+    });
+
+    (base_url, handle)
+}
+
+/// Reads a full HTTP/1.1 request with a `Content-Length` body.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> CapturedRequest {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let read = socket.read(&mut chunk).await.expect("read request");
+        buffer.extend_from_slice(&chunk[..read]);
+
+        let text = String::from_utf8_lossy(&buffer);
+        if let Some(header_end) = text.find("\r\n\r\n") {
+            let content_length = text[..header_end]
+                .lines()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap_or(0)))
+                .unwrap_or(0);
+            if buffer.len() >= header_end + 4 + content_length || read == 0 {
+                let request_line = text.lines().next().unwrap_or_default().to_string();
+                let headers = text[..header_end]
+                    .lines()
+                    .skip(1)
+                    .filter_map(|line| line.split_once(':'))
+                    .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+                    .collect();
+                let body = text[header_end + 4..].to_string();
+                return CapturedRequest { request_line, headers, body };
+            }
+        }
+        if read == 0 {
+            return CapturedRequest { request_line: text.lines().next().unwrap_or_default().to_string(), headers: Vec::new(), body: String::new() };
+        }
+    }
+}
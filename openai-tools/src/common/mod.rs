@@ -1,23 +1,42 @@
 pub mod auth;
+pub mod cassette;
 pub mod client;
+pub mod delete;
 pub mod errors;
 pub mod function;
 pub mod message;
+pub mod metadata;
+#[cfg(test)]
+pub(crate) mod mock_server;
 pub mod models;
+pub mod openai_client;
+pub mod pagination;
 pub mod parameters;
+pub mod pricing;
 pub mod role;
 pub mod structured_output;
+pub mod template;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub mod tool;
+pub mod transport;
 pub mod usage;
 
 pub use auth::{AuthProvider, AzureAuth, OpenAIAuth};
-pub use client::create_http_client;
+pub use client::{create_http_client, create_http_client_with_proxy};
+pub use delete::DeleteResponse;
 pub use errors::{OpenAIToolError, Result};
 pub use function::Function;
 pub use message::{Content, Message, ToolCall};
-pub use models::{ChatModel, EmbeddingModel, FineTuningModel, ParameterRestriction, ParameterSupport, RealtimeModel};
+pub use metadata::Metadata;
+pub use models::{ChatModel, EmbeddingModel, FineTuningModel, ParameterRestriction, ParameterSupport, ParameterSupportTable, RealtimeModel};
+pub use openai_client::OpenAIClient;
+pub use pagination::Page;
 pub use parameters::{ParameterProperty, Parameters};
+pub use pricing::{ModelPricing, PricingTable, RequestPreview};
 pub use role::Role;
 pub use structured_output::Schema;
+pub use template::PromptTemplate;
 pub use tool::Tool;
-pub use usage::{CompletionTokenDetails, PromptTokenDetails, Usage};
+pub use transport::{MockTransport, Transport};
+pub use usage::{CompletionTokenDetails, PromptTokenDetails, Usage, UsageTracker};
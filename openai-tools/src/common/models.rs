@@ -41,6 +41,7 @@
 //! - [Model Deprecations](https://platform.openai.com/docs/deprecations)
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // ============================================================================
 // Parameter Restriction Types
@@ -79,7 +80,7 @@ pub enum ParameterRestriction {
 /// // Reasoning models don't support logprobs
 /// assert!(!support.logprobs);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ParameterSupport {
     /// Temperature parameter restriction (Chat & Responses API)
     pub temperature: ParameterRestriction,
@@ -144,6 +145,58 @@ impl ParameterSupport {
     }
 }
 
+/// Parameter support of models by model ID, with user-defined overrides.
+///
+/// Lookups fall back to the built-in [`ChatModel::parameter_support`].
+/// Dated snapshots such as `o3-mini-2025-01-31` resolve to their base model.
+/// Overrides let requests to fine-tuned, new or third-party models be
+/// validated without a crate release.
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::common::models::{ParameterRestriction, ParameterSupport, ParameterSupportTable};
+///
+/// let mut table = ParameterSupportTable::new();
+/// table.set("my-local-reasoner", ParameterSupport::reasoning_model());
+///
+/// assert_eq!(table.get("my-local-reasoner").temperature, ParameterRestriction::FixedValue(1.0));
+/// assert_eq!(table.get("o3-mini-2025-01-31"), ParameterSupport::reasoning_model());
+/// assert_eq!(table.get("gpt-4o-mini").temperature, ParameterRestriction::Any);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParameterSupportTable {
+    /// Parameter support that takes precedence over the built-in one
+    overrides: HashMap<String, ParameterSupport>,
+}
+
+impl ParameterSupportTable {
+    /// Creates a table with the built-in parameter support.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the user-defined overrides.
+    pub fn overrides(&self) -> &HashMap<String, ParameterSupport> {
+        &self.overrides
+    }
+
+    /// Sets the parameter support of a model, overriding the built-in one.
+    pub fn set<T: Into<String>>(&mut self, model_id: T, support: ParameterSupport) -> &mut Self {
+        self.overrides.insert(model_id.into(), support);
+        self
+    }
+
+    /// Returns the parameter support of a model.
+    pub fn get(&self, model_id: &str) -> ParameterSupport {
+        self.overrides
+            .get(model_id)
+            .or_else(|| self.overrides.get(strip_snapshot_date(model_id)?))
+            .cloned()
+            .unwrap_or_else(|| ChatModel::from(model_id).parameter_support())
+    }
+}
+
 /// Models available for Chat Completions and Responses APIs.
 ///
 /// This enum covers all models that can be used with the Chat Completions API
@@ -450,6 +503,14 @@ impl ChatModel {
     }
 }
 
+/// Removes a trailing `-YYYY-MM-DD` snapshot date from a model ID.
+pub(crate) fn strip_snapshot_date(model_id: &str) -> Option<&str> {
+    let split = model_id.len().checked_sub(11)?;
+    let (base, date) = (model_id.get(..split)?, model_id.get(split..)?);
+    let is_date = date.bytes().enumerate().all(|(i, b)| if matches!(i, 0 | 5 | 8) { b == b'-' } else { b.is_ascii_digit() });
+    is_date.then_some(base)
+}
+
 impl std::fmt::Display for ChatModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -576,6 +637,13 @@ impl EmbeddingModel {
             Self::TextEmbeddingAda002 => 1536,
         }
     }
+
+    /// Returns the maximum number of tokens accepted for a single input.
+    pub fn max_input_tokens(&self) -> usize {
+        match self {
+            Self::TextEmbedding3Small | Self::TextEmbedding3Large | Self::TextEmbeddingAda002 => 8191,
+        }
+    }
 }
 
 impl std::fmt::Display for EmbeddingModel {
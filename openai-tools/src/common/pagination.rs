@@ -0,0 +1,346 @@
+//! Cursor-based pagination shared by list endpoints.
+//!
+//! List endpoints such as files, batches, fine-tuning jobs, and conversations
+//! return a page of objects together with `first_id`, `last_id`, and `has_more`.
+//! [`Page<T>`] captures that shape once, so pagination can be handled the same
+//! way regardless of the endpoint. Each list response type also converts into
+//! a [`Page`] via [`From`].
+//!
+//! Every list response (and [`Page`] itself) implements [`Paginated`], which
+//! exposes the cursor fields under common names. [`paginate`] turns any
+//! page-fetching closure into a stream of items that follows the cursors:
+//!
+//! ```rust,no_run
+//! use futures_util::{pin_mut, StreamExt};
+//! use openai_tools::batch::request::Batches;
+//! use openai_tools::common::pagination::paginate;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let batches = &Batches::new()?;
+//! let all = paginate(move |after| async move { batches.list(Some(100), after.as_deref()).await });
+//! pin_mut!(all);
+//! while let Some(batch) = all.next().await {
+//!     println!("{}", batch?.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::common::errors::Result;
+use futures_util::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::future::Future;
+
+/// A list response that can be paginated with an `after` cursor.
+pub trait Paginated {
+    /// The type of object in the list
+    type Item;
+
+    /// Returns the objects in this page.
+    fn data(&self) -> &[Self::Item];
+
+    /// Returns whether more objects are available after this page.
+    fn has_more(&self) -> bool;
+
+    /// Returns the ID of the last object in this page, if any.
+    fn last_id(&self) -> Option<&str>;
+
+    /// Consumes the page and returns its objects.
+    fn into_data(self) -> Vec<Self::Item>;
+
+    /// Returns the cursor to pass as `after` when requesting the next page.
+    ///
+    /// Returns `None` when this is the last page.
+    fn next_cursor(&self) -> Option<&str> {
+        if self.has_more() {
+            self.last_id()
+        } else {
+            None
+        }
+    }
+}
+
+/// Streams every object of a paginated list endpoint.
+///
+/// `fetch_page` is called with the `after` cursor (`None` for the first page)
+/// and returns one page. Pages are requested lazily as the stream is consumed,
+/// following [`Paginated::next_cursor`] until the last page.
+///
+/// If a request fails, the error is yielded and the stream ends. To retry from
+/// where it stopped, start a new stream with [`paginate_after`] and the ID of
+/// the last object received.
+pub fn paginate<P, F, Fut>(fetch_page: F) -> impl Stream<Item = Result<P::Item>>
+where
+    P: Paginated,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    paginate_after(None, fetch_page)
+}
+
+/// Like [`paginate`], but starts after the object with ID `after`.
+pub fn paginate_after<P, F, Fut>(after: Option<String>, fetch_page: F) -> impl Stream<Item = Result<P::Item>>
+where
+    P: Paginated,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    // (page fetcher, pending items, cursor for the next page, whether more pages remain)
+    let state = (fetch_page, VecDeque::new(), after, true);
+
+    stream::try_unfold(state, |(mut fetch_page, mut buffer, mut cursor, mut has_more)| async move {
+        loop {
+            if let Some(item) = buffer.pop_front() {
+                return Ok(Some((item, (fetch_page, buffer, cursor, has_more))));
+            }
+            if !has_more {
+                return Ok(None);
+            }
+            let page = fetch_page(cursor.take()).await?;
+            cursor = page.next_cursor().map(str::to_string);
+            has_more = cursor.is_some() && !page.data().is_empty();
+            buffer.extend(page.into_data());
+        }
+    })
+}
+
+/// Implements [`Paginated`] for a list response with `data` and `has_more` fields.
+///
+/// By default the response must also have a `last_id: Option<String>` field;
+/// with `last_id = data` the cursor is the `id` of the last object instead.
+macro_rules! impl_paginated {
+    ($list:ty, $item:ty) => {
+        impl $crate::common::pagination::Paginated for $list {
+            type Item = $item;
+
+            fn data(&self) -> &[$item] {
+                &self.data
+            }
+
+            fn has_more(&self) -> bool {
+                self.has_more
+            }
+
+            fn last_id(&self) -> Option<&str> {
+                self.last_id.as_deref()
+            }
+
+            fn into_data(self) -> Vec<$item> {
+                self.data
+            }
+        }
+    };
+    ($list:ty, $item:ty, last_id = data) => {
+        impl $crate::common::pagination::Paginated for $list {
+            type Item = $item;
+
+            fn data(&self) -> &[$item] {
+                &self.data
+            }
+
+            fn has_more(&self) -> bool {
+                self.has_more
+            }
+
+            fn last_id(&self) -> Option<&str> {
+                self.data.last().map(|item| item.id.as_str())
+            }
+
+            fn into_data(self) -> Vec<$item> {
+                self.data
+            }
+        }
+    };
+}
+
+pub(crate) use impl_paginated;
+
+/// A page of objects returned by a cursor-paginated list endpoint.
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::common::pagination::Page;
+/// use openai_tools::files::response::File;
+///
+/// let json = r#"{
+///     "object": "list",
+///     "data": [],
+///     "first_id": "file-1",
+///     "last_id": "file-2",
+///     "has_more": true
+/// }"#;
+///
+/// let page: Page<File> = serde_json::from_str(json).unwrap();
+/// assert_eq!(page.next_cursor(), Some("file-2"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page<T> {
+    /// The objects in this page
+    pub data: Vec<T>,
+    /// ID of the first object in this page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+    /// ID of the last object in this page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+    /// Whether more objects are available after this page
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Returns the cursor to pass as `after` when requesting the next page.
+    ///
+    /// Returns `None` when this is the last page.
+    pub fn next_cursor(&self) -> Option<&str> {
+        if self.has_more {
+            self.last_id.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of objects in this page.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this page contains no objects.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T> Paginated for Page<T> {
+    type Item = T;
+
+    fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    fn last_id(&self) -> Option<&str> {
+        self.last_id.as_deref()
+    }
+
+    fn into_data(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversations::response::{Conversation, ConversationListResponse};
+    use crate::files::response::{File, FileListResponse};
+
+    const FILES_JSON: &str = r#"{
+        "object": "list",
+        "data": [
+            {"id": "file-abc", "object": "file", "bytes": 120, "created_at": 1700000000, "filename": "a.jsonl", "purpose": "batch"},
+            {"id": "file-def", "object": "file", "bytes": 240, "created_at": 1700000100, "filename": "b.jsonl", "purpose": "batch"}
+        ],
+        "first_id": "file-abc",
+        "last_id": "file-def",
+        "has_more": true
+    }"#;
+
+    const CONVERSATIONS_JSON: &str = r#"{
+        "object": "list",
+        "data": [
+            {"id": "conv_123", "object": "conversation", "created_at": 1700000000, "metadata": {"topic": "demo"}}
+        ],
+        "first_id": "conv_123",
+        "last_id": "conv_123",
+        "has_more": false
+    }"#;
+
+    #[test]
+    fn test_page_deserializes_files() {
+        let page: Page<File> = serde_json::from_str(FILES_JSON).unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.data[1].id, "file-def");
+        assert_eq!(page.first_id.as_deref(), Some("file-abc"));
+        assert_eq!(page.next_cursor(), Some("file-def"));
+
+        let response: FileListResponse = serde_json::from_str(FILES_JSON).unwrap();
+        let converted = Page::from(response);
+        assert_eq!(converted.next_cursor(), Some("file-def"));
+        assert_eq!(converted.into_iter().map(|file| file.filename).collect::<Vec<_>>(), vec!["a.jsonl", "b.jsonl"]);
+    }
+
+    #[test]
+    fn test_page_deserializes_conversations() {
+        let page: Page<Conversation> = serde_json::from_str(CONVERSATIONS_JSON).unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.data[0].id, "conv_123");
+        assert!(!page.has_more);
+        assert_eq!(page.next_cursor(), None);
+
+        let response: ConversationListResponse = serde_json::from_str(CONVERSATIONS_JSON).unwrap();
+        let converted = Page::from(response);
+        assert_eq!(converted.last_id.as_deref(), Some("conv_123"));
+        assert!(!converted.has_more);
+    }
+
+    #[test]
+    fn test_page_defaults_missing_cursor_fields() {
+        let page: Page<serde_json::Value> = serde_json::from_str(r#"{"object": "list", "data": [{"id": "x"}]}"#).unwrap();
+        assert_eq!(page.first_id, None);
+        assert!(!page.has_more);
+        assert!(!page.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursors_across_pages() {
+        use futures_util::StreamExt;
+
+        let pages = |after: Option<String>| async move {
+            let (ids, has_more) = match after.as_deref() {
+                None => (vec!["a", "b"], true),
+                Some("b") => (vec!["c"], true),
+                Some("c") => (vec!["d"], false),
+                Some(other) => return Err(crate::common::errors::OpenAIToolError::Error(format!("unexpected cursor {}", other))),
+            };
+            let data: Vec<String> = ids.into_iter().map(str::to_string).collect();
+            Ok(Page { first_id: data.first().cloned(), last_id: data.last().cloned(), has_more, data })
+        };
+
+        let items: Vec<String> = paginate(pages).map(|item| item.unwrap()).collect().await;
+        assert_eq!(items, vec!["a", "b", "c", "d"]);
+
+        let resumed: Vec<String> = paginate_after(Some("b".to_string()), pages).map(|item| item.unwrap()).collect().await;
+        assert_eq!(resumed, vec!["c", "d"]);
+
+        let failed: Vec<_> = paginate_after(Some("x".to_string()), pages).collect().await;
+        assert_eq!(failed.len(), 1);
+        assert!(failed[0].is_err());
+    }
+
+    #[test]
+    fn test_list_responses_expose_cursor() {
+        use crate::fine_tuning::response::FineTuningJobListResponse;
+
+        let files: FileListResponse = serde_json::from_str(FILES_JSON).unwrap();
+        assert_eq!(Paginated::next_cursor(&files), Some("file-def"));
+        assert_eq!(files.data().len(), 2);
+
+        let jobs: FineTuningJobListResponse = serde_json::from_str(r#"{"object": "list", "data": [], "has_more": false}"#).unwrap();
+        assert_eq!(jobs.next_cursor(), None);
+        assert!(jobs.into_data().is_empty());
+    }
+}
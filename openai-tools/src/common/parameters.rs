@@ -1,6 +1,6 @@
 use serde::{de::Error, Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 pub type Name = String;
 
@@ -12,6 +12,18 @@ pub struct ParameterProperty {
     pub description: Option<String>,
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
     pub enum_values: Option<Vec<String>>,
+    /// Schema of the elements of an `array` parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<ParameterProperty>>,
+    /// Properties of a nested `object` parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<Name, ParameterProperty>>,
+    /// Required properties of a nested `object` parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<Name>>,
+    /// Whether a nested `object` parameter accepts properties not listed in `properties`
+    #[serde(rename = "additionalProperties", skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<bool>,
 }
 
 impl TryFrom<Value> for ParameterProperty {
@@ -41,7 +53,24 @@ impl TryFrom<Value> for ParameterProperty {
                 None
             }
         };
-        Ok(Self { type_name, description, enum_values })
+        let items = match props.get("items") {
+            Some(items) => Some(Box::new(Self::try_from(items.clone())?)),
+            None => None,
+        };
+        let properties = match props.get("properties") {
+            Some(Value::Object(properties)) => Some(
+                properties
+                    .iter()
+                    .map(|(name, prop)| Ok((name.clone(), Self::try_from(prop.clone())?)))
+                    .collect::<Result<BTreeMap<Name, Self>, serde_json::Error>>()?,
+            ),
+            Some(_) => return Err(serde_json::Error::custom("Expected 'properties' to be an object")),
+            None => None,
+        };
+        let required =
+            props.get("required").and_then(Value::as_array).map(|names| names.iter().filter_map(Value::as_str).map(|s| s.to_string()).collect());
+        let additional_properties = props.get("additionalProperties").and_then(Value::as_bool);
+        Ok(Self { type_name, description, enum_values, items, properties, required, additional_properties })
     }
 }
 
@@ -61,22 +90,94 @@ impl From<ParameterProperty> for Value {
         if let Some(enum_values) = prop.enum_values {
             map.insert("enum".to_string(), Value::Array(enum_values.iter().map(|s| Value::String(s.clone())).collect()));
         }
+
+        // items
+        if let Some(items) = prop.items {
+            map.insert("items".to_string(), Value::from(*items));
+        }
+
+        // properties
+        if let Some(properties) = prop.properties {
+            map.insert("properties".to_string(), Value::Object(properties.into_iter().map(|(name, prop)| (name, Value::from(prop))).collect()));
+        }
+
+        // required
+        if let Some(required) = prop.required {
+            map.insert("required".to_string(), Value::Array(required.into_iter().map(Value::String).collect()));
+        }
+
+        // additionalProperties
+        if let Some(additional_properties) = prop.additional_properties {
+            map.insert("additionalProperties".to_string(), Value::Bool(additional_properties));
+        }
         Value::Object(map)
     }
 }
 
 impl ParameterProperty {
     pub fn from_string<T: AsRef<str>>(description: T) -> Self {
-        Self { type_name: "string".into(), description: Some(description.as_ref().to_string()), enum_values: None }
+        Self { type_name: "string".into(), description: Some(description.as_ref().to_string()), ..Default::default() }
     }
     pub fn from_number<T: AsRef<str>>(description: T) -> Self {
-        Self { type_name: "number".into(), description: Some(description.as_ref().to_string()), enum_values: None }
+        Self { type_name: "number".into(), description: Some(description.as_ref().to_string()), ..Default::default() }
     }
     pub fn from_boolean<T: AsRef<str>>(description: T) -> Self {
-        Self { type_name: "boolean".into(), description: Some(description.as_ref().to_string()), enum_values: None }
+        Self { type_name: "boolean".into(), description: Some(description.as_ref().to_string()), ..Default::default() }
     }
     pub fn from_integer<T: AsRef<str>>(description: T) -> Self {
-        Self { type_name: "integer".into(), description: Some(description.as_ref().to_string()), enum_values: None }
+        Self { type_name: "integer".into(), description: Some(description.as_ref().to_string()), ..Default::default() }
+    }
+    /// Creates a `string` parameter restricted to the given `variants`.
+    ///
+    /// The model can then only produce one of the listed values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::parameters::ParameterProperty;
+    /// use openai_tools::common::tool::Tool;
+    ///
+    /// let unit = ParameterProperty::from_enum("Temperature unit", vec!["celsius", "fahrenheit"]);
+    /// let json = serde_json::to_value(&unit).unwrap();
+    /// assert_eq!(json["enum"], serde_json::json!(["celsius", "fahrenheit"]));
+    ///
+    /// let tool = Tool::function("get_weather", "Get the weather", vec![("unit", unit)], false);
+    /// ```
+    pub fn from_enum<T: AsRef<str>>(description: T, variants: Vec<&str>) -> Self {
+        Self::from_string(description).add_enum_values(variants)
+    }
+    /// Creates an `array` parameter whose elements follow `item_type`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::parameters::ParameterProperty;
+    ///
+    /// let tags = ParameterProperty::from_array(ParameterProperty::from_string("A tag"), "Tags to attach");
+    /// let json = serde_json::to_value(&tags).unwrap();
+    /// assert_eq!(json["type"], "array");
+    /// assert_eq!(json["items"]["type"], "string");
+    /// ```
+    pub fn from_array<T: AsRef<str>>(item_type: ParameterProperty, description: T) -> Self {
+        Self {
+            type_name: "array".into(),
+            description: Some(description.as_ref().to_string()),
+            items: Some(Box::new(item_type)),
+            ..Default::default()
+        }
+    }
+    /// Creates a nested `object` parameter with the properties of `nested`.
+    ///
+    /// The `required` and `additionalProperties` settings of `nested` are carried over.
+    pub fn from_object<T: AsRef<str>>(nested: Parameters, description: T) -> Self {
+        Self {
+            type_name: "object".into(),
+            description: Some(description.as_ref().to_string()),
+            properties: Some(nested.properties),
+            required: nested.required,
+            additional_properties: nested.additional_properties,
+            ..Default::default()
+        }
     }
     pub fn add_enum_values<T: AsRef<str>>(&mut self, values: Vec<T>) -> Self {
         self.enum_values = Some(values.into_iter().map(|v| v.as_ref().to_string()).collect());
@@ -88,7 +189,7 @@ impl ParameterProperty {
 pub struct Parameters {
     #[serde(rename = "type")]
     pub type_name: String,
-    pub properties: HashMap<Name, ParameterProperty>,
+    pub properties: BTreeMap<Name, ParameterProperty>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<Vec<Name>>,
     #[serde(rename = "additionalProperties", skip_serializing_if = "Option::is_none")]
@@ -97,10 +198,52 @@ pub struct Parameters {
 
 impl Parameters {
     pub fn new<T: AsRef<str>>(properties: Vec<(T, ParameterProperty)>, additional_properties: Option<bool>) -> Self {
-        let props = properties.iter().map(|(k, v)| (k.as_ref().to_string(), v.clone())).collect::<HashMap<String, ParameterProperty>>();
+        let props = properties.iter().map(|(k, v)| (k.as_ref().to_string(), v.clone())).collect::<BTreeMap<String, ParameterProperty>>();
         let required = properties.iter().map(|(k, _)| k.as_ref().to_string()).collect::<Vec<_>>();
         Self { type_name: "object".into(), properties: props, required: Some(required), additional_properties }
     }
+
+    /// Replaces the `required` list, marking every other property as optional.
+    ///
+    /// [`Parameters::new`] marks all properties as required; use this when some
+    /// of them may be omitted by the model.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::parameters::{ParameterProperty, Parameters};
+    ///
+    /// let params = Parameters::new(
+    ///     vec![("query", ParameterProperty::from_string("Search query")), ("limit", ParameterProperty::from_integer("Maximum results"))],
+    ///     None,
+    /// )
+    /// .with_required(vec!["query"]);
+    /// assert_eq!(params.required, Some(vec!["query".to_string()]));
+    /// ```
+    pub fn with_required<T: AsRef<str>>(mut self, required: Vec<T>) -> Self {
+        self.required = Some(required.iter().map(|name| name.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Sets `additionalProperties` to `false` on this object and every nested
+    /// object that does not set it explicitly, as strict mode requires.
+    pub(crate) fn disallow_additional_properties(&mut self) {
+        self.additional_properties.get_or_insert(false);
+        self.properties.values_mut().for_each(ParameterProperty::disallow_additional_properties);
+    }
+}
+
+impl ParameterProperty {
+    /// Applies [`Parameters::disallow_additional_properties`] to nested objects and array items.
+    fn disallow_additional_properties(&mut self) {
+        if let Some(properties) = &mut self.properties {
+            self.additional_properties.get_or_insert(false);
+            properties.values_mut().for_each(ParameterProperty::disallow_additional_properties);
+        }
+        if let Some(items) = &mut self.items {
+            items.disallow_additional_properties();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -143,4 +286,56 @@ mod tests {
         assert_eq!(deserialized.enum_values, Some(vec!["value1".to_string(), "value2".to_string(), "value3".to_string()]));
         assert_eq!(deserialized.description, Some("An enum parameter".to_string()));
     }
+
+    #[test]
+    fn test_parameter_property_array_serialization() {
+        let prop = ParameterProperty::from_array(ParameterProperty::from_string("A tag"), "Tags to attach");
+        let value = serde_json::to_value(&prop).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "type": "array",
+                "description": "Tags to attach",
+                "items": {"type": "string", "description": "A tag"}
+            })
+        );
+
+        let deserialized = serde_json::from_value::<ParameterProperty>(value.clone()).unwrap();
+        assert_eq!(deserialized.items.unwrap().type_name, "string");
+        assert_eq!(Value::from(ParameterProperty::try_from(value.clone()).unwrap()), value);
+    }
+
+    #[test]
+    fn test_parameter_property_object_serialization() {
+        let address = Parameters::new(
+            vec![("city", ParameterProperty::from_string("City name")), ("zip", ParameterProperty::from_string("Postal code"))],
+            Some(false),
+        );
+        let prop = ParameterProperty::from_object(address, "Shipping address");
+        let value = serde_json::to_value(&prop).unwrap();
+        assert_eq!(value["type"], "object");
+        assert_eq!(value["description"], "Shipping address");
+        assert_eq!(value["properties"]["city"], serde_json::json!({"type": "string", "description": "City name"}));
+        assert_eq!(value["properties"]["zip"], serde_json::json!({"type": "string", "description": "Postal code"}));
+        assert_eq!(value["required"], serde_json::json!(["city", "zip"]));
+        assert_eq!(value["additionalProperties"], false);
+
+        let params = Parameters::new(vec![("address", prop)], None);
+        let value = serde_json::to_value(&params).unwrap();
+        assert_eq!(value["properties"]["address"]["properties"]["city"]["type"], "string");
+        assert_eq!(Value::from(ParameterProperty::try_from(value["properties"]["address"].clone()).unwrap()), value["properties"]["address"]);
+    }
+
+    #[test]
+    fn test_parameter_property_from_enum() {
+        let prop = ParameterProperty::from_enum("Temperature unit", vec!["celsius", "fahrenheit"]);
+        assert_eq!(
+            serde_json::to_value(&prop).unwrap(),
+            serde_json::json!({"type": "string", "description": "Temperature unit", "enum": ["celsius", "fahrenheit"]})
+        );
+
+        let tool = crate::common::tool::Tool::function("get_weather", "Get the weather", vec![("unit", prop)], false);
+        let value = serde_json::to_value(&tool).unwrap();
+        assert_eq!(value["function"]["parameters"]["properties"]["unit"]["enum"], serde_json::json!(["celsius", "fahrenheit"]));
+    }
 }
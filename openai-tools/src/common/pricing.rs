@@ -0,0 +1,272 @@
+//! Model pricing and request cost estimation.
+//!
+//! Prices are list prices in USD per one million tokens for the standard
+//! processing tier. They are provided for estimates only; check the
+//! [pricing page](https://openai.com/api/pricing/) for current values.
+
+use crate::common::errors::Result;
+use crate::common::message::Message;
+use crate::common::models::{strip_snapshot_date, ChatModel};
+use crate::common::usage::Usage;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Number of tokens added by the chat format for each message.
+const TOKENS_PER_MESSAGE: usize = 3;
+
+/// Number of tokens used to prime the assistant's reply.
+pub(crate) const TOKENS_PER_REPLY: usize = 3;
+
+/// Per-token prices of a model, in USD per one million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+    /// Price of input tokens
+    pub input: f64,
+    /// Price of cached input tokens, if the model supports prompt caching
+    pub cached_input: Option<f64>,
+    /// Price of output tokens
+    pub output: f64,
+}
+
+impl ModelPricing {
+    /// Creates a pricing entry from prices in USD per one million tokens.
+    pub const fn new(input: f64, cached_input: Option<f64>, output: f64) -> Self {
+        Self { input, cached_input, output }
+    }
+
+    /// Returns the list price of a model, or `None` for custom and unknown models.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::models::ChatModel;
+    /// use openai_tools::common::pricing::ModelPricing;
+    ///
+    /// let pricing = ModelPricing::for_model(&ChatModel::Gpt4oMini).unwrap();
+    /// assert_eq!(pricing.input, 0.15);
+    /// assert!(ModelPricing::for_model(&ChatModel::custom("ft:gpt-4o-mini:org::abc")).is_none());
+    /// ```
+    pub fn for_model(model: &ChatModel) -> Option<Self> {
+        let pricing = match model {
+            // GPT-5 Series
+            ChatModel::Gpt5_2 | ChatModel::Gpt5_2ChatLatest => Self::new(1.75, Some(0.175), 14.0),
+            ChatModel::Gpt5_2Pro => Self::new(21.0, None, 168.0),
+            ChatModel::Gpt5_1 | ChatModel::Gpt5_1ChatLatest | ChatModel::Gpt5_1CodexMax => Self::new(1.25, Some(0.125), 10.0),
+            ChatModel::Gpt5Mini => Self::new(0.25, Some(0.025), 2.0),
+            ChatModel::Gpt5Nano => Self::new(0.05, Some(0.005), 0.4),
+            // GPT-4.1 Series
+            ChatModel::Gpt4_1 => Self::new(2.0, Some(0.5), 8.0),
+            ChatModel::Gpt4_1Mini => Self::new(0.4, Some(0.1), 1.6),
+            ChatModel::Gpt4_1Nano => Self::new(0.1, Some(0.025), 0.4),
+            // GPT-4o Series
+            ChatModel::Gpt4o => Self::new(2.5, Some(1.25), 10.0),
+            ChatModel::Gpt4oMini => Self::new(0.15, Some(0.075), 0.6),
+            ChatModel::Gpt4oAudioPreview => Self::new(2.5, None, 10.0),
+            // GPT-4 Series
+            ChatModel::Gpt4Turbo => Self::new(10.0, None, 30.0),
+            ChatModel::Gpt4 => Self::new(30.0, None, 60.0),
+            // GPT-3.5 Series
+            ChatModel::Gpt3_5Turbo => Self::new(0.5, None, 1.5),
+            // Reasoning Models
+            ChatModel::O1 => Self::new(15.0, Some(7.5), 60.0),
+            ChatModel::O1Pro => Self::new(150.0, None, 600.0),
+            ChatModel::O3 => Self::new(2.0, Some(0.5), 8.0),
+            ChatModel::O3Mini => Self::new(1.1, Some(0.55), 4.4),
+            ChatModel::O4Mini => Self::new(1.1, Some(0.275), 4.4),
+            // Custom
+            ChatModel::Custom(_) => return None,
+        };
+        Some(pricing)
+    }
+
+    /// Returns the cost in USD of the given number of input tokens.
+    pub fn input_cost(&self, tokens: usize) -> f64 {
+        tokens as f64 * self.input / 1_000_000.0
+    }
+
+    /// Returns the cost in USD of the given number of output tokens.
+    pub fn output_cost(&self, tokens: usize) -> f64 {
+        tokens as f64 * self.output / 1_000_000.0
+    }
+
+    /// Returns the cost in USD of the tokens reported in `usage`.
+    ///
+    /// Cached prompt tokens are billed at the cached input price when the model
+    /// has one; reasoning tokens are part of the output tokens.
+    pub fn usage_cost(&self, usage: &Usage) -> f64 {
+        let prompt_tokens = usage.prompt_token_count();
+        let cached_tokens = usage.cached_token_count().min(prompt_tokens);
+        let cached_cost = cached_tokens as f64 * self.cached_input.unwrap_or(self.input) / 1_000_000.0;
+        self.input_cost(prompt_tokens - cached_tokens) + cached_cost + self.output_cost(usage.completion_token_count())
+    }
+}
+
+/// Prices of models by model ID, with user-defined overrides.
+///
+/// Lookups fall back to the built-in list prices of [`ModelPricing::for_model`].
+/// Dated snapshots such as `gpt-4o-2024-08-06` resolve to their base model.
+///
+/// Overrides can be loaded from JSON with [`PricingTable::from_json`], so price
+/// changes can be picked up from a configuration file without a crate release.
+/// The JSON form maps model IDs to prices in USD per one million tokens:
+///
+/// ```json
+/// {"gpt-4o": {"input": 2.5, "cached_input": 1.25, "output": 10.0}}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::common::pricing::{ModelPricing, PricingTable};
+///
+/// let mut pricing = PricingTable::new();
+/// pricing.set("ft:gpt-4o-mini:my-org::abc123", ModelPricing::new(0.3, Some(0.15), 1.2));
+///
+/// assert_eq!(pricing.get("gpt-4o-mini-2024-07-18").unwrap().input, 0.15);
+/// assert_eq!(pricing.get("ft:gpt-4o-mini:my-org::abc123").unwrap().input, 0.3);
+/// assert!(pricing.get("my-local-model").is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PricingTable {
+    /// Prices that take precedence over the built-in ones
+    overrides: HashMap<String, ModelPricing>,
+}
+
+impl PricingTable {
+    /// Creates a table with the built-in list prices.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a table whose overrides are read from JSON.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PricingTable)` - The table with the given overrides
+    /// * `Err(OpenAIToolError::SerdeJsonError)` - If the JSON is not a map of model IDs to prices
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the user-defined overrides.
+    pub fn overrides(&self) -> &HashMap<String, ModelPricing> {
+        &self.overrides
+    }
+
+    /// Sets the price of a model, overriding the built-in price if any.
+    pub fn set<T: Into<String>>(&mut self, model_id: T, pricing: ModelPricing) -> &mut Self {
+        self.overrides.insert(model_id.into(), pricing);
+        self
+    }
+
+    /// Returns the price of a model, or `None` if it is unknown.
+    pub fn get(&self, model_id: &str) -> Option<ModelPricing> {
+        let lookup = |id: &str| self.overrides.get(id).copied().or_else(|| ModelPricing::for_model(&ChatModel::from(id)));
+        lookup(model_id).or_else(|| lookup(strip_snapshot_date(model_id)?))
+    }
+}
+
+/// Estimated size and cost of a request, computed locally without a network call.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RequestPreview {
+    /// Estimated number of prompt (input) tokens
+    pub estimated_prompt_tokens: usize,
+    /// Estimated cost in USD of the prompt tokens, or `None` if the model's price is unknown
+    ///
+    /// Output tokens are not included since their number is only known after generation.
+    pub estimated_cost: Option<f64>,
+}
+
+impl RequestPreview {
+    /// Estimates the prompt size and cost of sending `messages` to `model`.
+    ///
+    /// Text content is tokenized with the model's tokenizer (falling back to
+    /// `o200k_base`), and the per-message overhead of the chat format is added.
+    /// Non-text content such as images is not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model the request will be sent to
+    /// * `instructions` - Optional system-level instructions sent with the request
+    /// * `messages` - The input messages
+    pub fn estimate(model: &ChatModel, instructions: Option<&str>, messages: &[Message]) -> Self {
+        let bpe = tokenizer(model);
+        let count = |text: &str| bpe.encode_with_special_tokens(text).len();
+
+        let mut tokens = TOKENS_PER_REPLY;
+        if let Some(instructions) = instructions {
+            tokens += TOKENS_PER_MESSAGE + count("developer") + count(instructions);
+        }
+        tokens += messages.iter().map(|message| count_message_tokens(bpe, message)).sum::<usize>();
+
+        let estimated_cost = ModelPricing::for_model(model).map(|pricing| pricing.input_cost(tokens));
+        Self { estimated_prompt_tokens: tokens, estimated_cost }
+    }
+}
+
+/// Returns the tokenizer of `model`, falling back to `o200k_base`.
+///
+/// Tokenizers are built once per process, since building one takes far longer
+/// than counting the tokens of a typical request.
+fn tokenizer(model: &ChatModel) -> &'static tiktoken_rs::CoreBPE {
+    use tiktoken_rs::tokenizer::{get_tokenizer, Tokenizer};
+
+    match get_tokenizer(model.as_str()) {
+        Some(Tokenizer::Cl100kBase) => tiktoken_rs::cl100k_base_singleton(),
+        Some(Tokenizer::P50kBase) => tiktoken_rs::p50k_base_singleton(),
+        Some(Tokenizer::P50kEdit) => tiktoken_rs::p50k_edit_singleton(),
+        Some(Tokenizer::R50kBase | Tokenizer::Gpt2) => tiktoken_rs::r50k_base_singleton(),
+        Some(Tokenizer::O200kHarmony) => tiktoken_rs::o200k_harmony_singleton(),
+        Some(Tokenizer::O200kBase) | None => tiktoken_rs::o200k_base_singleton(),
+    }
+}
+
+/// Counts the text tokens of a message plus the per-message overhead of the chat format.
+fn count_message_tokens(bpe: &tiktoken_rs::CoreBPE, message: &Message) -> usize {
+    let count = |text: &str| bpe.encode_with_special_tokens(text).len();
+    let contents = message.content.iter().chain(message.content_list.iter().flatten());
+    TOKENS_PER_MESSAGE + count(message.role.as_str()) + contents.filter_map(|content| content.text.as_deref()).map(count).sum::<usize>()
+}
+
+/// Estimates the prompt tokens of each message with the model's tokenizer.
+///
+/// The sum plus [`TOKENS_PER_REPLY`] equals the prompt size reported by
+/// [`RequestPreview::estimate`] for the same messages.
+pub(crate) fn message_token_counts(model: &ChatModel, messages: &[Message]) -> Vec<usize> {
+    let bpe = tokenizer(model);
+    messages.iter().map(|message| count_message_tokens(bpe, message)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::role::Role;
+
+    #[test]
+    fn test_request_preview_matches_hand_computed_tokens() {
+        // o200k_base: "Hello" "," " world" "!" => 4 tokens, "user" => 1 token
+        let messages = vec![Message::from_string(Role::User, "Hello, world!")];
+        let preview = RequestPreview::estimate(&ChatModel::Gpt4oMini, None, &messages);
+
+        // 3 (message overhead) + 1 (role) + 4 (content) + 3 (reply priming)
+        assert_eq!(preview.estimated_prompt_tokens, 11);
+        let cost = preview.estimated_cost.unwrap();
+        assert!((cost - 11.0 * 0.15 / 1_000_000.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_request_preview_unknown_model_has_no_cost() {
+        let messages = vec![Message::from_string(Role::User, "Hello, world!")];
+        let preview = RequestPreview::estimate(&ChatModel::custom("my-fine-tuned-model"), None, &messages);
+        assert_eq!(preview.estimated_prompt_tokens, 11);
+        assert_eq!(preview.estimated_cost, None);
+    }
+
+    #[test]
+    fn test_model_pricing_costs() {
+        let pricing = ModelPricing::new(2.0, Some(0.5), 8.0);
+        assert!((pricing.input_cost(1_000_000) - 2.0).abs() < f64::EPSILON);
+        assert!((pricing.output_cost(500_000) - 4.0).abs() < f64::EPSILON);
+    }
+}
@@ -6,6 +6,11 @@ pub enum Role {
     #[serde(rename = "system")]
     #[strum(to_string = "system")]
     System,
+    /// Instructions from the application developer, which newer models
+    /// (GPT-5, o-series) use in place of `system`
+    #[serde(rename = "developer")]
+    #[strum(to_string = "developer")]
+    Developer,
     #[serde(rename = "user")]
     #[strum(to_string = "user")]
     User,
@@ -27,6 +32,7 @@ impl TryFrom<String> for Role {
         let role = role.to_lowercase();
         match role.as_str() {
             "system" => Ok(Role::System),
+            "developer" => Ok(Role::Developer),
             "user" => Ok(Role::User),
             "assistant" => Ok(Role::Assistant),
             "function" => Ok(Role::Function),
@@ -40,12 +46,19 @@ impl Role {
     pub fn as_str(&self) -> &str {
         match self {
             Role::System => "system",
+            Role::Developer => "developer",
             Role::User => "user",
             Role::Assistant => "assistant",
             Role::Function => "function",
             Role::Tool => "tool",
         }
     }
+
+    /// Returns `true` for the roles that carry instructions rather than
+    /// conversation turns: `system` and `developer`.
+    pub fn is_instruction(&self) -> bool {
+        matches!(self, Role::System | Role::Developer)
+    }
 }
 
 #[cfg(test)]
@@ -54,6 +67,7 @@ mod tests {
     #[test]
     fn test_role_conversion() {
         assert_eq!(Role::try_from("system".to_string()).unwrap(), Role::System);
+        assert_eq!(Role::try_from("developer".to_string()).unwrap(), Role::Developer);
         assert_eq!(Role::try_from("user".to_string()).unwrap(), Role::User);
         assert_eq!(Role::try_from("assistant".to_string()).unwrap(), Role::Assistant);
         assert_eq!(Role::try_from("function".to_string()).unwrap(), Role::Function);
@@ -64,9 +78,20 @@ mod tests {
     #[test]
     fn test_role_as_str() {
         assert_eq!(Role::System.as_str(), "system");
+        assert_eq!(Role::Developer.as_str(), "developer");
         assert_eq!(Role::User.as_str(), "user");
         assert_eq!(Role::Assistant.as_str(), "assistant");
         assert_eq!(Role::Function.as_str(), "function");
         assert_eq!(Role::Tool.as_str(), "tool");
     }
+
+    #[test]
+    fn test_developer_role_serialization() {
+        assert_eq!(serde_json::to_string(&Role::Developer).unwrap(), r#""developer""#);
+        assert_eq!(serde_json::from_str::<Role>(r#""developer""#).unwrap(), Role::Developer);
+        assert_eq!(Role::Developer.to_string(), "developer");
+        assert!(Role::Developer.is_instruction());
+        assert!(Role::System.is_instruction());
+        assert!(!Role::User.is_instruction());
+    }
 }
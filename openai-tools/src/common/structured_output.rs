@@ -1,52 +1,175 @@
+use crate::common::errors::{OpenAIToolError, Result};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Maximum number of characters of the offending text quoted in parse errors.
+const MAX_QUOTED_CHARS: usize = 500;
+
+#[cfg(feature = "derive")]
+pub use openai_tools_derive::JsonSchema;
+
+/// Types that describe their own JSON Schema.
+///
+/// Implemented for strings, numbers, booleans, `Option<T>`, `Vec<T>` and
+/// slices. Structs usually implement it with `#[derive(JsonSchema)]` (the
+/// `derive` feature), which lists every field as a required property and uses
+/// each field's doc comment as its description:
+///
+/// ```rust,ignore
+/// use openai_tools::common::structured_output::{JsonSchema, Schema};
+///
+/// #[derive(JsonSchema, serde::Deserialize)]
+/// struct Weather {
+///     /// City name
+///     city: String,
+///     /// Temperature in degrees Celsius
+///     temperature: f64,
+/// }
+///
+/// let schema = Schema::chat_json_schema_for::<Weather>("weather");
+/// ```
+///
+/// Types passed to [`Schema::chat_json_schema_for`] or
+/// [`Schema::responses_json_schema_for`] must produce an object schema.
+pub trait JsonSchema {
+    /// Returns the JSON Schema describing this type.
+    fn json_schema() -> serde_json::Value;
+}
+
+macro_rules! impl_json_schema {
+    ($type_name:literal: $($ty:ty),*) => {
+        $(
+            impl JsonSchema for $ty {
+                fn json_schema() -> serde_json::Value {
+                    serde_json::json!({ "type": $type_name })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!("string": String, str, char);
+impl_json_schema!("boolean": bool);
+impl_json_schema!("integer": i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_json_schema!("number": f32, f64);
+
+/// Optional values accept `null` in addition to the inner type: `"null"` is
+/// added to the `type` of the inner schema, or the schema is wrapped in
+/// `anyOf` if it has no `type`. Strict structured outputs still require the
+/// property to be present.
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn json_schema() -> serde_json::Value {
+        let mut schema = T::json_schema();
+        match schema.get_mut("type") {
+            Some(serde_json::Value::String(type_name)) => {
+                let type_name = std::mem::take(type_name);
+                schema["type"] = serde_json::json!([type_name, "null"]);
+            }
+            Some(serde_json::Value::Array(types)) => {
+                if !types.iter().any(|t| t == "null") {
+                    types.push("null".into());
+                }
+            }
+            _ => schema = serde_json::json!({ "anyOf": [schema, { "type": "null" }] }),
+        }
+        schema
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for [T] {
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+    fn json_schema() -> serde_json::Value {
+        <[T]>::json_schema()
+    }
+}
+
+impl<T: JsonSchema + ?Sized> JsonSchema for Box<T> {
+    fn json_schema() -> serde_json::Value {
+        T::json_schema()
+    }
+}
+
+impl<T: JsonSchema + ?Sized> JsonSchema for &T {
+    fn json_schema() -> serde_json::Value {
+        T::json_schema()
+    }
+}
+
+/// The `type` of a schema item: one JSON type, or several, such as
+/// `["string", "null"]` for optional values.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+enum TypeName {
+    Single(String),
+    Union(Vec<String>),
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct ItemType {
     #[serde(rename = "type")]
-    type_name: String,
+    type_name: TypeName,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    items: Option<Box<JsonItem>>,
+    items: Option<Box<ItemType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<HashMap<String, ItemType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<Vec<String>>,
+    #[serde(rename = "additionalProperties", skip_serializing_if = "Option::is_none")]
+    additional_properties: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minimum: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maximum: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
 }
 
 impl ItemType {
     pub fn new<T: AsRef<str>, U: AsRef<str>>(type_name: T, description: U) -> Self {
         Self {
-            type_name: type_name.as_ref().to_string(),
+            type_name: TypeName::Single(type_name.as_ref().to_string()),
             description: match description.as_ref() {
                 "" => None,
                 _ => Some(description.as_ref().to_string()),
             },
             items: None,
+            properties: None,
+            required: None,
+            additional_properties: None,
+            minimum: None,
+            maximum: None,
+            pattern: None,
         }
     }
+}
 
-    pub fn clone(&self) -> Self {
-        let mut items: JsonItem = JsonItem::default();
-        if let Some(item) = &self.items {
-            let mut _properties: HashMap<String, ItemType> = HashMap::default();
-            for (key, value) in item.properties.iter() {
-                _properties.insert(key.clone(), value.clone());
-            }
-            items.type_name = item.type_name.clone();
-            items.properties = _properties;
-            items.required = item.required.clone();
-            items.additional_properties = item.additional_properties;
-        }
-
+impl From<JsonItem> for ItemType {
+    fn from(item: JsonItem) -> Self {
         Self {
-            type_name: self.type_name.clone(),
-            description: self.description.clone(),
-            items: if self.items.is_some() { Option::from(Box::new(items)) } else { None },
+            type_name: TypeName::Single(item.type_name.unwrap_or_else(|| "object".to_string())),
+            description: None,
+            items: None,
+            properties: Some(item.properties),
+            required: item.required,
+            additional_properties: Some(item.additional_properties),
+            minimum: None,
+            maximum: None,
+            pattern: None,
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct JsonItem {
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     type_name: Option<String>,
     properties: HashMap<String, ItemType>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -57,7 +180,7 @@ struct JsonItem {
 
 impl JsonItem {
     fn add_property<T: AsRef<str>>(&mut self, prop_name: T, item: ItemType) {
-        self.properties.insert(prop_name.as_ref().to_string(), item.clone());
+        self.properties.insert(prop_name.as_ref().to_string(), item);
         if self.required.is_none() {
             self.required = Some(vec![]);
         }
@@ -66,13 +189,23 @@ impl JsonItem {
 
     fn add_array<T: AsRef<str>>(&mut self, prop_name: T, items: JsonItem) {
         let mut prop = ItemType::new("array", "");
-        prop.items = Option::from(Box::new(items));
+        prop.items = Option::from(Box::new(ItemType::from(items)));
         self.properties.insert(prop_name.as_ref().to_string(), prop);
         if self.required.is_none() {
             self.required = Some(vec![]);
         }
         self.required.as_mut().unwrap().push(prop_name.as_ref().to_string());
     }
+
+    /// Converts the JSON Schema of a [`JsonSchema`] type into the root object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema is not an object schema with typed properties,
+    /// which cannot happen for derived implementations.
+    fn of<T: JsonSchema + ?Sized>() -> Self {
+        serde_json::from_value(T::json_schema()).expect("JsonSchema::json_schema() must return an object schema with typed properties")
+    }
 }
 
 impl Default for JsonItem {
@@ -89,19 +222,39 @@ pub struct Schema {
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     schema: Option<JsonItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strict: Option<bool>,
 }
 
 impl Schema {
     pub fn responses_text_schema() -> Self {
-        Self { type_name: Some("text".to_string()), name: None, schema: None }
+        Self { type_name: Some("text".to_string()), name: None, schema: None, strict: None }
     }
 
     pub fn responses_json_schema<T: AsRef<str>>(name: T) -> Self {
-        Self { type_name: Some("json_schema".to_string()), name: Some(name.as_ref().to_string()), schema: Some(JsonItem::default()) }
+        Self { type_name: Some("json_schema".to_string()), name: Some(name.as_ref().to_string()), schema: Some(JsonItem::default()), strict: None }
     }
 
     pub fn chat_json_schema<T: AsRef<str>>(name: T) -> Self {
-        Self { type_name: None, name: Some(name.as_ref().to_string()), schema: Some(JsonItem::default()) }
+        Self { type_name: None, name: Some(name.as_ref().to_string()), schema: Some(JsonItem::default()), strict: None }
+    }
+
+    /// Creates a Responses API schema from the [`JsonSchema`] implementation of `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T::json_schema()` is not an object schema (see [`JsonSchema`]).
+    pub fn responses_json_schema_for<T: JsonSchema + ?Sized>(name: &str) -> Self {
+        Self { type_name: Some("json_schema".to_string()), name: Some(name.to_string()), schema: Some(JsonItem::of::<T>()), strict: None }
+    }
+
+    /// Creates a Chat Completions schema from the [`JsonSchema`] implementation of `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T::json_schema()` is not an object schema (see [`JsonSchema`]).
+    pub fn chat_json_schema_for<T: JsonSchema + ?Sized>(name: &str) -> Self {
+        Self { type_name: None, name: Some(name.to_string()), schema: Some(JsonItem::of::<T>()), strict: None }
     }
 
     pub fn add_property<T: AsRef<str>, U: AsRef<str>, V: AsRef<str>>(&mut self, prop_name: T, type_name: U, description: V) {
@@ -117,4 +270,398 @@ impl Schema {
         }
         self.schema.as_mut().unwrap().add_array(prop_name, array_item);
     }
+
+    /// Adds a required `number` property with inclusive bounds, emitted as `minimum` / `maximum`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prop_name` - The property name
+    /// * `description` - The property description; empty for none
+    /// * `min` - The smallest allowed value, or `None` for no lower bound
+    /// * `max` - The largest allowed value, or `None` for no upper bound
+    pub fn add_number_property_with_range<T: AsRef<str>, U: AsRef<str>>(&mut self, prop_name: T, description: U, min: Option<f64>, max: Option<f64>) {
+        let mut prop = ItemType::new("number", description);
+        prop.minimum = min;
+        prop.maximum = max;
+        self.schema.as_mut().unwrap().add_property(prop_name, prop);
+    }
+
+    /// Adds a required `string` property that must match a regular expression, emitted as `pattern`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prop_name` - The property name
+    /// * `description` - The property description; empty for none
+    /// * `pattern` - The regular expression the value must match, e.g. `"^\\+[1-9][0-9]{7,14}$"`
+    pub fn add_string_property_with_pattern<T: AsRef<str>, U: AsRef<str>, V: AsRef<str>>(&mut self, prop_name: T, description: U, pattern: V) {
+        let mut prop = ItemType::new("string", description);
+        prop.pattern = Some(pattern.as_ref().to_string());
+        self.schema.as_mut().unwrap().add_property(prop_name, prop);
+    }
+
+    /// Adds a required property holding an array of scalar values, e.g. `tags: [string]`.
+    ///
+    /// Produces `{"type": "array", "items": {"type": item_type}}`. Use
+    /// [`add_array`](Self::add_array) for arrays of objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `prop_name` - The property name
+    /// * `item_type` - The JSON type of each element (`"string"`, `"number"`, `"integer"` or `"boolean"`)
+    /// * `description` - The property description; empty for none
+    pub fn add_scalar_array<T: AsRef<str>, U: AsRef<str>, V: AsRef<str>>(&mut self, prop_name: T, item_type: U, description: V) {
+        let mut prop = ItemType::new("array", description);
+        prop.items = Some(Box::new(ItemType::new(item_type, "")));
+        self.schema.as_mut().unwrap().add_property(prop_name, prop);
+    }
+
+    /// Sets the `strict` flag of the emitted `response_format` / `text.format` block.
+    ///
+    /// With `strict: true` the model is guaranteed to follow the schema exactly,
+    /// but the schema must list every property of every object in `required`
+    /// and set `additionalProperties` to `false`. Schemas built with
+    /// [`add_property`](Self::add_property), [`add_array`](Self::add_array) or
+    /// `#[derive(JsonSchema)]` always satisfy this; [`validate`](Self::validate)
+    /// checks it and runs automatically before a request is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to enable strict schema adherence
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    /// Returns whether strict schema adherence is enabled.
+    pub fn is_strict(&self) -> bool {
+        self.strict == Some(true)
+    }
+
+    /// Checks that the schema meets the strict mode requirements when strict mode is enabled.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If strict mode is disabled or the schema satisfies it
+    /// * `Err(OpenAIToolError::InvalidParameter)` - Naming the first property that violates strict mode
+    pub fn validate(&self) -> Result<()> {
+        if !self.is_strict() {
+            return Ok(());
+        }
+        let name = self.name.as_deref().unwrap_or_default();
+        let Some(schema) = &self.schema else {
+            return Err(OpenAIToolError::InvalidParameter(format!("Strict mode requires a JSON schema, but schema '{}' has none", name)));
+        };
+        let root = StrictObject::new(&schema.properties, schema.required.as_deref(), Some(schema.additional_properties));
+        check_strict_object(&SCHEMA_WORDING, "", root)
+            .map_err(|violation| OpenAIToolError::InvalidParameter(format!("Strict schema '{}' is invalid: {}", name, violation)))
+    }
+}
+
+impl StrictSchemaNode for ItemType {
+    fn object(&self) -> Option<StrictObject<'_, Self>> {
+        let properties = self.properties.as_ref()?;
+        Some(StrictObject::new(properties, self.required.as_deref(), self.additional_properties))
+    }
+
+    fn items(&self) -> Option<&Self> {
+        self.items.as_deref()
+    }
+}
+
+/// How strict schema violations name objects and their properties.
+const SCHEMA_WORDING: StrictWording = StrictWording { root: "the root object", object: "object", member: "property" };
+
+/// A schema node that strict mode constrains: an object, an array, or a leaf.
+///
+/// Implemented by the property types of structured output schemas and of
+/// function tool parameters, so both are checked by [`check_strict_object`].
+pub(crate) trait StrictSchemaNode: Sized {
+    /// Returns the object this node describes, if it has properties.
+    fn object(&self) -> Option<StrictObject<'_, Self>>;
+
+    /// Returns the schema of the array items, if this node is an array.
+    fn items(&self) -> Option<&Self>;
+}
+
+/// The parts of an object schema that strict mode constrains.
+pub(crate) struct StrictObject<'a, N> {
+    properties: Vec<(&'a str, &'a N)>,
+    required: &'a [String],
+    additional_properties: Option<bool>,
+}
+
+impl<'a, N> StrictObject<'a, N> {
+    pub(crate) fn new<I>(properties: I, required: Option<&'a [String]>, additional_properties: Option<bool>) -> Self
+    where
+        I: IntoIterator<Item = (&'a String, &'a N)>,
+    {
+        let properties = properties.into_iter().map(|(key, node)| (key.as_str(), node)).collect();
+        Self { properties, required: required.unwrap_or_default(), additional_properties }
+    }
+}
+
+/// How strict mode violations name the root object, nested objects and their members.
+pub(crate) struct StrictWording {
+    /// The root object, e.g. "the root object"
+    pub(crate) root: &'static str,
+    /// Prefix of a nested object's path, e.g. "object"
+    pub(crate) object: &'static str,
+    /// The members of an object, e.g. "property"
+    pub(crate) member: &'static str,
+}
+
+/// Checks one object of a strict schema, then the objects nested in it.
+///
+/// Every object must list all of its members as required and set
+/// `additionalProperties` to `false`. `path` is the dotted path of the object,
+/// empty for the root.
+pub(crate) fn check_strict_object<N: StrictSchemaNode>(
+    wording: &StrictWording,
+    path: &str,
+    object: StrictObject<'_, N>,
+) -> std::result::Result<(), String> {
+    let name = if path.is_empty() { wording.root.to_string() } else { format!("{} '{}'", wording.object, path) };
+    if object.additional_properties != Some(false) {
+        return Err(format!("{} must set additionalProperties to false", name));
+    }
+    let mut missing: Vec<&str> = object.properties.iter().map(|(key, _)| *key).filter(|key| !object.required.iter().any(|r| r == key)).collect();
+    missing.sort_unstable();
+    if !missing.is_empty() {
+        return Err(format!("every {} must be required, but {} does not require {}", wording.member, name, missing.join(", ")));
+    }
+    for (key, node) in object.properties {
+        let path = if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) };
+        check_strict_node(wording, &path, node)?;
+    }
+    Ok(())
+}
+
+/// Checks the objects nested in a node of a strict schema.
+fn check_strict_node<N: StrictSchemaNode>(wording: &StrictWording, path: &str, node: &N) -> std::result::Result<(), String> {
+    if let Some(object) = node.object() {
+        check_strict_object(wording, path, object)?;
+    }
+    if let Some(items) = node.items() {
+        check_strict_node(wording, &format!("{}[]", path), items)?;
+    }
+    Ok(())
+}
+
+/// Deserializes structured output text into `T`.
+///
+/// Parse errors are returned as [`OpenAIToolError::SerdeJsonError`] whose message
+/// quotes the offending text (truncated to [`MAX_QUOTED_CHARS`] characters).
+pub(crate) fn parse_structured_output<T: DeserializeOwned>(text: &str) -> Result<T> {
+    serde_json::from_str(text).map_err(|e| {
+        let mut quoted: String = text.chars().take(MAX_QUOTED_CHARS).collect();
+        if quoted.len() < text.len() {
+            quoted.push_str("...");
+        }
+        OpenAIToolError::SerdeJsonError(serde::de::Error::custom(format!("{} in structured output: {}", e, quoted)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openai_tools_derive::JsonSchema;
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    struct Address {
+        /// Street and number
+        street: String,
+        /// City name
+        city: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    struct PersonInfo {
+        /// Person's full name
+        name: String,
+        /// Person's age
+        age: u32,
+        #[serde(rename = "job")]
+        occupation: Option<String>,
+        /// Known nicknames,
+        /// most common first
+        nicknames: Vec<String>,
+        /// Home address
+        address: Address,
+        #[serde(skip)]
+        internal_id: u64,
+    }
+
+    #[test]
+    fn test_derive_json_schema_matches_hand_built_schema() {
+        let derived = serde_json::to_value(Schema::chat_json_schema_for::<PersonInfo>("person_info")).unwrap();
+        let expected = serde_json::json!({
+            "name": "person_info",
+            "schema": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string", "description": "Person's full name" },
+                    "age": { "type": "integer", "description": "Person's age" },
+                    "job": { "type": ["string", "null"] },
+                    "nicknames": { "type": "array", "items": { "type": "string" }, "description": "Known nicknames, most common first" },
+                    "address": {
+                        "type": "object",
+                        "description": "Home address",
+                        "properties": {
+                            "street": { "type": "string", "description": "Street and number" },
+                            "city": { "type": "string", "description": "City name" },
+                        },
+                        "required": ["street", "city"],
+                        "additionalProperties": false,
+                    },
+                },
+                "required": ["name", "age", "job", "nicknames", "address"],
+                "additionalProperties": false,
+            },
+        });
+        assert_eq!(derived, expected);
+
+        let mut hand_built = Schema::responses_json_schema("address");
+        hand_built.add_property("street", "string", "Street and number");
+        hand_built.add_property("city", "string", "City name");
+        assert_eq!(serde_json::to_value(Schema::responses_json_schema_for::<Address>("address")).unwrap(), serde_json::to_value(hand_built).unwrap());
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    struct Contact {
+        /// Postal address, if known
+        address: Option<Address>,
+        /// Phone numbers, if any
+        phones: Option<Vec<String>>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct Order {
+        order_id: String,
+        total_price_usd: f64,
+        #[serde(rename = "items")]
+        line_items: Vec<String>,
+    }
+
+    #[test]
+    fn test_derive_json_schema_honors_rename_all() {
+        let schema = Order::json_schema();
+        assert_eq!(schema["required"], serde_json::json!(["orderId", "totalPriceUsd", "items"]));
+
+        let order: Order = parse_structured_output(r#"{"orderId": "o-1", "totalPriceUsd": 9.5, "items": ["book"]}"#).unwrap();
+        assert_eq!(order.total_price_usd, 9.5);
+    }
+
+    #[test]
+    fn test_optional_fields_are_nullable() {
+        assert_eq!(<Option<Option<u32>>>::json_schema(), serde_json::json!({ "type": ["integer", "null"] }));
+
+        let schema = Schema::responses_json_schema_for::<Contact>("contact");
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(value["schema"]["properties"]["address"]["type"], serde_json::json!(["object", "null"]));
+        assert_eq!(value["schema"]["properties"]["address"]["required"], serde_json::json!(["street", "city"]));
+        assert_eq!(value["schema"]["properties"]["phones"]["type"], serde_json::json!(["array", "null"]));
+        assert_eq!(value["schema"]["required"], serde_json::json!(["address", "phones"]));
+
+        let contact: Contact = parse_structured_output(r#"{"address": null, "phones": ["555-0100"]}"#).unwrap();
+        assert!(contact.address.is_none());
+    }
+
+    #[test]
+    fn test_strict_flag_serialization() {
+        let mut schema = Schema::chat_json_schema("answer");
+        schema.add_property("answer", "string", "The answer");
+        let value = serde_json::to_value(&schema).unwrap();
+        assert!(value.get("strict").is_none());
+        assert!(!schema.is_strict());
+
+        schema.strict(true);
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(value["strict"], true);
+        assert!(schema.validate().is_ok());
+
+        let mut schema = Schema::responses_json_schema_for::<Address>("address");
+        schema.strict(false);
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(value["type"], "json_schema");
+        assert_eq!(value["strict"], false);
+        assert!(schema.validate().is_ok());
+    }
+
+    #[test]
+    fn test_strict_validation_rejects_optional_properties() {
+        let mut schema: Schema = serde_json::from_value(serde_json::json!({
+            "name": "person",
+            "schema": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "address": {
+                        "type": "object",
+                        "properties": { "city": { "type": "string" }, "zip": { "type": "string" } },
+                        "required": ["city"],
+                        "additionalProperties": false,
+                    },
+                },
+                "required": ["name", "address"],
+                "additionalProperties": false,
+            },
+        }))
+        .unwrap();
+        assert!(schema.validate().is_ok(), "non-strict schemas are not checked");
+
+        schema.strict(true);
+        let err = schema.validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid parameter: Strict schema 'person' is invalid: every property must be required, but object 'address' does not require zip"
+        );
+
+        let err = Schema::responses_text_schema().strict(true).validate().unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)));
+    }
+
+    #[test]
+    fn test_add_scalar_array() {
+        let mut schema = Schema::chat_json_schema("article");
+        schema.add_property("title", "string", "Article title");
+        schema.add_scalar_array("tags", "string", "Topic tags");
+        schema.add_scalar_array("scores", "number", "");
+        schema.add_array("authors", vec![("name", "string")]);
+
+        let value = serde_json::to_value(&schema).unwrap();
+        let properties = &value["schema"]["properties"];
+        assert_eq!(properties["tags"], serde_json::json!({ "type": "array", "description": "Topic tags", "items": { "type": "string" } }));
+        assert_eq!(properties["scores"], serde_json::json!({ "type": "array", "items": { "type": "number" } }));
+        assert_eq!(properties["authors"]["items"]["type"], "object");
+        assert_eq!(value["schema"]["required"], serde_json::json!(["title", "tags", "scores", "authors"]));
+        assert!(schema.strict(true).validate().is_ok());
+    }
+
+    #[test]
+    fn test_range_and_pattern_constraints() {
+        let mut schema = Schema::responses_json_schema("review");
+        schema.add_number_property_with_range("rating", "Rating from 1 to 5", Some(1.0), Some(5.0));
+        schema.add_number_property_with_range("price", "", Some(0.0), None);
+        schema.add_string_property_with_pattern("phone", "E.164 phone number", "^\\+[1-9][0-9]{7,14}$");
+
+        let properties = serde_json::to_value(&schema).unwrap()["schema"]["properties"].clone();
+        assert_eq!(
+            properties["rating"],
+            serde_json::json!({ "type": "number", "description": "Rating from 1 to 5", "minimum": 1.0, "maximum": 5.0 })
+        );
+        assert_eq!(properties["price"], serde_json::json!({ "type": "number", "minimum": 0.0 }));
+        assert_eq!(
+            properties["phone"],
+            serde_json::json!({ "type": "string", "description": "E.164 phone number", "pattern": "^\\+[1-9][0-9]{7,14}$" })
+        );
+    }
 }
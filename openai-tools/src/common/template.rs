@@ -0,0 +1,185 @@
+//! Prompt templates with `{variable}` placeholders.
+//!
+//! A [`PromptTemplate`] is rendered by substituting each `{name}` placeholder
+//! with the value of the variable `name`. Literal braces are written as `{{`
+//! and `}}`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use openai_tools::common::message::Message;
+//! use openai_tools::common::role::Role;
+//! use openai_tools::common::template::PromptTemplate;
+//!
+//! let template = PromptTemplate::new("Translate {text} into {language}. Reply as {{\"translation\": ...}}.");
+//! let vars = HashMap::from([("text", "good morning"), ("language", "French")]);
+//!
+//! assert_eq!(template.render(&vars).unwrap(), "Translate good morning into French. Reply as {\"translation\": ...}.");
+//!
+//! let message = Message::from_template(Role::User, &template, &vars).unwrap();
+//! ```
+
+use crate::common::errors::{OpenAIToolError, Result};
+use std::collections::HashMap;
+
+/// A piece of a parsed template.
+enum Segment<'a> {
+    /// Text copied as is, with escaped braces already unescaped
+    Literal(&'a str),
+    /// A `{name}` placeholder
+    Variable(&'a str),
+}
+
+/// A reusable prompt with `{variable}` placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTemplate {
+    template: String,
+}
+
+impl PromptTemplate {
+    /// Creates a template from a string with `{variable}` placeholders.
+    ///
+    /// The template is validated when it is rendered.
+    pub fn new<T: Into<String>>(template: T) -> Self {
+        Self { template: template.into() }
+    }
+
+    /// Returns the template source.
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Returns the names of the placeholders, in order of first appearance.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<&str>)` - The placeholder names
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the template is malformed
+    pub fn variables(&self) -> Result<Vec<&str>> {
+        let mut names = Vec::new();
+        for segment in self.segments()? {
+            if let Segment::Variable(name) = segment {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Substitutes every placeholder with the value of its variable.
+    ///
+    /// Variables that do not appear in the template are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The rendered prompt
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If a variable is missing or the template is malformed
+    pub fn render(&self, vars: &HashMap<&str, &str>) -> Result<String> {
+        let mut rendered = String::with_capacity(self.template.len());
+        for segment in self.segments()? {
+            match segment {
+                Segment::Literal(text) => rendered.push_str(text),
+                Segment::Variable(name) => match vars.get(name) {
+                    Some(value) => rendered.push_str(value),
+                    None => return Err(OpenAIToolError::InvalidParameter(format!("Missing template variable: {}", name))),
+                },
+            }
+        }
+        Ok(rendered)
+    }
+
+    /// Splits the template into literals and placeholders.
+    fn segments(&self) -> Result<Vec<Segment<'_>>> {
+        let template = self.template.as_str();
+        let mut segments = Vec::new();
+        let mut literal_start = 0;
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '{' | '}' if chars.peek().map(|&(_, next)| next) == Some(c) => {
+                    // Escaped brace: keep one and skip the other
+                    segments.push(Segment::Literal(&template[literal_start..=i]));
+                    chars.next();
+                    literal_start = i + 2;
+                }
+                '{' => {
+                    segments.push(Segment::Literal(&template[literal_start..i]));
+                    let end = template[i + 1..]
+                        .find(['{', '}'])
+                        .map(|offset| i + 1 + offset)
+                        .filter(|&end| template[end..].starts_with('}'))
+                        .ok_or_else(|| OpenAIToolError::InvalidParameter(format!("Unclosed '{{' at byte {} of prompt template", i)))?;
+                    let name = template[i + 1..end].trim();
+                    if name.is_empty() {
+                        return Err(OpenAIToolError::InvalidParameter(format!("Empty placeholder at byte {} of prompt template", i)));
+                    }
+                    segments.push(Segment::Variable(name));
+                    while chars.next_if(|&(j, _)| j <= end).is_some() {}
+                    literal_start = end + 1;
+                }
+                '}' => {
+                    return Err(OpenAIToolError::InvalidParameter(format!(
+                        "Unmatched '}}' at byte {} of prompt template; use '}}}}' for a literal brace",
+                        i
+                    )));
+                }
+                _ => {}
+            }
+        }
+        segments.push(Segment::Literal(&template[literal_start..]));
+        Ok(segments)
+    }
+}
+
+impl From<&str> for PromptTemplate {
+    fn from(template: &str) -> Self {
+        Self::new(template)
+    }
+}
+
+impl From<String> for PromptTemplate {
+    fn from(template: String) -> Self {
+        Self::new(template)
+    }
+}
+
+impl std::fmt::Display for PromptTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_substitutes_variables() {
+        let template = PromptTemplate::new("Hello {name}, welcome to { place }! Bye {name}.");
+        let vars = HashMap::from([("name", "Ada"), ("place", "Paris"), ("unused", "x")]);
+        assert_eq!(template.render(&vars).unwrap(), "Hello Ada, welcome to Paris! Bye Ada.");
+        assert_eq!(template.variables().unwrap(), vec!["name", "place"]);
+    }
+
+    #[test]
+    fn test_render_unescapes_literal_braces() {
+        let template = PromptTemplate::new("{{\"key\": \"{value}\"}} and {{{value}}}");
+        let vars = HashMap::from([("value", "v")]);
+        assert_eq!(template.render(&vars).unwrap(), "{\"key\": \"v\"} and {v}");
+    }
+
+    #[test]
+    fn test_render_errors() {
+        let vars = HashMap::from([("name", "Ada")]);
+        let missing = PromptTemplate::new("Hi {name}, from {sender}").render(&vars).unwrap_err();
+        assert_eq!(missing.to_string(), "Invalid parameter: Missing template variable: sender");
+
+        for malformed in ["Hi {name", "Hi name}", "Hi {}", "Hi {na{me}"] {
+            let err = PromptTemplate::new(malformed).render(&vars).unwrap_err();
+            assert!(matches!(err, OpenAIToolError::InvalidParameter(_)), "{}: {:?}", malformed, err);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Assertion helpers shared by unit tests.
+
+/// Returns the JSON paths of all `null` values in `value`.
+pub(crate) fn null_paths(value: &serde_json::Value, path: &str) -> Vec<String> {
+    match value {
+        serde_json::Value::Null => vec![path.to_string()],
+        serde_json::Value::Array(items) => items.iter().enumerate().flat_map(|(i, v)| null_paths(v, &format!("{}[{}]", path, i))).collect(),
+        serde_json::Value::Object(map) => map.iter().flat_map(|(k, v)| null_paths(v, &format!("{}.{}", path, k))).collect(),
+        _ => vec![],
+    }
+}
@@ -1,10 +1,27 @@
 use crate::common::{
+    errors::{OpenAIToolError, Result},
     function::Function,
     parameters::{Name, ParameterProperty, Parameters},
+    structured_output::{check_strict_object, StrictObject, StrictSchemaNode, StrictWording},
 };
 use serde::{Deserialize, Serialize};
 
+/// Maximum length of a tool name accepted by the API.
+pub const MAX_TOOL_NAME_LENGTH: usize = 64;
+
+/// Maximum length of a tool description accepted by the API.
+pub const MAX_TOOL_DESCRIPTION_LENGTH: usize = 1024;
+
+/// A tool the model may call.
+///
+/// Function tools are stored in the Chat Completions shape,
+/// `{"type": "function", "function": {...}}`. The Responses API expects the
+/// function fields at the top level instead; [`Responses`](crate::responses::request::Responses)
+/// flattens them when serializing, so the same `Tool` can be passed to
+/// `ChatCompletion::tools`, `Responses::tools`, and (via `RealtimeTool::from`)
+/// the Realtime API. Both shapes are accepted when deserializing.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(from = "ToolRepr")]
 pub struct Tool {
     #[serde(rename = "type")]
     pub type_name: String,
@@ -22,6 +39,9 @@ pub struct Tool {
     pub function: Option<Function>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Parameters>,
+    /// Container the code interpreter runs in: a container ID or `{"type": "auto"}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<serde_json::Value>,
 }
 
 impl Tool {
@@ -57,4 +77,378 @@ impl Tool {
             ..Default::default()
         }
     }
+
+    /// Creates the built-in web search tool of the Responses API.
+    ///
+    /// Serializes as `{"type": "web_search_preview"}`.
+    pub fn web_search_preview() -> Self {
+        Self { type_name: "web_search_preview".into(), ..Default::default() }
+    }
+
+    /// Creates the built-in code interpreter tool of the Responses API.
+    ///
+    /// # Arguments
+    ///
+    /// * `container` - ID of an existing container to run code in, or `None`
+    ///   to let the API create one (`{"type": "auto"}`)
+    pub fn code_interpreter(container: Option<String>) -> Self {
+        let container = match container {
+            Some(id) => serde_json::Value::String(id),
+            None => serde_json::json!({"type": "auto"}),
+        };
+        Self { type_name: "code_interpreter".into(), container: Some(container), ..Default::default() }
+    }
+
+    /// Creates a function tool in which only the parameters named in `required` are required.
+    ///
+    /// [`Tool::function`] marks every parameter as required, which forces the model
+    /// to invent values for arguments that are truly optional.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The function name
+    /// * `description` - What the function does
+    /// * `parameters` - The function parameters
+    /// * `required` - Names of the parameters the model must always provide
+    /// * `strict` - Whether to enable strict schema adherence
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::parameters::ParameterProperty;
+    /// use openai_tools::common::tool::Tool;
+    ///
+    /// let tool = Tool::function_with_required(
+    ///     "search_docs",
+    ///     "Search the docs",
+    ///     vec![("query", ParameterProperty::from_string("Search query")), ("limit", ParameterProperty::from_integer("Maximum results"))],
+    ///     vec!["query"],
+    ///     false,
+    /// );
+    /// ```
+    pub fn function_with_required<T: AsRef<str>, U: AsRef<str>, V: AsRef<str>, W: AsRef<str>>(
+        name: T,
+        description: U,
+        parameters: Vec<(V, ParameterProperty)>,
+        required: Vec<W>,
+        strict: bool,
+    ) -> Self {
+        let parameters = Parameters::new(parameters, None).with_required(required);
+        Self {
+            type_name: "function".into(),
+            name: Some(name.as_ref().to_string()),
+            function: Some(Function::new(name, description, parameters, strict)),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a function tool after checking its name and description against the API's limits.
+    ///
+    /// Unlike [`Tool::function`], invalid input is reported here with a descriptive
+    /// error instead of a `400 Bad Request` from the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The function name; must match `^[a-zA-Z0-9_-]{1,64}$`
+    /// * `description` - What the function does; at most 1024 characters
+    /// * `parameters` - The function parameters
+    /// * `strict` - Whether to enable strict schema adherence
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Tool)` - The function tool
+    /// * `Err(OpenAIToolError)` - If the name or description is invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::parameters::ParameterProperty;
+    /// use openai_tools::common::tool::Tool;
+    ///
+    /// let params = vec![("query", ParameterProperty::from_string("Search query"))];
+    /// assert!(Tool::try_function("search_docs", "Search the docs", params.clone(), false).is_ok());
+    /// assert!(Tool::try_function("docs.search", "Search the docs", params, false).is_err());
+    /// ```
+    pub fn try_function<T: AsRef<str>, U: AsRef<str>, V: AsRef<str>>(
+        name: T,
+        description: U,
+        parameters: Vec<(V, ParameterProperty)>,
+        strict: bool,
+    ) -> Result<Self> {
+        validate_tool_name(name.as_ref())?;
+        validate_tool_description(description.as_ref())?;
+        Ok(Self::function(name, description, parameters, strict))
+    }
+
+    /// Checks that a strict function tool meets the requirements of strict mode.
+    ///
+    /// With `strict: true`, the API requires every object in the parameters to
+    /// list all of its properties as required and to set `additionalProperties`
+    /// to `false`. Mark optional parameters with a `null` type instead of
+    /// leaving them out of `required`. Non-strict and non-function tools are
+    /// always valid.
+    ///
+    /// This is called before sending chat and Responses requests.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the tool is valid
+    /// * `Err(OpenAIToolError::InvalidParameter)` - Describing the first violation found
+    pub fn validate(&self) -> Result<()> {
+        let function = match &self.function {
+            Some(function) if function.strict => function,
+            _ => return Ok(()),
+        };
+        let result = match &function.parameters {
+            Some(parameters) => check_strict_object(
+                &PARAMETER_WORDING,
+                "",
+                StrictObject::new(&parameters.properties, parameters.required.as_deref(), parameters.additional_properties),
+            ),
+            None => Ok(()),
+        };
+        result.map_err(|reason| OpenAIToolError::InvalidParameter(format!("Strict tool '{}' is invalid: {}", function.name, reason)))
+    }
+}
+
+/// How strict tool violations name the parameters object, nested objects and their members.
+const PARAMETER_WORDING: StrictWording = StrictWording { root: "the parameters object", object: "parameter", member: "parameter" };
+
+impl StrictSchemaNode for ParameterProperty {
+    fn object(&self) -> Option<StrictObject<'_, Self>> {
+        let properties = self.properties.as_ref()?;
+        Some(StrictObject::new(properties, self.required.as_deref(), self.additional_properties))
+    }
+
+    fn items(&self) -> Option<&Self> {
+        self.items.as_deref()
+    }
+}
+
+impl From<Function> for Tool {
+    /// Wraps a function definition in a function tool.
+    fn from(function: Function) -> Self {
+        Self { type_name: "function".into(), name: Some(function.name.clone()), function: Some(function), ..Default::default() }
+    }
+}
+
+/// Deserialization form of [`Tool`], accepting both the nested Chat Completions
+/// and the flattened Responses API shape of function tools.
+#[derive(Deserialize)]
+struct ToolRepr {
+    #[serde(rename = "type")]
+    type_name: String,
+    name: Option<String>,
+    description: Option<String>,
+    strict: Option<bool>,
+    server_label: Option<String>,
+    server_url: Option<String>,
+    require_approval: Option<String>,
+    allowed_tools: Option<Vec<String>>,
+    function: Option<Function>,
+    parameters: Option<Parameters>,
+    container: Option<serde_json::Value>,
+}
+
+impl From<ToolRepr> for Tool {
+    fn from(repr: ToolRepr) -> Self {
+        let flattened_function = repr.type_name == "function" && repr.function.is_none();
+        let (function, parameters) = match (flattened_function, repr.name.clone()) {
+            (true, Some(name)) => (
+                Some(Function {
+                    name,
+                    description: repr.description,
+                    parameters: repr.parameters,
+                    strict: repr.strict.unwrap_or(false),
+                    ..Default::default()
+                }),
+                None,
+            ),
+            _ => (repr.function, repr.parameters),
+        };
+        Self {
+            type_name: repr.type_name,
+            name: repr.name,
+            server_label: repr.server_label,
+            server_url: repr.server_url,
+            require_approval: repr.require_approval,
+            allowed_tools: repr.allowed_tools,
+            function,
+            parameters,
+            container: repr.container,
+        }
+    }
+}
+
+/// Checks that `name` is a valid tool name, i.e. matches `^[a-zA-Z0-9_-]{1,64}$`.
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::common::tool::validate_tool_name;
+///
+/// assert!(validate_tool_name("get_weather").is_ok());
+/// assert!(validate_tool_name("weather.get").is_err());
+/// ```
+pub fn validate_tool_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(OpenAIToolError::Error("Tool name must not be empty".into()));
+    }
+    if name.len() > MAX_TOOL_NAME_LENGTH {
+        return Err(OpenAIToolError::Error(format!(
+            "Tool name '{}' is {} characters long; the maximum is {}",
+            name,
+            name.len(),
+            MAX_TOOL_NAME_LENGTH
+        )));
+    }
+    if let Some(invalid) = name.chars().find(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == '-')) {
+        return Err(OpenAIToolError::Error(format!(
+            "Tool name '{}' contains invalid character '{}'; only letters, digits, '_' and '-' are allowed",
+            name, invalid
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `description` does not exceed [`MAX_TOOL_DESCRIPTION_LENGTH`] characters.
+pub fn validate_tool_description(description: &str) -> Result<()> {
+    let length = description.chars().count();
+    if length > MAX_TOOL_DESCRIPTION_LENGTH {
+        return Err(OpenAIToolError::Error(format!(
+            "Tool description is {} characters long; the maximum is {}",
+            length, MAX_TOOL_DESCRIPTION_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_tool_name_accepts_valid_names() {
+        for name in ["get_weather", "search-docs", "Tool42", "a", &"x".repeat(MAX_TOOL_NAME_LENGTH)] {
+            assert!(validate_tool_name(name).is_ok(), "{} should be valid", name);
+        }
+    }
+
+    #[test]
+    fn test_validate_tool_name_rejects_invalid_names() {
+        for name in ["", "weather.get", "get weather", "café", &"x".repeat(MAX_TOOL_NAME_LENGTH + 1)] {
+            assert!(validate_tool_name(name).is_err(), "{} should be invalid", name);
+        }
+
+        let err = validate_tool_name("Client.search").unwrap_err();
+        assert!(err.to_string().contains("invalid character '.'"));
+    }
+
+    #[test]
+    fn test_try_function() {
+        let params = vec![("location", ParameterProperty::from_string("City name"))];
+
+        let tool = Tool::try_function("get_weather", "Get the weather", params.clone(), false).unwrap();
+        assert_eq!(tool.name.as_deref(), Some("get_weather"));
+
+        assert!(Tool::try_function("weather.get", "Get the weather", params.clone(), false).is_err());
+        assert!(Tool::try_function("get_weather", "x".repeat(MAX_TOOL_DESCRIPTION_LENGTH + 1), params, false).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_accepts_nested_and_flattened_function_tools() {
+        let nested: Tool = serde_json::from_value(serde_json::json!({
+            "type": "function",
+            "function": {"name": "get_weather", "description": "Get the weather", "parameters": {"type": "object", "properties": {}}, "strict": true}
+        }))
+        .unwrap();
+        let flattened: Tool = serde_json::from_value(serde_json::json!({
+            "type": "function", "name": "get_weather", "description": "Get the weather", "parameters": {"type": "object", "properties": {}}, "strict": true
+        }))
+        .unwrap();
+
+        for tool in [&nested, &flattened] {
+            let function = tool.function.as_ref().unwrap();
+            assert_eq!(function.name, "get_weather");
+            assert_eq!(function.description.as_deref(), Some("Get the weather"));
+            assert!(function.strict);
+            assert!(tool.parameters.is_none());
+        }
+        assert_eq!(serde_json::to_value(&flattened).unwrap()["function"]["name"], "get_weather");
+
+        let mcp: Tool =
+            serde_json::from_value(serde_json::json!({"type": "mcp", "server_label": "docs", "server_url": "https://example.com/mcp"})).unwrap();
+        assert!(mcp.function.is_none());
+        assert_eq!(mcp.server_label.as_deref(), Some("docs"));
+    }
+
+    #[test]
+    fn test_from_function() {
+        let function = Function::new("get_weather", "Get the weather", Parameters::new(Vec::<(String, ParameterProperty)>::new(), None), false);
+        let tool = Tool::from(function);
+        assert_eq!(tool.type_name, "function");
+        assert_eq!(tool.name.as_deref(), Some("get_weather"));
+    }
+
+    #[test]
+    fn test_strict_function_serializes_strict_and_closed_objects() {
+        let address =
+            Parameters::new(vec![("city", ParameterProperty::from_string("City")), ("zip", ParameterProperty::from_string("ZIP code"))], None);
+        let tool = Tool::function(
+            "ship_order",
+            "Ship an order",
+            vec![("order_id", ParameterProperty::from_string("Order ID")), ("address", ParameterProperty::from_object(address, "Shipping address"))],
+            true,
+        );
+
+        let value = serde_json::to_value(&tool).unwrap();
+        let function = &value["function"];
+        assert_eq!(function["strict"], true);
+        assert_eq!(function["parameters"]["additionalProperties"], false);
+        assert_eq!(function["parameters"]["properties"]["address"]["additionalProperties"], false);
+        let mut required: Vec<&str> = function["parameters"]["required"].as_array().unwrap().iter().map(|v| v.as_str().unwrap()).collect();
+        required.sort_unstable();
+        assert_eq!(required, vec!["address", "order_id"]);
+        assert!(tool.validate().is_ok());
+
+        let lenient = Tool::function("ship_order", "Ship an order", vec![("order_id", ParameterProperty::from_string("Order ID"))], false);
+        let value = serde_json::to_value(&lenient).unwrap();
+        assert_eq!(value["function"]["strict"], false);
+        assert!(value["function"]["parameters"].get("additionalProperties").is_none());
+    }
+
+    #[test]
+    fn test_validate_rejects_strict_tool_with_optional_parameters() {
+        let params = vec![("query", ParameterProperty::from_string("Search query")), ("limit", ParameterProperty::from_integer("Maximum results"))];
+        let tool = Tool::function_with_required("search_docs", "Search the docs", params.clone(), vec!["query"], true);
+
+        let err = tool.validate().unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)));
+        assert_eq!(
+            err.to_string(),
+            "Invalid parameter: Strict tool 'search_docs' is invalid: every parameter must be required, but the parameters object does not require limit"
+        );
+
+        let mut open = Tool::function("search_docs", "Search the docs", params, true);
+        open.function.as_mut().unwrap().parameters.as_mut().unwrap().additional_properties = Some(true);
+        assert!(open.validate().unwrap_err().to_string().contains("the parameters object must set additionalProperties to false"));
+
+        assert!(Tool::function_with_required("search_docs", "Search the docs", Vec::<(&str, _)>::new(), Vec::<&str>::new(), false)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_function_with_required() {
+        let params = vec![
+            ("query", ParameterProperty::from_string("Search query")),
+            ("limit", ParameterProperty::from_integer("Maximum results")),
+            ("language", ParameterProperty::from_string("Result language")),
+        ];
+
+        let tool = Tool::function_with_required("search_docs", "Search the docs", params, vec!["query"], false);
+        let value = serde_json::to_value(&tool).unwrap();
+        assert_eq!(value["function"]["parameters"]["required"], serde_json::json!(["query"]));
+        assert_eq!(value["function"]["parameters"]["properties"].as_object().unwrap().len(), 3);
+    }
 }
@@ -0,0 +1,234 @@
+//! Pluggable HTTP transport.
+//!
+//! API clients send their (non-streaming) requests through a [`Transport`].
+//! By default this is the `reqwest` HTTP client; [`MockTransport`] returns
+//! canned responses instead and records every request, so code built on the
+//! clients can be tested offline. A transport is attached with
+//! [`ChatCompletion::with_transport`](crate::chat::request::ChatCompletion::with_transport)
+//! or [`Responses::with_transport`](crate::responses::request::Responses::with_transport).
+//!
+//! To replay real API responses recorded to a file, see
+//! [`CassetteTransport`](crate::common::cassette::CassetteTransport).
+//!
+//! Streaming requests always use `reqwest`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use openai_tools::chat::request::ChatCompletion;
+//! use openai_tools::common::message::Message;
+//! use openai_tools::common::role::Role;
+//! use openai_tools::common::transport::MockTransport;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let transport = MockTransport::new();
+//! transport.push_response(
+//!     200,
+//!     r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 0, "model": "gpt-4o-mini",
+//!         "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hi!"}, "finish_reason": "stop"}],
+//!         "usage": {"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7}}"#,
+//! );
+//!
+//! let mut chat = ChatCompletion::with_url("https://api.openai.com/v1", "sk-test");
+//! chat.with_transport(transport.clone()).messages(vec![Message::from_string(Role::User, "Hello")]);
+//!
+//! let response = chat.chat().await?;
+//! assert_eq!(response.texts(), vec!["Hi!"]);
+//!
+//! let requests = transport.requests();
+//! assert_eq!(requests[0].url, "https://api.openai.com/v1/chat/completions");
+//! assert_eq!(requests[0].body_json().unwrap()["messages"][0]["content"], "Hello");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::common::client::HttpClient;
+use crate::common::errors::{OpenAIToolError, Result};
+use futures_util::future::BoxFuture;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// An HTTP request sent by an API client.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// The HTTP method
+    pub method: request::Method,
+    /// The full request URL, including the query string
+    pub url: String,
+    /// The request headers, including authentication
+    pub headers: request::header::HeaderMap,
+    /// The request body, if any
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Parses the request body as JSON.
+    ///
+    /// Returns `None` if there is no body or it is not valid JSON.
+    pub fn body_json(&self) -> Option<serde_json::Value> {
+        self.body.as_deref().and_then(|body| serde_json::from_str(body).ok())
+    }
+}
+
+/// An HTTP response returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// The HTTP status code
+    pub status: request::StatusCode,
+    /// The response headers
+    pub headers: request::header::HeaderMap,
+    /// The response body
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Creates a response with the given status and body and no headers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HttpResponse)` - The response
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If `status` is not a valid HTTP status code
+    pub fn new<S: Into<String>>(status: u16, body: S) -> Result<Self> {
+        let status = request::StatusCode::from_u16(status)
+            .map_err(|_| OpenAIToolError::InvalidParameter(format!("{} is not a valid HTTP status code", status)))?;
+        Ok(Self { status, headers: request::header::HeaderMap::new(), body: body.into() })
+    }
+}
+
+/// Sends HTTP requests on behalf of the API clients.
+///
+/// Implementations must be thread-safe, as clients may be shared across tasks.
+pub trait Transport: Send + Sync + std::fmt::Debug {
+    /// Sends `request` and returns the response, whatever its status.
+    ///
+    /// Errors are only for failures to get a response at all, such as
+    /// connection errors and timeouts.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// The default transport: sends requests with `reqwest`.
+impl Transport for request::Client {
+    fn send(&self, http_request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(execute(self.request(http_request.method.clone(), &http_request.url), http_request))
+    }
+}
+
+impl Transport for HttpClient {
+    fn send(&self, http_request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(execute(self.request(http_request.method.clone(), &http_request.url), http_request))
+    }
+}
+
+/// Adds the headers and body of `http_request` to `builder` and sends it.
+async fn execute(builder: request::RequestBuilder, http_request: HttpRequest) -> Result<HttpResponse> {
+    let mut builder = builder.headers(http_request.headers);
+    if let Some(body) = http_request.body {
+        builder = builder.body(body);
+    }
+    let response = builder.send().await.map_err(OpenAIToolError::from)?;
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.text().await.map_err(OpenAIToolError::from)?;
+    Ok(HttpResponse { status, headers, body })
+}
+
+/// Sends `http_request` through `transport` if one is set, otherwise through `client`.
+pub(crate) async fn send_with(transport: Option<&Arc<dyn Transport>>, client: &HttpClient, http_request: HttpRequest) -> Result<HttpResponse> {
+    match transport {
+        Some(transport) => transport.send(http_request).await,
+        None => Transport::send(client, http_request).await,
+    }
+}
+
+/// A [`Transport`] that returns queued responses and records the requests.
+///
+/// Responses are returned in the order they were pushed; a request made when
+/// none are left fails with an error. Clones share the same queue and
+/// recorded requests, so keep a clone to inspect the requests after handing
+/// the transport to a client.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: VecDeque<HttpResponse>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+    /// Creates a transport with no queued responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response with the given status and body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `status` is not a valid HTTP status code.
+    pub fn push_response<S: Into<String>>(&self, status: u16, body: S) -> &Self {
+        let response = HttpResponse::new(status, body).expect("invalid HTTP status code");
+        self.push(response)
+    }
+
+    /// Queues a fully specified response, e.g. one with headers.
+    pub fn push(&self, response: HttpResponse) -> &Self {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).responses.push_back(response);
+        self
+    }
+
+    /// Returns the requests sent so far, oldest first.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).requests.clone()
+    }
+
+    /// Returns the number of queued responses not yet returned.
+    pub fn remaining(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).responses.len()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let line = format!("{} {}", request.method, request.url);
+        state.requests.push(request);
+        let response =
+            state.responses.pop_front().ok_or_else(|| OpenAIToolError::Error(format!("MockTransport has no response queued for {}", line)));
+        Box::pin(async move { response })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_transport_returns_queued_responses_in_order() {
+        let transport = MockTransport::new();
+        transport.push_response(200, "first").push_response(404, "second");
+
+        let request =
+            |url: &str| HttpRequest { method: request::Method::GET, url: url.to_string(), headers: request::header::HeaderMap::new(), body: None };
+        let first = transport.send(request("https://example.com/a")).await.unwrap();
+        let second = transport.send(request("https://example.com/b")).await.unwrap();
+        assert_eq!((first.status.as_u16(), first.body.as_str()), (200, "first"));
+        assert_eq!((second.status.as_u16(), second.body.as_str()), (404, "second"));
+
+        let err = transport.send(request("https://example.com/c")).await.unwrap_err();
+        assert_eq!(err.to_string(), "Error: MockTransport has no response queued for GET https://example.com/c");
+        assert_eq!(
+            transport.requests().iter().map(|r| r.url.as_str()).collect::<Vec<_>>(),
+            ["https://example.com/a", "https://example.com/b", "https://example.com/c"]
+        );
+        assert_eq!(transport.remaining(), 0);
+    }
+
+    #[test]
+    fn test_http_response_rejects_invalid_status() {
+        assert!(HttpResponse::new(42, "").is_err());
+    }
+}
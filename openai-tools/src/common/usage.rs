@@ -1,7 +1,9 @@
+use crate::common::pricing::PricingTable;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::{Add, AddAssign};
 
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct CompletionTokenDetails {
     pub reasoning_tokens: Option<usize>,
     pub audio_tokens: Option<usize>,
@@ -9,7 +11,7 @@ pub struct CompletionTokenDetails {
     pub rejected_prediction_tokens: Option<usize>,
 }
 
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct PromptTokenDetails {
     pub cached_tokens: Option<usize>,
     pub audio_tokens: Option<usize>,
@@ -22,7 +24,7 @@ pub struct PromptTokenDetails {
 /// Different fields may be populated depending on the specific API endpoint
 /// and model used.
 ///
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct Usage {
     pub input_tokens: Option<usize>,
     pub input_tokens_details: Option<HashMap<String, usize>>,
@@ -34,3 +36,405 @@ pub struct Usage {
     pub total_tokens: Option<usize>,
     pub completion_tokens_details: Option<CompletionTokenDetails>,
 }
+
+/// Sums two optional counts, staying `None` only if both are `None`.
+fn add_counts(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or_default() + b.unwrap_or_default()),
+    }
+}
+
+/// Sums two optional values, staying `None` only if both are `None`.
+fn add_options<T: AddAssign>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            a += b;
+            Some(a)
+        }
+        (a, b) => a.or(b),
+    }
+}
+
+/// Merges token detail maps, summing the counts of keys present in both.
+fn add_detail_maps(a: Option<HashMap<String, usize>>, b: Option<HashMap<String, usize>>) -> Option<HashMap<String, usize>> {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            for (key, count) in b {
+                *a.entry(key).or_default() += count;
+            }
+            Some(a)
+        }
+        (a, b) => a.or(b),
+    }
+}
+
+impl AddAssign for CompletionTokenDetails {
+    fn add_assign(&mut self, other: Self) {
+        self.reasoning_tokens = add_counts(self.reasoning_tokens, other.reasoning_tokens);
+        self.audio_tokens = add_counts(self.audio_tokens, other.audio_tokens);
+        self.accepted_prediction_tokens = add_counts(self.accepted_prediction_tokens, other.accepted_prediction_tokens);
+        self.rejected_prediction_tokens = add_counts(self.rejected_prediction_tokens, other.rejected_prediction_tokens);
+    }
+}
+
+impl AddAssign for PromptTokenDetails {
+    fn add_assign(&mut self, other: Self) {
+        self.cached_tokens = add_counts(self.cached_tokens, other.cached_tokens);
+        self.audio_tokens = add_counts(self.audio_tokens, other.audio_tokens);
+    }
+}
+
+impl Usage {
+    /// Returns the number of prompt (input) tokens.
+    ///
+    /// Reads `prompt_tokens` (Chat Completions) or `input_tokens` (Responses API).
+    pub fn prompt_token_count(&self) -> usize {
+        self.prompt_tokens.or(self.input_tokens).unwrap_or_default()
+    }
+
+    /// Returns the number of completion (output) tokens, including reasoning tokens.
+    ///
+    /// Reads `completion_tokens` (Chat Completions) or `output_tokens` (Responses API).
+    pub fn completion_token_count(&self) -> usize {
+        self.completion_tokens.or(self.output_tokens).unwrap_or_default()
+    }
+
+    /// Returns the total number of tokens.
+    pub fn total_token_count(&self) -> usize {
+        self.total_tokens.unwrap_or_else(|| self.prompt_token_count() + self.completion_token_count())
+    }
+
+    /// Returns the number of prompt tokens served from the prompt cache, if reported.
+    ///
+    /// Reads `prompt_tokens_details.cached_tokens` (Chat Completions) or
+    /// `input_tokens_details.cached_tokens` (Responses API). Unlike
+    /// [`cached_token_count`](Self::cached_token_count), this tells a cache miss
+    /// (`Some(0)`) apart from a response without cache information (`None`).
+    pub fn cached_tokens(&self) -> Option<usize> {
+        let from_details = self.prompt_tokens_details.as_ref().and_then(|details| details.cached_tokens);
+        from_details.or_else(|| self.input_tokens_details.as_ref().and_then(|details| details.get("cached_tokens").copied()))
+    }
+
+    /// Returns the number of prompt tokens served from the prompt cache.
+    pub fn cached_token_count(&self) -> usize {
+        self.cached_tokens().unwrap_or_default()
+    }
+
+    /// Returns the number of reasoning tokens generated by reasoning models.
+    pub fn reasoning_token_count(&self) -> usize {
+        let from_details = self.completion_tokens_details.as_ref().and_then(|details| details.reasoning_tokens);
+        let from_map = self.output_tokens_details.as_ref().and_then(|details| details.get("reasoning_tokens").copied());
+        from_details.or(from_map).unwrap_or_default()
+    }
+
+    /// Returns the number of predicted output tokens that appeared in the completion.
+    ///
+    /// Only reported for requests with a predicted output.
+    pub fn accepted_prediction_token_count(&self) -> usize {
+        self.completion_tokens_details.as_ref().and_then(|details| details.accepted_prediction_tokens).unwrap_or_default()
+    }
+
+    /// Returns the number of predicted output tokens that did not appear in the completion.
+    ///
+    /// Rejected tokens are still billed as completion tokens.
+    pub fn rejected_prediction_token_count(&self) -> usize {
+        self.completion_tokens_details.as_ref().and_then(|details| details.rejected_prediction_tokens).unwrap_or_default()
+    }
+
+    /// Estimates the cost in USD of this usage for `model`.
+    ///
+    /// Cached prompt tokens are billed at the model's cached input price.
+    /// Returns `0.0` with a warning if `pricing` has no price for the model.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::pricing::PricingTable;
+    /// use openai_tools::common::usage::Usage;
+    ///
+    /// let usage = Usage { prompt_tokens: Some(2_000), completion_tokens: Some(500), ..Default::default() };
+    /// // 2,000 * $0.15/M + 500 * $0.60/M
+    /// assert!((usage.estimate_cost("gpt-4o-mini", &PricingTable::new()) - 0.0006).abs() < 1e-12);
+    /// ```
+    pub fn estimate_cost(&self, model: &str, pricing: &PricingTable) -> f64 {
+        match pricing.get(model) {
+            Some(price) => price.usage_cost(self),
+            None => {
+                tracing::warn!("No price known for model '{}'; estimating its cost as zero.", model);
+                0.0
+            }
+        }
+    }
+}
+
+impl AddAssign for Usage {
+    /// Adds the token counts of `other`; counts missing from both sides stay `None`.
+    fn add_assign(&mut self, other: Self) {
+        self.input_tokens = add_counts(self.input_tokens, other.input_tokens);
+        self.input_tokens_details = add_detail_maps(self.input_tokens_details.take(), other.input_tokens_details);
+        self.output_tokens = add_counts(self.output_tokens, other.output_tokens);
+        self.output_tokens_details = add_detail_maps(self.output_tokens_details.take(), other.output_tokens_details);
+        self.prompt_tokens = add_counts(self.prompt_tokens, other.prompt_tokens);
+        self.prompt_tokens_details = add_options(self.prompt_tokens_details.take(), other.prompt_tokens_details);
+        self.completion_tokens = add_counts(self.completion_tokens, other.completion_tokens);
+        self.total_tokens = add_counts(self.total_tokens, other.total_tokens);
+        self.completion_tokens_details = add_options(self.completion_tokens_details.take(), other.completion_tokens_details);
+    }
+}
+
+impl Add for Usage {
+    type Output = Usage;
+
+    fn add(mut self, other: Self) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+/// Running token totals across many requests.
+///
+/// Usage is recorded per model so that costs can be estimated with each
+/// model's own prices.
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::common::pricing::PricingTable;
+/// use openai_tools::common::usage::{Usage, UsageTracker};
+///
+/// let mut tracker = UsageTracker::new();
+/// tracker.record("gpt-4o-mini", &Usage { prompt_tokens: Some(1_000), completion_tokens: Some(200), ..Default::default() });
+/// tracker.record("gpt-4o-mini", &Usage { prompt_tokens: Some(500), completion_tokens: Some(100), ..Default::default() });
+///
+/// assert_eq!(tracker.request_count(), 2);
+/// assert_eq!(tracker.prompt_tokens(), 1_500);
+/// println!("${:.6}", tracker.cost_estimate(&PricingTable::new()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UsageTracker {
+    /// Usage summed over all requests
+    total: Usage,
+    /// Usage summed per model ID
+    by_model: HashMap<String, Usage>,
+    /// Number of recorded requests
+    requests: usize,
+}
+
+impl UsageTracker {
+    /// Creates an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the usage of one request made with `model`.
+    pub fn record(&mut self, model: &str, usage: &Usage) -> &mut Self {
+        self.total += usage.clone();
+        *self.by_model.entry(model.to_string()).or_default() += usage.clone();
+        self.requests += 1;
+        self
+    }
+
+    /// Returns the usage summed over all requests.
+    pub fn total(&self) -> &Usage {
+        &self.total
+    }
+
+    /// Returns the usage summed per model ID.
+    pub fn by_model(&self) -> &HashMap<String, Usage> {
+        &self.by_model
+    }
+
+    /// Returns the number of recorded requests.
+    pub fn request_count(&self) -> usize {
+        self.requests
+    }
+
+    /// Returns the total number of prompt tokens.
+    pub fn prompt_tokens(&self) -> usize {
+        self.by_model.values().map(Usage::prompt_token_count).sum()
+    }
+
+    /// Returns the total number of completion tokens.
+    pub fn completion_tokens(&self) -> usize {
+        self.by_model.values().map(Usage::completion_token_count).sum()
+    }
+
+    /// Returns the total number of tokens.
+    pub fn total_tokens(&self) -> usize {
+        self.by_model.values().map(Usage::total_token_count).sum()
+    }
+
+    /// Returns the total number of cached prompt tokens.
+    pub fn cached_tokens(&self) -> usize {
+        self.by_model.values().map(Usage::cached_token_count).sum()
+    }
+
+    /// Returns the total number of reasoning tokens.
+    pub fn reasoning_tokens(&self) -> usize {
+        self.by_model.values().map(Usage::reasoning_token_count).sum()
+    }
+
+    /// Estimates the total cost in USD of the recorded usage.
+    ///
+    /// Models without a price in `pricing` are counted as free, with a warning.
+    pub fn cost_estimate(&self, pricing: &PricingTable) -> f64 {
+        self.by_model.iter().map(|(model, usage)| usage.estimate_cost(model, pricing)).sum()
+    }
+
+    /// Clears all recorded usage.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_add_sums_counts_and_details() {
+        let chat = Usage {
+            prompt_tokens: Some(100),
+            prompt_tokens_details: Some(PromptTokenDetails { cached_tokens: Some(40), audio_tokens: None }),
+            completion_tokens: Some(20),
+            total_tokens: Some(120),
+            completion_tokens_details: Some(CompletionTokenDetails { reasoning_tokens: Some(5), ..Default::default() }),
+            ..Default::default()
+        };
+        let mut sum = chat.clone() + chat.clone();
+        assert_eq!(sum.prompt_tokens, Some(200));
+        assert_eq!(sum.total_tokens, Some(240));
+        assert_eq!(sum.input_tokens, None);
+        assert_eq!(sum.cached_token_count(), 80);
+        assert_eq!(sum.reasoning_token_count(), 10);
+
+        sum += Usage {
+            input_tokens: Some(10),
+            input_tokens_details: Some(HashMap::from([("cached_tokens".to_string(), 4)])),
+            output_tokens: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(sum.input_tokens, Some(10));
+        assert_eq!(sum.input_tokens_details.unwrap()["cached_tokens"], 4);
+    }
+
+    #[test]
+    fn test_usage_tracker_accumulates_across_apis_and_estimates_cost() {
+        let mut tracker = UsageTracker::new();
+        tracker.record(
+            "gpt-4o-mini",
+            &Usage {
+                prompt_tokens: Some(1_000_000),
+                prompt_tokens_details: Some(PromptTokenDetails { cached_tokens: Some(200_000), audio_tokens: None }),
+                completion_tokens: Some(100_000),
+                total_tokens: Some(1_100_000),
+                ..Default::default()
+            },
+        );
+        tracker.record(
+            "o3-mini-2025-01-31",
+            &Usage {
+                input_tokens: Some(1_000),
+                output_tokens: Some(500),
+                output_tokens_details: Some(HashMap::from([("reasoning_tokens".to_string(), 300)])),
+                total_tokens: Some(1_500),
+                ..Default::default()
+            },
+        );
+        tracker.record("my-local-model", &Usage { prompt_tokens: Some(7), completion_tokens: Some(3), ..Default::default() });
+
+        assert_eq!(tracker.request_count(), 3);
+        assert_eq!(tracker.prompt_tokens(), 1_001_007);
+        assert_eq!(tracker.completion_tokens(), 100_503);
+        assert_eq!(tracker.total_tokens(), 1_101_510);
+        assert_eq!(tracker.cached_tokens(), 200_000);
+        assert_eq!(tracker.reasoning_tokens(), 300);
+
+        // gpt-4o-mini: 0.8M * $0.15 + 0.2M * $0.075 + 0.1M * $0.6 = $0.195
+        // o3-mini: 1k * $1.1/M + 500 * $4.4/M = $0.0033; my-local-model is unpriced
+        let cost = tracker.cost_estimate(&PricingTable::new());
+        assert!((cost - (0.195 + 0.0033)).abs() < 1e-9, "cost = {}", cost);
+
+        tracker.reset();
+        assert_eq!(tracker.request_count(), 0);
+        assert_eq!(tracker.cost_estimate(&PricingTable::new()), 0.0);
+    }
+
+    #[test]
+    fn test_usage_serialization_round_trip() {
+        let usage = Usage {
+            input_tokens: Some(120),
+            input_tokens_details: Some(HashMap::from([("cached_tokens".to_string(), 64)])),
+            output_tokens: Some(40),
+            output_tokens_details: Some(HashMap::from([("reasoning_tokens".to_string(), 16)])),
+            prompt_tokens: Some(120),
+            prompt_tokens_details: Some(PromptTokenDetails { cached_tokens: Some(64), audio_tokens: None }),
+            completion_tokens: Some(40),
+            total_tokens: Some(160),
+            completion_tokens_details: Some(CompletionTokenDetails {
+                reasoning_tokens: Some(16),
+                audio_tokens: None,
+                accepted_prediction_tokens: Some(0),
+                rejected_prediction_tokens: Some(0),
+            }),
+        };
+
+        let json = serde_json::to_value(&usage).unwrap();
+        assert_eq!(json["total_tokens"], 160);
+        assert_eq!(json["prompt_tokens_details"]["cached_tokens"], 64);
+        assert_eq!(json["completion_tokens_details"]["reasoning_tokens"], 16);
+        assert_eq!(json["output_tokens_details"]["reasoning_tokens"], 16);
+
+        let restored: Usage = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, usage);
+    }
+
+    #[test]
+    fn test_estimate_cost_with_overridden_pricing() {
+        let usage = Usage {
+            input_tokens: Some(10_000),
+            input_tokens_details: Some(HashMap::from([("cached_tokens".to_string(), 4_000)])),
+            output_tokens: Some(2_000),
+            ..Default::default()
+        };
+        let mut pricing = PricingTable::from_json(r#"{"gpt-4o": {"input": 5.0, "cached_input": 2.0, "output": 20.0}}"#).unwrap();
+        pricing.set("my-model", crate::common::pricing::ModelPricing::new(1.0, None, 2.0));
+
+        // 6,000 * $5/M + 4,000 * $2/M + 2,000 * $20/M = $0.078
+        assert!((usage.estimate_cost("gpt-4o", &pricing) - 0.078).abs() < 1e-12);
+        // Built-in price of a model that is not overridden: 6,000 * $0.15/M + 4,000 * $0.075/M + 2,000 * $0.6/M = $0.0024
+        assert!((usage.estimate_cost("gpt-4o-mini", &pricing) - 0.0024).abs() < 1e-12);
+        // Without a cached price, cached tokens are billed as regular input: 10,000 * $1/M + 2,000 * $2/M = $0.014
+        assert!((usage.estimate_cost("my-model", &pricing) - 0.014).abs() < 1e-12);
+        assert_eq!(usage.estimate_cost("unknown-model", &pricing), 0.0);
+    }
+
+    #[test]
+    fn test_prediction_token_accessors() {
+        let usage: Usage = serde_json::from_str(
+            r#"{"prompt_tokens": 20, "completion_tokens": 30, "total_tokens": 50,
+                "completion_tokens_details": {"reasoning_tokens": 0, "accepted_prediction_tokens": 18, "rejected_prediction_tokens": 4}}"#,
+        )
+        .unwrap();
+        assert_eq!(usage.accepted_prediction_token_count(), 18);
+        assert_eq!(usage.rejected_prediction_token_count(), 4);
+        assert_eq!(Usage::default().accepted_prediction_token_count(), 0);
+    }
+
+    #[test]
+    fn test_cached_tokens_accessor() {
+        let chat: Usage = serde_json::from_str(
+            r#"{"prompt_tokens": 2048, "completion_tokens": 10, "total_tokens": 2058, "prompt_tokens_details": {"cached_tokens": 1920}}"#,
+        )
+        .unwrap();
+        assert_eq!(chat.cached_tokens(), Some(1920));
+
+        let responses: Usage =
+            serde_json::from_str(r#"{"input_tokens": 2048, "input_tokens_details": {"cached_tokens": 0}, "output_tokens": 10}"#).unwrap();
+        assert_eq!(responses.cached_tokens(), Some(0));
+
+        assert_eq!(Usage::default().cached_tokens(), None);
+        assert_eq!(Usage::default().cached_token_count(), 0);
+    }
+}
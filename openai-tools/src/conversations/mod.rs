@@ -108,6 +108,8 @@ pub use response::{Conversation, ConversationItem, ConversationItemListResponse,
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::errors::OpenAIToolError;
+    use crate::common::metadata::Metadata;
     use std::collections::HashMap;
 
     #[test]
@@ -211,6 +213,32 @@ mod tests {
         assert!(item.content.is_some());
     }
 
+    #[test]
+    fn test_conversation_item_text() {
+        let legacy: ConversationItem =
+            serde_json::from_str(r#"{"id": "item_abc123", "object": "conversation.item", "type": "message", "role": "user", "content": "Hello!"}"#)
+                .unwrap();
+        assert_eq!(legacy.text().as_deref(), Some("Hello!"));
+
+        let input: ConversationItem =
+            serde_json::from_str(r#"{"id": "msg_1", "type": "message", "role": "user", "content": [{"type": "input_text", "text": "Hello!"}]}"#)
+                .unwrap();
+        assert_eq!(input.text().as_deref(), Some("Hello!"));
+
+        let output: ConversationItem = serde_json::from_str(
+            r#"{"id": "msg_2", "type": "message", "role": "assistant", "content": [
+                {"type": "output_text", "text": "First", "annotations": []},
+                {"type": "input_image", "image_url": "https://example.com/cat.png"},
+                {"type": "output_text", "text": "Second", "annotations": []}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(output.text().as_deref(), Some("First\nSecond"));
+
+        let no_text: ConversationItem = serde_json::from_str(r#"{"id": "fc_1", "type": "function_call"}"#).unwrap();
+        assert_eq!(no_text.text(), None);
+    }
+
     #[test]
     fn test_conversation_item_list_response() {
         let json = r#"{
@@ -284,6 +312,59 @@ mod tests {
         assert_eq!(item.role, Some("assistant".to_string()));
     }
 
+    #[test]
+    fn test_input_item_from_message_content() {
+        use crate::common::message::{Content, Message};
+        use crate::common::role::Role;
+
+        let item = InputItem::from_message(&Message::from_string(Role::Developer, "Be concise."));
+        assert_eq!(item.role.as_deref(), Some("developer"));
+        assert_eq!(item.content, Some(serde_json::json!("Be concise.")));
+
+        let message = Message::from_message_array(
+            Role::User,
+            vec![Content::from_text("What's in this image?"), Content::from_image_url("https://example.com/cat.png")],
+        );
+        let item = InputItem::from_message(&message);
+        assert_eq!(item.item_type, "message");
+        assert_eq!(
+            item.content,
+            Some(serde_json::json!([
+                {"type": "input_text", "text": "What's in this image?"},
+                {"type": "input_image", "image_url": "https://example.com/cat.png"}
+            ]))
+        );
+
+        let message = Message::from_message_array(Role::Assistant, vec![Content::from_text("A cat.")]);
+        assert_eq!(InputItem::from_message(&message).content, Some(serde_json::json!([{"type": "output_text", "text": "A cat."}])));
+    }
+
+    #[test]
+    fn test_input_item_from_messages_maps_tool_calls() {
+        use crate::common::message::{Message, ToolCall};
+        use crate::common::role::Role;
+
+        let call = ToolCall::function("call_1", "get_weather", r#"{"city": "Tokyo"}"#).unwrap();
+        let history = vec![
+            Message::from_string(Role::System, "You are a weather bot."),
+            Message::from_string(Role::User, "Weather in Tokyo?"),
+            Message::assistant_with_tool_calls(Some("Let me check.".to_string()), vec![call]),
+            Message::from_tool_call_response("Sunny", "call_1"),
+        ];
+
+        let items = serde_json::to_value(InputItem::from_messages(&history)).unwrap();
+        assert_eq!(
+            items,
+            serde_json::json!([
+                {"type": "message", "role": "system", "content": "You are a weather bot."},
+                {"type": "message", "role": "user", "content": "Weather in Tokyo?"},
+                {"type": "message", "role": "assistant", "content": "Let me check."},
+                {"type": "function_call", "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Tokyo\"}"},
+                {"type": "function_call_output", "call_id": "call_1", "output": "Sunny"}
+            ])
+        );
+    }
+
     #[test]
     fn test_conversation_include_as_str() {
         assert_eq!(ConversationInclude::WebSearchCallSources.as_str(), "web_search_call.action.sources");
@@ -325,4 +406,73 @@ mod tests {
         assert!(conversation.metadata.is_some());
         assert!(conversation.metadata.as_ref().unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn test_list_all_items_follows_pagination() {
+        use futures_util::{pin_mut, StreamExt};
+
+        let first_page = r#"{
+            "object": "list",
+            "data": [
+                {"id": "msg_1", "type": "message", "role": "user", "content": [{"type": "input_text", "text": "Hi"}]},
+                {"id": "msg_2", "type": "message", "role": "assistant", "content": [{"type": "output_text", "text": "Hello!"}]}
+            ],
+            "first_id": "msg_1",
+            "last_id": "msg_2",
+            "has_more": true
+        }"#;
+        let second_page = r#"{
+            "object": "list",
+            "data": [
+                {"id": "msg_3", "type": "message", "role": "user", "content": [{"type": "input_text", "text": "Bye"}]}
+            ],
+            "first_id": "msg_3",
+            "last_id": "msg_3",
+            "has_more": false
+        }"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, first_page.to_string()), (200, second_page.to_string())]).await;
+
+        let conversations = Conversations::with_url(base_url, "sk-test".to_string());
+        let items = conversations.list_all_items("conv_abc123");
+        pin_mut!(items);
+        let mut ids = Vec::new();
+        while let Some(item) = items.next().await {
+            ids.push(item.unwrap().id);
+        }
+        assert_eq!(ids, vec!["msg_1", "msg_2", "msg_3"]);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /v1/conversations/conv_abc123/items HTTP/1.1");
+        assert_eq!(requests[1].request_line, "GET /v1/conversations/conv_abc123/items?after=msg_2 HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_update_sends_metadata_patch() {
+        let updated = r#"{"id": "conv_abc123", "object": "conversation", "created_at": 1741900000, "metadata": {"status": "resolved"}}"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, updated.to_string())]).await;
+
+        let conversations = Conversations::with_url(base_url, "sk-test".to_string());
+        let mut metadata = Metadata::new();
+        metadata.insert("status", "resolved").unwrap();
+        let conversation = conversations.update("conv_abc123", metadata).await.unwrap();
+        assert_eq!(conversation.metadata.unwrap().get("status"), Some(&"resolved".to_string()));
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /v1/conversations/conv_abc123 HTTP/1.1");
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(), serde_json::json!({"metadata": {"status": "resolved"}}));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_metadata_rejected_before_sending() {
+        // Nothing listens on the discard port; validation fails before any request
+        let conversations = Conversations::with_url("http://127.0.0.1:9".to_string(), "sk-test".to_string());
+
+        let too_many: HashMap<String, String> = (0..17).map(|i| (format!("key{}", i), "value".to_string())).collect();
+        let err = conversations.create(too_many, None).await.unwrap_err();
+        assert!(matches!(&err, OpenAIToolError::InvalidParameter(message) if message.contains("17 entries")), "unexpected error: {}", err);
+
+        let long_value = HashMap::from([("note".to_string(), "x".repeat(513))]);
+        let err = conversations.update("conv_abc123", long_value).await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)), "unexpected error: {}", err);
+    }
 }
@@ -39,9 +39,14 @@
 //! ```
 
 use crate::common::auth::AuthProvider;
-use crate::common::client::create_http_client;
-use crate::common::errors::{ErrorResponse, OpenAIToolError, Result};
-use crate::conversations::response::{Conversation, ConversationItemListResponse, ConversationListResponse, DeleteConversationResponse, InputItem};
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
+use crate::common::metadata;
+use crate::common::pagination::paginate;
+use crate::conversations::response::{
+    Conversation, ConversationItem, ConversationItemListResponse, ConversationListResponse, DeleteConversationResponse, InputItem,
+};
+use futures_util::stream::Stream;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -140,6 +145,8 @@ pub struct Conversations {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl Conversations {
@@ -163,36 +170,36 @@ impl Conversations {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Conversations client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Conversations client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Conversations client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Conversations client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Conversations client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -202,6 +209,12 @@ impl Conversations {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -215,8 +228,8 @@ impl Conversations {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
@@ -224,15 +237,6 @@ impl Conversations {
         Ok((client, headers))
     }
 
-    /// Handles API error responses.
-    fn handle_error(status: request::StatusCode, content: &str) -> OpenAIToolError {
-        if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(content) {
-            OpenAIToolError::Error(error_resp.error.message.unwrap_or_default())
-        } else {
-            OpenAIToolError::Error(format!("API error ({}): {}", status, content))
-        }
-    }
-
     /// Creates a new conversation.
     ///
     /// You can optionally provide metadata and initial items to include
@@ -240,12 +244,15 @@ impl Conversations {
     ///
     /// # Arguments
     ///
-    /// * `metadata` - Optional key-value pairs for storing additional information
+    /// * `metadata` - Optional key-value pairs for storing additional information: a
+    ///   [`Metadata`](crate::common::metadata::Metadata), a `HashMap`, or `None`
     /// * `items` - Optional initial items to add to the conversation (up to 20 items)
     ///
     /// # Returns
     ///
     /// * `Ok(Conversation)` - The created conversation object
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the metadata exceeds the API limits
+    ///   (see [`Metadata`](crate::common::metadata::Metadata))
     /// * `Err(OpenAIToolError)` - If the request fails
     ///
     /// # Example
@@ -270,24 +277,27 @@ impl Conversations {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn create(&self, metadata: Option<HashMap<String, String>>, items: Option<Vec<InputItem>>) -> Result<Conversation> {
+    pub async fn create(&self, metadata: impl Into<Option<HashMap<String, String>>>, items: Option<Vec<InputItem>>) -> Result<Conversation> {
+        let metadata = metadata.into();
+        metadata.as_ref().map(metadata::validate_map).transpose()?;
         let (client, headers) = self.create_client()?;
 
         let request_body = CreateConversationRequest { metadata, items };
         let body = serde_json::to_string(&request_body)?;
 
         let url = self.auth.endpoint(CONVERSATIONS_PATH);
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<Conversation>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -323,17 +333,18 @@ impl Conversations {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(CONVERSATIONS_PATH), conversation_id);
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<Conversation>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -344,11 +355,13 @@ impl Conversations {
     /// # Arguments
     ///
     /// * `conversation_id` - The ID of the conversation to update
-    /// * `metadata` - The new metadata to set
+    /// * `metadata` - The new metadata to set, as a [`Metadata`](crate::common::metadata::Metadata) or a `HashMap`
     ///
     /// # Returns
     ///
     /// * `Ok(Conversation)` - The updated conversation object
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the metadata exceeds the API limits
+    ///   (see [`Metadata`](crate::common::metadata::Metadata))
     /// * `Err(OpenAIToolError)` - If the request fails
     ///
     /// # Example
@@ -369,24 +382,27 @@ impl Conversations {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn update(&self, conversation_id: &str, metadata: HashMap<String, String>) -> Result<Conversation> {
+    pub async fn update(&self, conversation_id: &str, metadata: impl Into<HashMap<String, String>>) -> Result<Conversation> {
+        let metadata = metadata.into();
+        metadata::validate_map(&metadata)?;
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(CONVERSATIONS_PATH), conversation_id);
 
         let request_body = UpdateConversationRequest { metadata };
         let body = serde_json::to_string(&request_body)?;
 
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<Conversation>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -423,17 +439,18 @@ impl Conversations {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(CONVERSATIONS_PATH), conversation_id);
 
-        let response = client.delete(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.delete(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<DeleteConversationResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -480,17 +497,18 @@ impl Conversations {
         let request_body = CreateItemsRequest { items };
         let body = serde_json::to_string(&request_body)?;
 
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ConversationItemListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -568,22 +586,60 @@ impl Conversations {
             format!("{}/{}/items?{}", self.auth.endpoint(CONVERSATIONS_PATH), conversation_id, params.join("&"))
         };
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ConversationItemListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
+    /// Lists all items in a conversation as an async stream, following pagination cursors automatically.
+    ///
+    /// Pages are requested lazily as the stream is consumed, using `has_more` and
+    /// `last_id` from each page to request the next one. If a request fails, the
+    /// error is yielded and the stream ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `conversation_id` - The ID of the conversation
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding each `ConversationItem` across all pages
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use openai_tools::conversations::request::Conversations;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let conversations = Conversations::new()?;
+    ///
+    ///     let items = conversations.list_all_items("conv_abc123");
+    ///     pin_mut!(items);
+    ///     while let Some(item) = items.next().await {
+    ///         let item = item?;
+    ///         println!("Item: {} ({})", item.id, item.item_type);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_all_items<'a>(&'a self, conversation_id: &'a str) -> impl Stream<Item = Result<ConversationItem>> + 'a {
+        paginate(move |after| async move { self.list_items(conversation_id, None, after.as_deref(), None, None).await })
+    }
+
     /// Lists all conversations (if available).
     ///
     /// Note: This endpoint may not be available in all API versions.
@@ -632,17 +688,18 @@ impl Conversations {
             format!("{}?{}", self.auth.endpoint(CONVERSATIONS_PATH), params.join("&"))
         };
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            return Err(Self::handle_error(status, &content));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ConversationListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -4,6 +4,10 @@
 //! The Conversations API allows you to create and manage long-running conversations
 //! with the Responses API.
 
+use crate::common::delete::DeleteResponse;
+use crate::common::message::{Content, Message};
+use crate::common::pagination::{impl_paginated, Page};
+use crate::common::role::Role;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -64,6 +68,14 @@ pub struct ConversationListResponse {
     pub has_more: bool,
 }
 
+impl From<ConversationListResponse> for Page<Conversation> {
+    fn from(response: ConversationListResponse) -> Self {
+        Page { data: response.data, first_id: response.first_id, last_id: response.last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(ConversationListResponse, Conversation);
+
 /// Represents a conversation item.
 ///
 /// Items can be messages, tool calls, tool outputs, reasoning, or other types
@@ -89,6 +101,49 @@ pub struct ConversationItem {
     pub status: Option<String>,
 }
 
+impl ConversationItem {
+    /// Returns the text of the item, normalizing the different content shapes.
+    ///
+    /// `content` may be a plain string (legacy format) or an array of parts such
+    /// as `{"type": "input_text", "text": "..."}` or `{"type": "output_text", "text": "..."}`.
+    /// Text parts are joined with newlines; non-text parts (images, files, ...) are skipped.
+    ///
+    /// Returns `None` if the item has no text content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::conversations::response::ConversationItem;
+    ///
+    /// let legacy: ConversationItem = serde_json::from_str(r#"{"id": "item_1", "type": "message", "content": "Hello!"}"#).unwrap();
+    /// assert_eq!(legacy.text().as_deref(), Some("Hello!"));
+    ///
+    /// let parts: ConversationItem = serde_json::from_str(
+    ///     r#"{"id": "msg_1", "type": "message", "content": [{"type": "output_text", "text": "Hello!", "annotations": []}]}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(parts.text().as_deref(), Some("Hello!"));
+    /// ```
+    pub fn text(&self) -> Option<String> {
+        match self.content.as_ref()? {
+            serde_json::Value::String(text) => Some(text.clone()),
+            serde_json::Value::Array(parts) => {
+                let texts = parts
+                    .iter()
+                    .filter(|part| matches!(part.get("type").and_then(|t| t.as_str()), Some("input_text" | "output_text" | "text")))
+                    .filter_map(|part| part.get("text").and_then(|t| t.as_str()))
+                    .collect::<Vec<_>>();
+                if texts.is_empty() {
+                    None
+                } else {
+                    Some(texts.join("\n"))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Response structure for listing conversation items.
 ///
 /// Contains a list of conversation item objects with pagination information.
@@ -109,25 +164,25 @@ pub struct ConversationItemListResponse {
     pub has_more: bool,
 }
 
-/// Response structure for conversation deletion.
-///
-/// Returned when a conversation is successfully deleted.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteConversationResponse {
-    /// The conversation ID that was deleted
-    pub id: String,
-    /// Object type, always "conversation.deleted"
-    pub object: String,
-    /// Whether the conversation was successfully deleted
-    pub deleted: bool,
+impl From<ConversationItemListResponse> for Page<ConversationItem> {
+    fn from(response: ConversationItemListResponse) -> Self {
+        Page { data: response.data, first_id: response.first_id, last_id: response.last_id, has_more: response.has_more }
+    }
 }
 
+impl_paginated!(ConversationItemListResponse, ConversationItem);
+
+/// Response structure for conversation deletion, with object type
+/// `"conversation.deleted"`.
+pub type DeleteConversationResponse = DeleteResponse;
+
 /// Input item for creating conversation items.
 ///
-/// Used when adding new items to a conversation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Used when adding new items to a conversation. Chat Completions history can
+/// be converted with [`InputItem::from_messages`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InputItem {
-    /// The type of item (e.g., "message")
+    /// The type of item (e.g., "message", "function_call", "function_call_output")
     #[serde(rename = "type")]
     pub item_type: String,
     /// The role of the item (e.g., "user", "assistant")
@@ -136,6 +191,18 @@ pub struct InputItem {
     /// The content of the item (can be a string or structured content)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content: Option<serde_json::Value>,
+    /// The ID of the tool call (function_call and function_call_output items)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    /// The name of the called function (function_call items)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The function arguments as a JSON string (function_call items)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<String>,
+    /// The result of the tool call (function_call_output items)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
 }
 
 impl InputItem {
@@ -156,7 +223,12 @@ impl InputItem {
     /// assert_eq!(item.role, Some("user".to_string()));
     /// ```
     pub fn message(role: &str, content: &str) -> Self {
-        Self { item_type: "message".to_string(), role: Some(role.to_string()), content: Some(serde_json::Value::String(content.to_string())) }
+        Self {
+            item_type: "message".to_string(),
+            role: Some(role.to_string()),
+            content: Some(serde_json::Value::String(content.to_string())),
+            ..Default::default()
+        }
     }
 
     /// Creates a new user message item.
@@ -194,4 +266,106 @@ impl InputItem {
     pub fn assistant_message(content: &str) -> Self {
         Self::message("assistant", content)
     }
+
+    /// Converts a Chat Completions message into a conversation item.
+    ///
+    /// - `system`, `developer`, `user` and `assistant` messages become `message`
+    ///   items with the same role. Text-only messages keep plain string content;
+    ///   multi-part messages become `input_text` / `input_image` parts
+    ///   (`output_text` for the assistant).
+    /// - `tool` (and legacy `function`) messages become `function_call_output`
+    ///   items answering their `tool_call_id`.
+    ///
+    /// Tool calls of assistant messages are not items of the message itself;
+    /// use [`from_messages`](Self::from_messages) to convert them as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The chat message to convert
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::Message;
+    /// use openai_tools::common::role::Role;
+    /// use openai_tools::conversations::response::InputItem;
+    ///
+    /// let item = InputItem::from_message(&Message::from_string(Role::User, "Hello!"));
+    /// assert_eq!(item.item_type, "message");
+    /// assert_eq!(item.role.as_deref(), Some("user"));
+    /// assert_eq!(item.content, Some(serde_json::json!("Hello!")));
+    /// ```
+    pub fn from_message(message: &Message) -> Self {
+        if matches!(message.role, Role::Tool | Role::Function) {
+            return Self {
+                item_type: "function_call_output".to_string(),
+                call_id: message.tool_call_id.clone(),
+                output: Some(message.as_text().unwrap_or_default()),
+                ..Default::default()
+            };
+        }
+
+        let text_type = if message.role == Role::Assistant { "output_text" } else { "input_text" };
+        let content = match (&message.content, &message.content_list) {
+            (Some(Content { text: Some(text), .. }), None) => serde_json::Value::String(text.clone()),
+            (single, list) => single
+                .iter()
+                .chain(list.iter().flatten())
+                .filter_map(|part| match (&part.text, &part.image_url) {
+                    (Some(text), _) => Some(serde_json::json!({"type": text_type, "text": text})),
+                    (None, Some(image_url)) => Some(serde_json::json!({"type": "input_image", "image_url": image_url})),
+                    (None, None) => None,
+                })
+                .collect(),
+        };
+        Self { item_type: "message".to_string(), role: Some(message.role.as_str().to_string()), content: Some(content), ..Default::default() }
+    }
+
+    /// Converts a Chat Completions history into conversation items, in order.
+    ///
+    /// Messages are converted with [`from_message`](Self::from_message). The
+    /// tool calls of an assistant message are added as `function_call` items
+    /// after it, so they line up with the `function_call_output` items of the
+    /// following tool messages. An assistant message with tool calls but no
+    /// text produces only the `function_call` items.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The chat history, oldest first
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::message::{Message, ToolCall};
+    /// use openai_tools::common::role::Role;
+    /// use openai_tools::conversations::response::InputItem;
+    ///
+    /// let call = ToolCall::function("call_1", "get_weather", r#"{"city": "Tokyo"}"#).unwrap();
+    /// let history = vec![
+    ///     Message::from_string(Role::User, "What's the weather in Tokyo?"),
+    ///     Message::assistant_with_tool_calls(None, vec![call]),
+    ///     Message::from_tool_call_response("Sunny, 21°C", "call_1"),
+    /// ];
+    ///
+    /// let items = InputItem::from_messages(&history);
+    /// let types: Vec<_> = items.iter().map(|item| item.item_type.as_str()).collect();
+    /// assert_eq!(types, ["message", "function_call", "function_call_output"]);
+    /// ```
+    pub fn from_messages(messages: &[Message]) -> Vec<Self> {
+        let mut items = Vec::with_capacity(messages.len());
+        for message in messages {
+            let tool_calls = message.tool_calls.as_deref().unwrap_or_default();
+            if tool_calls.is_empty() || message.as_text().is_some() {
+                items.push(Self::from_message(message));
+            }
+            items.extend(tool_calls.iter().map(|call| Self {
+                item_type: "function_call".to_string(),
+                call_id: Some(call.id.clone()),
+                name: Some(call.function.name.clone()),
+                arguments: Some(serde_json::to_string(&call.function.arguments.clone().unwrap_or_default()).unwrap_or_else(|_| "{}".to_string())),
+                ..Default::default()
+            }));
+        }
+        items
+    }
 }
@@ -146,7 +146,8 @@ pub mod response;
 #[cfg(test)]
 mod tests {
     use crate::common::models::EmbeddingModel;
-    use crate::embedding::request::Embedding;
+    use crate::embedding::request::{merge_chunk_embeddings, plan_input_chunks, Embedding, InputChunk, OversizedInputStrategy};
+    use crate::embedding::response::Response;
 
     #[test]
     fn test_embedding_builder_model() {
@@ -197,4 +198,77 @@ mod tests {
         embedding.model(EmbeddingModel::TextEmbedding3Small).input_text("Hello!").encoding_format("float");
         // Method chaining works
     }
+
+    #[tokio::test]
+    async fn test_embed_oversized_input_returns_indexed_error() {
+        let mut embedding = Embedding::new().expect("Embedding initialization should succeed");
+        embedding.input_text_array(vec!["short text".to_string(), " hello".repeat(9000)]);
+
+        let err = embedding.embed().await.expect_err("Oversized input should fail before sending");
+        let message = err.to_string();
+        assert!(message.contains("Input 1 has 9000 tokens"), "{}", message);
+        assert!(message.contains("8191-token limit of text-embedding-3-small"), "{}", message);
+    }
+
+    #[test]
+    fn test_plan_input_chunks_strategies() {
+        let model = EmbeddingModel::TextEmbedding3Small;
+        let texts = vec!["fits".to_string(), " hello".repeat(25)];
+
+        assert_eq!(plan_input_chunks(&model, &texts[..1], 10, OversizedInputStrategy::Error).unwrap(), None);
+        assert!(plan_input_chunks(&model, &texts, 10, OversizedInputStrategy::Error).is_err());
+
+        let truncated = plan_input_chunks(&model, &texts, 10, OversizedInputStrategy::Truncate).unwrap().unwrap();
+        assert_eq!(truncated.len(), 2);
+        assert_eq!(truncated[1].text, " hello".repeat(10));
+
+        let split = plan_input_chunks(&model, &texts, 10, OversizedInputStrategy::Split).unwrap().unwrap();
+        let pieces: Vec<(usize, usize)> = split.iter().map(|c| (c.input_index, c.tokens)).collect();
+        assert_eq!(pieces, vec![(0, 1), (1, 10), (1, 10), (1, 5)]);
+        assert_eq!(split.iter().skip(1).map(|c| c.text.as_str()).collect::<String>(), texts[1]);
+    }
+
+    #[test]
+    fn test_plan_input_chunks_split_keeps_multibyte_text_intact() {
+        let model = EmbeddingModel::TextEmbedding3Small;
+        // Mixed scripts and emoji, whose characters span several bytes and tokens
+        let texts = vec!["日本語のテキスト 🦀 Rust と埋め込み。".repeat(20)];
+
+        let split = plan_input_chunks(&model, &texts, 7, OversizedInputStrategy::Split).unwrap().unwrap();
+        assert!(split.len() > 1);
+        assert!(split.iter().all(|c| c.tokens <= 7 && !c.text.is_empty()));
+        assert_eq!(split.iter().map(|c| c.text.as_str()).collect::<String>(), texts[0]);
+        assert_eq!(split.iter().map(|c| c.tokens).sum::<usize>(), tiktoken_rs::cl100k_base().unwrap().encode_ordinary(&texts[0]).len());
+    }
+
+    #[test]
+    fn test_merge_chunk_embeddings_weighted_average() {
+        let chunks = vec![
+            InputChunk { input_index: 0, text: "a".into(), tokens: 1 },
+            InputChunk { input_index: 1, text: "b".into(), tokens: 3 },
+            InputChunk { input_index: 1, text: "c".into(), tokens: 1 },
+        ];
+        let response: Response = serde_json::from_str(
+            r#"{
+                "object": "list",
+                "data": [
+                    {"object": "embedding", "embedding": [1.0, 0.0], "index": 0},
+                    {"object": "embedding", "embedding": [1.0, 0.0], "index": 1},
+                    {"object": "embedding", "embedding": [0.0, 1.0], "index": 2}
+                ],
+                "model": "text-embedding-3-small",
+                "usage": {"prompt_tokens": 5, "total_tokens": 5}
+            }"#,
+        )
+        .unwrap();
+
+        let merged = merge_chunk_embeddings(response, &chunks).unwrap();
+        assert_eq!(merged.data.len(), 2);
+        assert_eq!(merged.data[1].index, 1);
+        let vector = merged.data[1].embedding.as_1d().unwrap();
+        // (3 * [1, 0] + 1 * [0, 1]) / 4, normalized
+        let norm = (0.75f32 * 0.75 + 0.25 * 0.25).sqrt();
+        assert!((vector[0] - 0.75 / norm).abs() < 1e-6);
+        assert!((vector[1] - 0.25 / norm).abs() < 1e-6);
+    }
 }
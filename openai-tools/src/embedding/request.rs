@@ -9,6 +9,7 @@
 //! - **Builder Pattern**: Fluent API for constructing embedding requests
 //! - **Single & Batch Input**: Support for single text or multiple texts at once
 //! - **Encoding Formats**: Support for `float` and `base64` output formats
+//! - **Oversized Inputs**: Inputs over the model's token limit are rejected by index, truncated, or split
 //! - **Error Handling**: Robust error management and validation
 //!
 //! # Quick Start
@@ -63,13 +64,14 @@
 //! }
 //! ```
 
-use crate::common::auth::{AuthProvider, OpenAIAuth};
-use crate::common::client::create_http_client;
-use crate::common::errors::{ErrorResponse, OpenAIToolError, Result};
+use crate::common::auth::AuthProvider;
+use crate::common::client::cached_http_client;
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
 use crate::common::models::EmbeddingModel;
-use crate::embedding::response::Response;
+use crate::embedding::response::{Embedding as EmbeddingVector, EmbeddingData, Response};
 use core::str;
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
 use std::time::Duration;
 
 /// Internal structure for handling input text in embedding requests.
@@ -91,6 +93,15 @@ struct Input {
 }
 
 impl Input {
+    /// Returns the input texts, whether a single text or an array was set.
+    fn texts(&self) -> Vec<String> {
+        if self.input_text_array.is_empty() {
+            vec![self.input_text.clone()]
+        } else {
+            self.input_text_array.clone()
+        }
+    }
+
     /// Creates an Input from a single text string.
     ///
     /// # Arguments
@@ -143,15 +154,149 @@ impl Serialize for Input {
 ///
 /// Contains all parameters that can be sent to the API endpoint.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
-struct Body {
+pub(crate) struct Body {
     /// The model to use for embedding generation
     model: EmbeddingModel,
     /// The input text(s) to embed
     input: Input,
     /// The format for the output embeddings ("float" or "base64")
+    #[serde(skip_serializing_if = "Option::is_none")]
     encoding_format: Option<String>,
 }
 
+/// How [`Embedding::embed`] handles inputs longer than the model's token limit.
+///
+/// See [`EmbeddingModel::max_input_tokens`] for the limit of each model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OversizedInputStrategy {
+    /// Fail before sending the request, with an error naming the offending input's index (default)
+    #[default]
+    Error,
+    /// Keep only the first `max_input_tokens` tokens of the input
+    Truncate,
+    /// Split the input into chunks that fit the limit, embed each chunk, and combine the
+    /// chunk embeddings into one by a token-weighted average (normalized to unit length)
+    ///
+    /// Requires the `float` encoding format.
+    Split,
+}
+
+/// A piece of an input text that fits within the model's token limit.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct InputChunk {
+    /// Index of the input the chunk belongs to
+    pub(crate) input_index: usize,
+    /// The chunk text
+    pub(crate) text: String,
+    /// Number of tokens in the chunk
+    pub(crate) tokens: usize,
+}
+
+/// Splits `texts` into chunks that fit within `max_tokens`, according to `strategy`.
+///
+/// Returns `Ok(None)` if every input already fits, so the request can be sent as is.
+pub(crate) fn plan_input_chunks(
+    model: &EmbeddingModel,
+    texts: &[String],
+    max_tokens: usize,
+    strategy: OversizedInputStrategy,
+) -> Result<Option<Vec<InputChunk>>> {
+    // A token covers at least one byte, so short texts can skip tokenization
+    if texts.iter().all(|text| text.len() <= max_tokens) {
+        return Ok(None);
+    }
+
+    let bpe = tiktoken_rs::cl100k_base().map_err(OpenAIToolError::AnyhowError)?;
+    let mut chunks = Vec::new();
+    let mut oversized = false;
+    for (input_index, text) in texts.iter().enumerate() {
+        let tokens = bpe.encode_ordinary(text);
+        if tokens.len() <= max_tokens {
+            chunks.push(InputChunk { input_index, text: text.clone(), tokens: tokens.len() });
+            continue;
+        }
+
+        oversized = true;
+        match strategy {
+            OversizedInputStrategy::Error => {
+                return Err(OpenAIToolError::Error(format!(
+                    "Input {} has {} tokens, which exceeds the {}-token limit of {}. \
+                     Shorten it or use OversizedInputStrategy::Truncate or OversizedInputStrategy::Split.",
+                    input_index,
+                    tokens.len(),
+                    max_tokens,
+                    model
+                )));
+            }
+            OversizedInputStrategy::Truncate | OversizedInputStrategy::Split => {
+                let mut offset = 0;
+                let mut rest = tokens.as_slice();
+                while !rest.is_empty() {
+                    // Map the token window back to a byte range of the text. A window ending
+                    // inside a multi-byte character does not decode, so it is shortened until
+                    // it ends on a character boundary.
+                    let (window, length) = (1..=rest.len().min(max_tokens))
+                        .rev()
+                        .find_map(|window| bpe.decode(rest[..window].to_vec()).ok().map(|decoded| (window, decoded.len())))
+                        .ok_or_else(|| {
+                            OpenAIToolError::Error(format!(
+                                "Input {} cannot be split into {}-token chunks at character boundaries",
+                                input_index, max_tokens
+                            ))
+                        })?;
+                    rest = &rest[window..];
+                    let end = if rest.is_empty() { text.len() } else { offset + length };
+                    chunks.push(InputChunk { input_index, text: text[offset..end].to_string(), tokens: window });
+                    offset = end;
+
+                    if strategy == OversizedInputStrategy::Truncate {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(oversized.then_some(chunks))
+}
+
+/// Combines the embeddings of input chunks into one embedding per input.
+///
+/// Each input's embedding is the token-weighted average of its chunk embeddings,
+/// normalized to unit length.
+pub(crate) fn merge_chunk_embeddings(response: Response, chunks: &[InputChunk]) -> Result<Response> {
+    let mut merged: Vec<(usize, Vec<f32>, f32)> = Vec::new();
+    let mut data = response.data;
+    data.sort_by_key(|d| d.index);
+
+    for (chunk, item) in chunks.iter().zip(data.iter()) {
+        let vector = item.embedding.as_1d().ok_or_else(|| OpenAIToolError::Error("Chunked inputs require the 'float' encoding format".into()))?;
+        let weight = chunk.tokens as f32;
+        match merged.last_mut() {
+            Some((index, sum, total)) if *index == chunk.input_index => {
+                sum.iter_mut().zip(vector).for_each(|(s, v)| *s += v * weight);
+                *total += weight;
+            }
+            _ => merged.push((chunk.input_index, vector.iter().map(|v| v * weight).collect(), weight)),
+        }
+    }
+
+    let object = data.first().map(|d| d.object.clone()).unwrap_or_else(|| "embedding".to_string());
+    let data = merged
+        .into_iter()
+        .map(|(index, sum, total)| {
+            let mut average: Vec<f32> = sum.into_iter().map(|v| v / total).collect();
+            let norm = average.iter().map(|v| v * v).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                average.iter_mut().for_each(|v| *v /= norm);
+            }
+            EmbeddingData { object: object.clone(), embedding: EmbeddingVector::OneDim(average), index }
+        })
+        .collect();
+
+    Ok(Response { data, ..response })
+}
+
 /// Default API path for Embeddings
 const EMBEDDINGS_PATH: &str = "embeddings";
 
@@ -190,9 +335,15 @@ pub struct Embedding {
     /// Authentication provider (OpenAI or Azure)
     auth: AuthProvider,
     /// Request body containing model and input parameters
-    body: Body,
+    pub(crate) body: Body,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
+    /// HTTP client built on first use and reused by later requests
+    cached_http_client: OnceLock<request::Client>,
+    /// How to handle inputs over the model's token limit
+    oversized_input: OversizedInputStrategy,
 }
 
 impl Embedding {
@@ -217,7 +368,14 @@ impl Embedding {
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
         let body = Body::default();
-        Ok(Self { auth, body, timeout: None })
+        Ok(Self {
+            auth,
+            body,
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            oversized_input: OversizedInputStrategy::default(),
+        })
     }
 
     /// Creates a new Embedding instance with a custom authentication provider
@@ -232,7 +390,24 @@ impl Embedding {
     ///
     /// A new Embedding instance with the specified auth provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, body: Body::default(), timeout: None }
+        Self {
+            auth,
+            body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            oversized_input: OversizedInputStrategy::default(),
+        }
+    }
+
+    /// Creates a new Embedding instance that sends requests with the given HTTP client
+    ///
+    /// Use this to reuse a pre-configured `reqwest::Client` (connection limits,
+    /// proxy, TLS settings, ...). The API key is loaded as in [`Embedding::new`].
+    pub fn with_http_client(http_client: request::Client) -> Result<Self> {
+        let mut embedding = Self::new()?;
+        embedding.http_client = Some(http_client);
+        Ok(embedding)
     }
 
     /// Creates a new Embedding instance for Azure OpenAI API
@@ -244,7 +419,14 @@ impl Embedding {
     /// `Result<Embedding>` - Configured for Azure or error if env vars missing
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            oversized_input: OversizedInputStrategy::default(),
+        })
     }
 
     /// Creates a new Embedding instance by auto-detecting the provider
@@ -252,7 +434,14 @@ impl Embedding {
     /// Tries Azure first (if AZURE_OPENAI_API_KEY is set), then falls back to OpenAI.
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            oversized_input: OversizedInputStrategy::default(),
+        })
     }
 
     /// Creates a new Embedding instance with URL-based provider detection
@@ -267,7 +456,14 @@ impl Embedding {
     /// * `api_key` - The API key or token
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, body: Body::default(), timeout: None }
+        Self {
+            auth,
+            body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            oversized_input: OversizedInputStrategy::default(),
+        }
     }
 
     /// Creates a new Embedding instance from URL using environment variables
@@ -276,7 +472,14 @@ impl Embedding {
     /// credentials from the appropriate environment variables.
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            oversized_input: OversizedInputStrategy::default(),
+        })
     }
 
     /// Returns the authentication provider
@@ -297,7 +500,7 @@ impl Embedding {
     /// A mutable reference to self for method chaining
     pub fn base_url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         if let AuthProvider::OpenAI(ref openai_auth) = self.auth {
-            let new_auth = OpenAIAuth::new(openai_auth.api_key()).with_base_url(url.as_ref());
+            let new_auth = openai_auth.clone().with_base_url(url.as_ref());
             self.auth = AuthProvider::OpenAI(new_auth);
         } else {
             tracing::warn!("base_url() is only supported for OpenAI provider. Use azure() or with_auth() for Azure.");
@@ -348,6 +551,12 @@ impl Embedding {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -454,6 +663,31 @@ impl Embedding {
         self
     }
 
+    /// Sets how inputs longer than the model's token limit are handled.
+    ///
+    /// By default ([`OversizedInputStrategy::Error`]), [`embed`](Self::embed) fails
+    /// before sending the request with an error naming the offending input's index.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The strategy for oversized inputs
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use openai_tools::embedding::request::{Embedding, OversizedInputStrategy};
+    /// # let mut embedding = Embedding::new().unwrap();
+    /// embedding.oversized_input_strategy(OversizedInputStrategy::Split);
+    /// ```
+    pub fn oversized_input_strategy(&mut self, strategy: OversizedInputStrategy) -> &mut Self {
+        self.oversized_input = strategy;
+        self
+    }
+
     /// Sends the embedding request to the OpenAI API.
     ///
     /// This method validates the request parameters, constructs the HTTP request,
@@ -470,6 +704,7 @@ impl Embedding {
     /// - API key is not set
     /// - Model ID is not set
     /// - Input text is not set
+    /// - An input exceeds the model's token limit and the strategy is [`OversizedInputStrategy::Error`]
     /// - Network request fails
     /// - Response parsing fails
     ///
@@ -495,9 +730,25 @@ impl Embedding {
             return Err(OpenAIToolError::Error("Input text is not set.".into()));
         }
 
-        let body = serde_json::to_string(&self.body)?;
+        let texts = self.body.input.texts();
+        let max_tokens = self.body.model.max_input_tokens();
+        let Some(chunks) = plan_input_chunks(&self.body.model, &texts, max_tokens, self.oversized_input)? else {
+            return self.send(&self.body).await;
+        };
 
-        let client = create_http_client(self.timeout)?;
+        if self.body.encoding_format.as_deref() == Some("base64") {
+            return Err(OpenAIToolError::Error("Truncating or splitting oversized inputs requires the 'float' encoding format".into()));
+        }
+        let body = Body { input: Input::from_text_array(chunks.iter().map(|c| c.text.clone()).collect()), ..self.body.clone() };
+        let response = self.send(&body).await?;
+        merge_chunk_embeddings(response, &chunks)
+    }
+
+    /// Sends a request body to the Embeddings API and parses the response.
+    async fn send(&self, request_body: &Body) -> Result<Response> {
+        let body = serde_json::to_string(request_body)?;
+
+        let client = cached_http_client(self.http_client.as_ref(), &self.cached_http_client, self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
         headers.insert("User-Agent", request::header::HeaderValue::from_static("openai-tools-rust"));
@@ -507,26 +758,24 @@ impl Embedding {
 
         if cfg!(test) {
             // Replace API key with a placeholder in debug mode
-            let body_for_debug = serde_json::to_string_pretty(&self.body).unwrap().replace(self.auth.api_key(), "*************");
+            let body_for_debug = serde_json::to_string_pretty(request_body).unwrap().replace(self.auth.api_key(), "*************");
             tracing::info!("Request body: {}", body_for_debug);
         }
 
         // Get the endpoint URL from the auth provider
         let endpoint = self.auth.endpoint(EMBEDDINGS_PATH);
 
-        let response = client.post(&endpoint).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&endpoint).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
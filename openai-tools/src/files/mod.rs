@@ -152,7 +152,7 @@ pub mod response;
 
 #[cfg(test)]
 mod tests {
-    use crate::files::request::FilePurpose;
+    use crate::files::request::{mime_type_from_filename, FilePurpose};
     use crate::files::response::{DeleteResponse, File, FileListResponse};
 
     #[test]
@@ -224,6 +224,32 @@ mod tests {
         assert_eq!(response.data[1].purpose, "batch");
     }
 
+    #[test]
+    fn test_file_list_response_pagination_cursor() {
+        let json = r#"{
+            "object": "list",
+            "data": [
+                {"id": "file-abc123", "object": "file", "bytes": 140, "created_at": 1613779657, "filename": "a.jsonl", "purpose": "batch"},
+                {"id": "file-def456", "object": "file", "bytes": 250, "created_at": 1613779700, "filename": "b.jsonl", "purpose": "batch"}
+            ],
+            "first_id": "file-abc123",
+            "last_id": "file-def456",
+            "has_more": true
+        }"#;
+
+        let mut response: FileListResponse = serde_json::from_str(json).expect("Should deserialize FileListResponse");
+        assert_eq!(response.first_id, Some("file-abc123".to_string()));
+        assert_eq!(response.next_cursor(), Some("file-def456"));
+
+        // Falls back to the last file ID when last_id is missing
+        response.last_id = None;
+        assert_eq!(response.next_cursor(), Some("file-def456"));
+
+        // No cursor on the last page
+        response.has_more = Some(false);
+        assert_eq!(response.next_cursor(), None);
+    }
+
     #[test]
     fn test_delete_response_deserialization() {
         let json = r#"{
@@ -275,4 +301,102 @@ mod tests {
         // status should not be in the JSON since it's None with skip_serializing_if
         assert!(!json.contains("\"status\""));
     }
+
+    #[test]
+    fn test_mime_type_inference() {
+        assert_eq!(mime_type_from_filename("training.jsonl"), "application/jsonl");
+        assert_eq!(mime_type_from_filename("image.png"), "image/png");
+        assert_eq!(mime_type_from_filename("speech.wav"), "audio/wav");
+        assert_eq!(mime_type_from_filename("paper.pdf"), "application/pdf");
+        // Case-insensitive and path-aware
+        assert_eq!(mime_type_from_filename("data/Upload.JSONL"), "application/jsonl");
+        assert_eq!(mime_type_from_filename("archive.tar.PDF"), "application/pdf");
+    }
+
+    #[test]
+    fn test_mime_type_inference_fallback() {
+        assert_eq!(mime_type_from_filename("batch_input"), "application/octet-stream");
+        assert_eq!(mime_type_from_filename(".env"), "application/octet-stream");
+        assert_eq!(mime_type_from_filename("data.unknownext"), "application/octet-stream");
+        assert_eq!(mime_type_from_filename(""), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_mime_type_override() {
+        use crate::files::request::resolve_content_type;
+
+        assert_eq!(resolve_content_type("batch_input", None), "application/octet-stream");
+        assert_eq!(resolve_content_type("batch_input", Some("application/jsonl")), "application/jsonl");
+        // An explicit content type takes precedence over the extension
+        assert_eq!(resolve_content_type("data.txt", Some("application/jsonl")), "application/jsonl");
+    }
+
+    #[tokio::test]
+    async fn test_lines_reassembles_chunked_body() {
+        use crate::files::request::lines;
+        use bytes::Bytes;
+        use futures_util::{stream, StreamExt};
+
+        // Lines split across chunks, including inside a multi-byte character
+        let body = "{\"custom_id\": \"a\"}\r\n{\"custom_id\": \"caf\u{e9}\"}\n\n{\"custom_id\": \"c\"}".as_bytes();
+        let chunks = [&body[..5], &body[5..22], &body[22..40], &body[40..]];
+        let chunks = stream::iter(chunks.into_iter().map(|chunk| Ok(Bytes::copy_from_slice(chunk))));
+
+        let collected = lines(chunks).map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(collected, vec![r#"{"custom_id": "a"}"#, "{\"custom_id\": \"caf\u{e9}\"}", r#"{"custom_id": "c"}"#]);
+    }
+
+    #[tokio::test]
+    async fn test_content_lines_streams_file_content() {
+        use crate::files::request::Files;
+        use futures_util::StreamExt;
+
+        let body = "{\"id\": 1}\n{\"id\": 2}\n";
+        let (base_url, server) =
+            crate::common::mock_server::serve(vec![(200, body.to_string()), (404, r#"{"error": {"message": "No such File object"}}"#.to_string())])
+                .await;
+        let files = Files::with_url(base_url, "sk-test".to_string());
+
+        let lines = files.content_lines("file-abc123").await.unwrap().map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(lines, vec![r#"{"id": 1}"#, r#"{"id": 2}"#]);
+
+        assert!(files.content_stream("file-missing").await.is_err());
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /v1/files/file-abc123/content HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_upload_path_with_progress_reports_bytes_sent() {
+        use crate::files::request::Files;
+        use std::sync::{Arc, Mutex};
+
+        let path = std::env::temp_dir().join(format!("openai-tools-upload-{}.jsonl", std::process::id()));
+        let content = "{\"custom_id\": \"request-1\"}\n".repeat(5000);
+        std::fs::write(&path, &content).unwrap();
+
+        let uploaded =
+            r#"{"id": "file-abc123", "object": "file", "bytes": 140000, "created_at": 1700000000, "filename": "batch.jsonl", "purpose": "batch"}"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, uploaded.to_string())]).await;
+        let files = Files::with_url(base_url, "sk-test".to_string());
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let recorder = reports.clone();
+        let file = files
+            .upload_path_with_progress(path.to_str().unwrap(), FilePurpose::Batch, move |sent, total| recorder.lock().unwrap().push((sent, total)))
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(file.id, "file-abc123");
+
+        let total = content.len() as u64;
+        let reports = reports.lock().unwrap().clone();
+        assert!(reports.len() > 1);
+        assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(reports.last(), Some(&(total, total)));
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /v1/files HTTP/1.1");
+        assert!(requests[0].body.contains(&content));
+    }
 }
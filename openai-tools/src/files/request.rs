@@ -6,7 +6,7 @@
 //! # Key Features
 //!
 //! - **Upload Files**: Upload files for fine-tuning, batch processing, assistants, etc.
-//! - **List Files**: Retrieve all uploaded files
+//! - **List Files**: Retrieve all uploaded files, or stream them page by page
 //! - **Retrieve File**: Get details of a specific file
 //! - **Delete File**: Remove an uploaded file
 //! - **Get Content**: Retrieve the content of a file
@@ -30,14 +30,19 @@
 //! }
 //! ```
 
-use crate::common::auth::{AuthProvider, OpenAIAuth};
-use crate::common::client::create_http_client;
-use crate::common::errors::{ErrorResponse, OpenAIToolError, Result};
+use crate::common::auth::AuthProvider;
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
+use crate::common::pagination::paginate;
 use crate::files::response::{DeleteResponse, File, FileListResponse};
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
 use request::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::path::Path;
 use std::time::Duration;
+use tokio::io::AsyncReadExt;
 
 /// Default API path for Files
 const FILES_PATH: &str = "files";
@@ -120,6 +125,8 @@ pub struct Files {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl Files {
@@ -143,36 +150,36 @@ impl Files {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Files client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Files client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Files client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Files client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Files client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -193,7 +200,7 @@ impl Files {
     /// A mutable reference to self for method chaining
     pub fn base_url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         if let AuthProvider::OpenAI(ref openai_auth) = self.auth {
-            let new_auth = OpenAIAuth::new(openai_auth.api_key()).with_base_url(url.as_ref());
+            let new_auth = openai_auth.clone().with_base_url(url.as_ref());
             self.auth = AuthProvider::OpenAI(new_auth);
         } else {
             tracing::warn!("base_url() is only supported for OpenAI provider. Use azure() or with_auth() for Azure.");
@@ -203,6 +210,12 @@ impl Files {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -226,8 +239,8 @@ impl Files {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("User-Agent", request::header::HeaderValue::from_static("openai-tools-rust"));
@@ -264,17 +277,66 @@ impl Files {
     /// }
     /// ```
     pub async fn upload_path(&self, file_path: &str, purpose: FilePurpose) -> Result<File> {
+        self.upload_path_with_progress(file_path, purpose, |_, _| {}).await
+    }
+
+    /// Uploads a file from a file path, reporting progress as the file is sent.
+    ///
+    /// The file is streamed from disk rather than read into memory. `progress`
+    /// is called with `(bytes_sent, total_bytes)` after each chunk is handed to
+    /// the connection, ending with `bytes_sent == total_bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - Path to the file to upload
+    /// * `purpose` - The intended purpose of the uploaded file
+    /// * `progress` - Callback receiving the number of bytes sent and the file size
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(File)` - The uploaded file object
+    /// * `Err(OpenAIToolError)` - If the file cannot be read or the upload fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::files::request::{Files, FilePurpose};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let files = Files::new()?;
+    ///     let file = files
+    ///         .upload_path_with_progress("batch.jsonl", FilePurpose::Batch, |sent, total| {
+    ///             eprint!("\rUploading: {:>3}%", sent * 100 / total.max(1));
+    ///         })
+    ///         .await?;
+    ///     println!("\nUploaded: {}", file.id);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_path_with_progress<F>(&self, file_path: &str, purpose: FilePurpose, progress: F) -> Result<File>
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
         let path = Path::new(file_path);
         let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("file").to_string();
 
-        let content = tokio::fs::read(file_path).await.map_err(|e| OpenAIToolError::Error(format!("Failed to read file: {}", e)))?;
+        let file = tokio::fs::File::open(path).await.map_err(|e| OpenAIToolError::Error(format!("Failed to read file: {}", e)))?;
+        let total = file.metadata().await.map_err(|e| OpenAIToolError::Error(format!("Failed to read file: {}", e)))?.len();
+
+        let mime = resolve_content_type(&filename, None);
+        let file_part = Part::stream_with_length(progress_body(file, total, progress), total)
+            .file_name(filename)
+            .mime_str(mime)
+            .map_err(|e| OpenAIToolError::Error(format!("Failed to set MIME type: {}", e)))?;
 
-        self.upload_bytes(&content, &filename, purpose).await
+        self.upload_part(file_part, purpose).await
     }
 
     /// Uploads a file from bytes.
     ///
     /// The file will be uploaded with the specified filename and purpose.
+    /// The MIME type is inferred from the filename extension.
     ///
     /// # Arguments
     ///
@@ -304,30 +366,78 @@ impl Files {
     /// }
     /// ```
     pub async fn upload_bytes(&self, content: &[u8], filename: &str, purpose: FilePurpose) -> Result<File> {
-        let (client, headers) = self.create_client()?;
+        self.upload_bytes_with_content_type(content, filename, purpose, None).await
+    }
 
+    /// Uploads a file from bytes with an explicit MIME type.
+    ///
+    /// When `content_type` is `None`, the MIME type is inferred from the filename
+    /// extension with [`mime_type_from_filename`]. Pass an explicit content type for
+    /// extensionless filenames or when the extension does not match the content.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The file content as bytes
+    /// * `filename` - The name to give the file
+    /// * `purpose` - The intended purpose of the uploaded file
+    /// * `content_type` - Optional MIME type overriding the inferred one
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(File)` - The uploaded file object
+    /// * `Err(OpenAIToolError)` - If the content type is invalid or the upload fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::files::request::{Files, FilePurpose};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let files = Files::new()?;
+    ///
+    ///     let content = b"{\"custom_id\": \"request-1\"}";
+    ///     let file = files.upload_bytes_with_content_type(content, "batch_input", FilePurpose::Batch, Some("application/jsonl")).await?;
+    ///
+    ///     println!("Uploaded: {}", file.id);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_bytes_with_content_type(
+        &self,
+        content: &[u8],
+        filename: &str,
+        purpose: FilePurpose,
+        content_type: Option<&str>,
+    ) -> Result<File> {
+        let mime = resolve_content_type(filename, content_type);
         let file_part = Part::bytes(content.to_vec())
             .file_name(filename.to_string())
-            .mime_str("application/octet-stream")
+            .mime_str(mime)
             .map_err(|e| OpenAIToolError::Error(format!("Failed to set MIME type: {}", e)))?;
 
+        self.upload_part(file_part, purpose).await
+    }
+
+    /// Sends the multipart upload request for a prepared file part.
+    async fn upload_part(&self, file_part: Part, purpose: FilePurpose) -> Result<File> {
+        let (client, headers) = self.create_client()?;
+
         let form = Form::new().part("file", file_part).text("purpose", purpose.as_str().to_string());
 
         let endpoint = self.auth.endpoint(FILES_PATH);
-        let response = client.post(&endpoint).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&endpoint).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<File>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -367,28 +477,78 @@ impl Files {
     /// }
     /// ```
     pub async fn list(&self, purpose: Option<FilePurpose>) -> Result<FileListResponse> {
+        self.list_page(purpose, None, None).await
+    }
+
+    /// Lists all files as an async stream, following pagination cursors automatically.
+    ///
+    /// Pages are requested lazily as the stream is consumed, using `has_more` and
+    /// `last_id` from each page to request the next one. If a request fails, the
+    /// error is yielded and the stream ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `purpose` - Optional filter by file purpose
+    /// * `limit` - Optional number of files to request per page
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding each `File` across all pages
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use openai_tools::files::request::{Files, FilePurpose};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let files = Files::new()?;
+    ///
+    ///     let stream = files.list_paginated(Some(FilePurpose::Batch), Some(100));
+    ///     pin_mut!(stream);
+    ///     while let Some(file) = stream.next().await {
+    ///         let file = file?;
+    ///         println!("{}: {}", file.id, file.filename);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_paginated(&self, purpose: Option<FilePurpose>, limit: Option<u32>) -> impl Stream<Item = Result<File>> + '_ {
+        paginate(move |after| async move { self.list_page(purpose, limit, after.as_deref()).await })
+    }
+
+    /// Requests a single page of files.
+    async fn list_page(&self, purpose: Option<FilePurpose>, limit: Option<u32>, after: Option<&str>) -> Result<FileListResponse> {
         let (client, headers) = self.create_client()?;
 
+        let mut params = Vec::new();
+        if let Some(p) = purpose {
+            params.push(format!("purpose={}", p.as_str()));
+        }
+        if let Some(l) = limit {
+            params.push(format!("limit={}", l));
+        }
+        if let Some(a) = after {
+            params.push(format!("after={}", a));
+        }
+
         let endpoint = self.auth.endpoint(FILES_PATH);
-        let url = match purpose {
-            Some(p) => format!("{}?purpose={}", endpoint, p.as_str()),
-            None => endpoint,
-        };
+        let url = if params.is_empty() { endpoint } else { format!("{}?{}", endpoint, params.join("&")) };
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<FileListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -425,20 +585,18 @@ impl Files {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(FILES_PATH), file_id);
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<File>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -475,20 +633,18 @@ impl Files {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(FILES_PATH), file_id);
 
-        let response = client.delete(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.delete(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<DeleteResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -525,10 +681,209 @@ impl Files {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}/content", self.auth.endpoint(FILES_PATH), file_id);
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let bytes = response.bytes().await.map_err(OpenAIToolError::RequestError)?;
+        let bytes = response.bytes().await.map_err(OpenAIToolError::from)?;
 
         Ok(bytes.to_vec())
     }
+
+    /// Retrieves the content of a file as a stream of byte chunks.
+    ///
+    /// Unlike [`Files::content`], the file is never held in memory as a whole,
+    /// which suits large batch output files.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The ID of the file to retrieve content from
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Stream)` - A stream of the file's bytes as they arrive
+    /// * `Err(OpenAIToolError)` - If the request fails or the API returns an error
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use openai_tools::files::request::Files;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let files = Files::new()?;
+    ///     let mut output = tokio::fs::File::create("output.jsonl").await?;
+    ///
+    ///     let mut chunks = files.content_stream("file-abc123").await?;
+    ///     while let Some(chunk) = chunks.next().await {
+    ///         output.write_all(&chunk?).await?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn content_stream(&self, file_id: &str) -> Result<impl Stream<Item = Result<Bytes>> + Unpin> {
+        let (client, headers) = self.create_client()?;
+        let url = format!("{}/{}/content", self.auth.endpoint(FILES_PATH), file_id);
+
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let request_id = request_id_header(response.headers());
+            let content = response.text().await.map_err(OpenAIToolError::from)?;
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
+        Ok(response.bytes_stream().map(|chunk| chunk.map_err(OpenAIToolError::from)))
+    }
+
+    /// Retrieves the content of a text file line by line.
+    ///
+    /// Intended for JSONL files such as batch outputs: each non-empty line is
+    /// yielded without its line terminator, and only the current line is buffered.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The ID of the file to retrieve content from
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Stream)` - A stream of the file's lines
+    /// * `Err(OpenAIToolError)` - If the request fails or the API returns an error
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use openai_tools::batch::response::BatchResultLine;
+    /// use openai_tools::files::request::Files;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let files = Files::new()?;
+    ///
+    ///     let lines = files.content_lines("file-abc123").await?;
+    ///     pin_mut!(lines);
+    ///     while let Some(line) = lines.next().await {
+    ///         let output: BatchResultLine = serde_json::from_str(&line?)?;
+    ///         println!("{}", output.custom_id);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn content_lines(&self, file_id: &str) -> Result<impl Stream<Item = Result<String>>> {
+        Ok(lines(self.content_stream(file_id).await?))
+    }
+}
+
+/// Size of the chunks read from disk when streaming an upload.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Wraps a file in a request body that reports the number of bytes handed to the connection.
+fn progress_body<F>(file: tokio::fs::File, total: u64, progress: F) -> request::Body
+where
+    F: Fn(u64, u64) + Send + Sync + 'static,
+{
+    let chunks = stream::try_unfold((file, 0u64, progress), move |(mut file, sent, progress)| async move {
+        let mut buffer = vec![0u8; UPLOAD_CHUNK_SIZE];
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok::<_, std::io::Error>(None);
+        }
+        buffer.truncate(read);
+        let sent = sent + read as u64;
+        progress(sent, total);
+        Ok(Some((Bytes::from(buffer), (file, sent, progress))))
+    });
+    request::Body::wrap_stream(chunks)
+}
+
+/// Splits a stream of byte chunks into non-empty lines.
+///
+/// Lines may span chunks; `\r\n` and `\n` terminators are both accepted.
+pub(crate) fn lines<S>(chunks: S) -> impl Stream<Item = Result<String>>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin,
+{
+    // (byte chunks, bytes of the incomplete line, complete lines not yet yielded, whether the input ended)
+    let state = (chunks, Vec::<u8>::new(), VecDeque::<Vec<u8>>::new(), false);
+
+    stream::try_unfold(state, |(mut chunks, mut partial, mut pending, mut finished)| async move {
+        loop {
+            if let Some(mut line) = pending.pop_front() {
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+                if line.is_empty() {
+                    continue;
+                }
+                let line = String::from_utf8(line).map_err(|e| OpenAIToolError::Error(format!("File content is not valid UTF-8: {}", e)))?;
+                return Ok(Some((line, (chunks, partial, pending, finished))));
+            }
+            if finished {
+                return Ok(None);
+            }
+            match chunks.next().await {
+                Some(chunk) => {
+                    partial.extend_from_slice(&chunk?);
+                    while let Some(pos) = partial.iter().position(|b| *b == b'\n') {
+                        let rest = partial.split_off(pos + 1);
+                        partial.pop();
+                        pending.push_back(std::mem::replace(&mut partial, rest));
+                    }
+                }
+                None => {
+                    finished = true;
+                    pending.push_back(std::mem::take(&mut partial));
+                }
+            }
+        }
+    })
+}
+
+/// Returns the explicit content type if given, otherwise the type inferred from the filename.
+pub(crate) fn resolve_content_type<'a>(filename: &str, content_type: Option<&'a str>) -> &'a str {
+    content_type.unwrap_or_else(|| mime_type_from_filename(filename))
+}
+
+/// Infers the MIME type of a file from its filename extension.
+///
+/// The extension is matched case-insensitively. Filenames without an extension,
+/// or with an unrecognized one, map to `application/octet-stream`.
+///
+/// # Example
+///
+/// ```rust
+/// use openai_tools::files::request::mime_type_from_filename;
+///
+/// assert_eq!(mime_type_from_filename("batch.jsonl"), "application/jsonl");
+/// assert_eq!(mime_type_from_filename("photo.PNG"), "image/png");
+/// assert_eq!(mime_type_from_filename("README"), "application/octet-stream");
+/// ```
+pub fn mime_type_from_filename(filename: &str) -> &'static str {
+    let extension = Path::new(filename).extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("jsonl") => "application/jsonl",
+        Some("json") => "application/json",
+        Some("pdf") => "application/pdf",
+        Some("txt") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("html") | Some("htm") => "text/html",
+        Some("docx") => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        Some("pptx") => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("wav") => "audio/wav",
+        Some("mp3") => "audio/mpeg",
+        Some("m4a") => "audio/mp4",
+        Some("flac") => "audio/flac",
+        Some("ogg") => "audio/ogg",
+        Some("webm") => "audio/webm",
+        Some("mp4") => "video/mp4",
+        _ => "application/octet-stream",
+    }
 }
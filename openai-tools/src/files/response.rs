@@ -2,6 +2,7 @@
 //!
 //! This module defines the response structures for the OpenAI Files API.
 
+use crate::common::pagination::{Page, Paginated};
 use serde::{Deserialize, Serialize};
 
 /// Represents an uploaded file in the OpenAI platform.
@@ -41,20 +42,56 @@ pub struct FileListResponse {
     pub object: String,
     /// Array of file objects
     pub data: Vec<File>,
+    /// ID of the first file in this page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+    /// ID of the last file in this page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
     /// Whether there are more files to retrieve
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_more: Option<bool>,
 }
 
-/// Response structure for file deletion.
-///
-/// Returned when a file is successfully deleted.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteResponse {
-    /// The file identifier that was deleted
-    pub id: String,
-    /// Object type, always "file"
-    pub object: String,
-    /// Whether the file was successfully deleted
-    pub deleted: bool,
+impl FileListResponse {
+    /// Returns the cursor to pass as `after` when requesting the next page.
+    ///
+    /// Returns `None` when this is the last page. Falls back to the ID of the
+    /// last file in `data` when the API does not return `last_id`.
+    pub fn next_cursor(&self) -> Option<&str> {
+        if !self.has_more.unwrap_or(false) {
+            return None;
+        }
+        self.last_id.as_deref().or_else(|| self.data.last().map(|file| file.id.as_str()))
+    }
+}
+
+impl From<FileListResponse> for Page<File> {
+    fn from(response: FileListResponse) -> Self {
+        let last_id = response.last_id.or_else(|| response.data.last().map(|file| file.id.clone()));
+        Page { first_id: response.first_id, last_id, has_more: response.has_more.unwrap_or(false), data: response.data }
+    }
+}
+
+impl Paginated for FileListResponse {
+    type Item = File;
+
+    fn data(&self) -> &[File] {
+        &self.data
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more.unwrap_or(false)
+    }
+
+    fn last_id(&self) -> Option<&str> {
+        self.last_id.as_deref().or_else(|| self.data.last().map(|file| file.id.as_str()))
+    }
+
+    fn into_data(self) -> Vec<File> {
+        self.data
+    }
 }
+
+/// Response structure for file deletion, shared with the other delete endpoints.
+pub use crate::common::delete::DeleteResponse;
@@ -194,6 +194,18 @@ mod tests {
         assert!(json.contains("\"training_file\":\"file-abc123\""));
         assert!(json.contains("\"suffix\":\"test-model\""));
         assert!(!json.contains("validation_file")); // None should be skipped
+        assert!(!json.contains("metadata"));
+    }
+
+    #[test]
+    fn test_create_fine_tuning_job_request_with_metadata() {
+        use std::collections::HashMap;
+
+        let metadata = HashMap::from([("experiment".to_string(), "exp-42".to_string())]);
+        let request = CreateFineTuningJobRequest::new(FineTuningModel::Gpt4oMini_2024_07_18, "file-abc123").with_metadata(metadata);
+
+        let json: serde_json::Value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["metadata"], serde_json::json!({"experiment": "exp-42"}));
     }
 
     #[test]
@@ -211,6 +223,31 @@ mod tests {
         assert_eq!(job.status, FineTuningJobStatus::Failed);
     }
 
+    #[test]
+    fn test_fine_tuning_job_status_is_terminal() {
+        assert!(!FineTuningJobStatus::ValidatingFiles.is_terminal());
+        assert!(!FineTuningJobStatus::Queued.is_terminal());
+        assert!(!FineTuningJobStatus::Running.is_terminal());
+        assert!(FineTuningJobStatus::Succeeded.is_terminal());
+        assert!(FineTuningJobStatus::Failed.is_terminal());
+        assert!(FineTuningJobStatus::Cancelled.is_terminal());
+    }
+
+    #[test]
+    fn test_wait_until_complete_backoff() {
+        use crate::fine_tuning::request::next_poll_interval;
+        use std::time::Duration;
+
+        let initial = Duration::from_secs(10);
+        assert_eq!(next_poll_interval(initial, initial, false), Duration::from_secs(15));
+        assert_eq!(next_poll_interval(Duration::from_secs(15), initial, true), initial);
+        // Capped at five minutes
+        assert_eq!(next_poll_interval(Duration::from_secs(280), initial, false), Duration::from_secs(300));
+        // Never shorter than the requested interval
+        let long = Duration::from_secs(600);
+        assert_eq!(next_poll_interval(long, long, false), long);
+    }
+
     #[test]
     fn test_hyperparameters_default() {
         let hp = Hyperparameters::default();
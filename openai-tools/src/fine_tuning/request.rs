@@ -12,6 +12,7 @@
 //! - **Cancel Jobs**: Cancel an in-progress job
 //! - **List Events**: View training progress and events
 //! - **List Checkpoints**: Access model checkpoints from training
+//! - **Wait for Completion**: Poll a job until it finishes while streaming its events
 //!
 //! # Quick Start
 //!
@@ -33,19 +34,27 @@
 //! ```
 
 use crate::common::auth::AuthProvider;
-use crate::common::client::create_http_client;
-use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
+use crate::common::metadata;
 use crate::common::models::FineTuningModel;
 use crate::fine_tuning::response::{
-    DpoConfig, FineTuningCheckpointListResponse, FineTuningEventListResponse, FineTuningJob, FineTuningJobListResponse, Hyperparameters, Integration,
-    MethodConfig, SupervisedConfig,
+    DpoConfig, FineTuningCheckpointListResponse, FineTuningEvent, FineTuningEventListResponse, FineTuningJob, FineTuningJobListResponse,
+    Hyperparameters, Integration, MethodConfig, SupervisedConfig,
 };
 use serde::Serialize;
-use std::time::Duration;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 /// Default API path for Fine-tuning
 const FINE_TUNING_PATH: &str = "fine_tuning/jobs";
 
+/// Upper bound for the backoff interval used by [`FineTuning::wait_until_complete`]
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Number of events fetched per poll by [`FineTuning::wait_until_complete`]
+const EVENTS_PER_POLL: u32 = 100;
+
 /// Request to create a new fine-tuning job.
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateFineTuningJobRequest {
@@ -74,6 +83,10 @@ pub struct CreateFineTuningJobRequest {
     /// Integrations to enable (e.g., Weights & Biases).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub integrations: Option<Vec<Integration>>,
+
+    /// Key-value pairs to attach to the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 impl CreateFineTuningJobRequest {
@@ -96,7 +109,16 @@ impl CreateFineTuningJobRequest {
     /// );
     /// ```
     pub fn new(model: FineTuningModel, training_file: impl Into<String>) -> Self {
-        Self { model, training_file: training_file.into(), validation_file: None, suffix: None, seed: None, method: None, integrations: None }
+        Self {
+            model,
+            training_file: training_file.into(),
+            validation_file: None,
+            suffix: None,
+            seed: None,
+            method: None,
+            integrations: None,
+            metadata: None,
+        }
     }
 
     /// Sets the validation file for the job.
@@ -134,6 +156,15 @@ impl CreateFineTuningJobRequest {
         self.integrations = Some(integrations);
         self
     }
+
+    /// Sets the metadata for the job.
+    ///
+    /// Accepts a [`Metadata`](crate::common::metadata::Metadata) or a `HashMap`;
+    /// the API limits are checked by [`FineTuning::create`].
+    pub fn with_metadata(mut self, metadata: impl Into<HashMap<String, String>>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
 }
 
 /// Client for interacting with the OpenAI Fine-tuning API.
@@ -177,6 +208,8 @@ pub struct FineTuning {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl FineTuning {
@@ -200,36 +233,36 @@ impl FineTuning {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new FineTuning client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new FineTuning client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new FineTuning client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new FineTuning client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new FineTuning client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -239,6 +272,12 @@ impl FineTuning {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -252,8 +291,8 @@ impl FineTuning {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
@@ -294,19 +333,26 @@ impl FineTuning {
     /// }
     /// ```
     pub async fn create(&self, request: CreateFineTuningJobRequest) -> Result<FineTuningJob> {
+        request.metadata.as_ref().map(metadata::validate_map).transpose()?;
         let (client, headers) = self.create_client()?;
 
         let body = serde_json::to_string(&request).map_err(OpenAIToolError::SerdeJsonError)?;
 
         let url = self.auth.endpoint(FINE_TUNING_PATH);
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<FineTuningJob>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
@@ -342,17 +388,98 @@ impl FineTuning {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}", self.auth.endpoint(FINE_TUNING_PATH), job_id);
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<FineTuningJob>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
+    /// Waits until a fine-tuning job finishes, polling its status periodically.
+    ///
+    /// The job is polled with `retrieve` until its status is `Succeeded`, `Failed`,
+    /// or `Cancelled`. Between polls, newly created job events are passed to
+    /// `on_event` in chronological order. The interval between polls starts at
+    /// `poll_interval` and backs off (up to 5 minutes) while no new events arrive,
+    /// resetting whenever the job reports progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job to wait for
+    /// * `poll_interval` - The initial interval between status checks
+    /// * `timeout` - The maximum total time to wait
+    /// * `on_event` - Callback invoked for each new job event
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FineTuningJob)` - The job in its final state
+    /// * `Err(OpenAIToolError)` - If a request fails or the timeout elapses
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use openai_tools::fine_tuning::request::FineTuning;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let fine_tuning = FineTuning::new()?;
+    ///
+    ///     let job = fine_tuning
+    ///         .wait_until_complete("ftjob-abc123", Duration::from_secs(30), Duration::from_secs(6 * 60 * 60), |event| {
+    ///             println!("[{}] {}", event.level, event.message);
+    ///         })
+    ///         .await?;
+    ///
+    ///     println!("Final status: {:?}", job.status);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_until_complete<F>(&self, job_id: &str, poll_interval: Duration, timeout: Duration, mut on_event: F) -> Result<FineTuningJob>
+    where
+        F: FnMut(&FineTuningEvent),
+    {
+        let started = Instant::now();
+        let mut seen_events = HashSet::new();
+        let mut interval = poll_interval;
+
+        loop {
+            let job = self.retrieve(job_id).await?;
+
+            // Events are returned newest first
+            let events = self.list_events(job_id, Some(EVENTS_PER_POLL), None).await?;
+            let new_events: Vec<FineTuningEvent> = events.data.into_iter().filter(|event| seen_events.insert(event.id.clone())).collect();
+            for event in new_events.iter().rev() {
+                on_event(event);
+            }
+
+            if job.status.is_terminal() {
+                return Ok(job);
+            }
+
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(OpenAIToolError::Error(format!(
+                    "Timed out after {:?} waiting for fine-tuning job {} (status: {:?})",
+                    timeout, job_id, job.status
+                )));
+            }
+
+            interval = next_poll_interval(interval, poll_interval, !new_events.is_empty());
+            tokio::time::sleep(interval.min(timeout - elapsed)).await;
+        }
+    }
+
     /// Cancels an in-progress fine-tuning job.
     ///
     /// # Arguments
@@ -382,14 +509,20 @@ impl FineTuning {
         let (client, headers) = self.create_client()?;
         let url = format!("{}/{}/cancel", self.auth.endpoint(FINE_TUNING_PATH), job_id);
 
-        let response = client.post(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<FineTuningJob>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
@@ -442,14 +575,20 @@ impl FineTuning {
             url.push_str(&params.join("&"));
         }
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<FineTuningJobListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
@@ -503,14 +642,20 @@ impl FineTuning {
             url.push_str(&params.join("&"));
         }
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<FineTuningEventListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
@@ -565,14 +710,32 @@ impl FineTuning {
             url.push_str(&params.join("&"));
         }
 
-        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.get(&url).headers(headers).send().await.map_err(OpenAIToolError::from)?;
 
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
+        if !status.is_success() {
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
         serde_json::from_str::<FineTuningCheckpointListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 }
+
+/// Computes the next polling interval for [`FineTuning::wait_until_complete`].
+///
+/// Resets to `initial` when the job made progress, otherwise grows by 1.5x up to
+/// [`MAX_POLL_INTERVAL`] (or `initial`, if that is larger).
+pub(crate) fn next_poll_interval(current: Duration, initial: Duration, progressed: bool) -> Duration {
+    if progressed {
+        initial
+    } else {
+        current.mul_f64(1.5).min(MAX_POLL_INTERVAL.max(initial))
+    }
+}
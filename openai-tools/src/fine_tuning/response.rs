@@ -2,7 +2,9 @@
 //!
 //! This module defines the response types for the OpenAI Fine-tuning API.
 
+use crate::common::pagination::{impl_paginated, Page};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// The status of a fine-tuning job.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -22,6 +24,13 @@ pub enum FineTuningJobStatus {
     Cancelled,
 }
 
+impl FineTuningJobStatus {
+    /// Returns `true` if the job has finished (`Succeeded`, `Failed`, or `Cancelled`).
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
 /// Hyperparameters used for fine-tuning.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Hyperparameters {
@@ -155,6 +164,10 @@ pub struct FineTuningJob {
 
     /// User-provided suffix for the model name.
     pub user_provided_suffix: Option<String>,
+
+    /// Key-value pairs attached to the job.
+    #[serde(default)]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 /// Response for listing fine-tuning jobs.
@@ -170,6 +183,16 @@ pub struct FineTuningJobListResponse {
     pub has_more: bool,
 }
 
+impl From<FineTuningJobListResponse> for Page<FineTuningJob> {
+    fn from(response: FineTuningJobListResponse) -> Self {
+        let first_id = response.data.first().map(|item| item.id.clone());
+        let last_id = response.data.last().map(|item| item.id.clone());
+        Page { data: response.data, first_id, last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(FineTuningJobListResponse, FineTuningJob, last_id = data);
+
 /// A fine-tuning event object.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FineTuningEvent {
@@ -209,6 +232,16 @@ pub struct FineTuningEventListResponse {
     pub has_more: bool,
 }
 
+impl From<FineTuningEventListResponse> for Page<FineTuningEvent> {
+    fn from(response: FineTuningEventListResponse) -> Self {
+        let first_id = response.data.first().map(|item| item.id.clone());
+        let last_id = response.data.last().map(|item| item.id.clone());
+        Page { data: response.data, first_id, last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(FineTuningEventListResponse, FineTuningEvent, last_id = data);
+
 /// Metrics for a fine-tuning checkpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckpointMetrics {
@@ -277,3 +310,11 @@ pub struct FineTuningCheckpointListResponse {
     /// Whether there are more checkpoints to retrieve.
     pub has_more: bool,
 }
+
+impl From<FineTuningCheckpointListResponse> for Page<FineTuningCheckpoint> {
+    fn from(response: FineTuningCheckpointListResponse) -> Self {
+        Page { data: response.data, first_id: response.first_id, last_id: response.last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(FineTuningCheckpointListResponse, FineTuningCheckpoint);
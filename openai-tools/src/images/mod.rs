@@ -8,6 +8,7 @@
 //! - **Image Generation**: Create images from text prompts
 //! - **Image Editing**: Modify existing images with masks and prompts
 //! - **Image Variations**: Create variations of existing images
+//! - **Streaming**: Receive progressive partial renders from gpt-image-1 (see [`stream`])
 //! - **Multiple Models**: Support for DALL-E 2, DALL-E 3, and GPT Image models
 //!
 //! ## Quick Start
@@ -162,10 +163,15 @@
 
 pub mod request;
 pub mod response;
+pub mod stream;
 
 #[cfg(test)]
 mod tests {
-    use crate::images::request::{ImageModel, ImageQuality, ImageSize, ImageStyle, ResponseFormat};
+    use crate::common::errors::OpenAIToolError;
+    use crate::images::request::{
+        GenerateOptions, GenerateRequest, ImageBackground, ImageModel, ImageModeration, ImageOutputFormat, ImageQuality, ImageSize, ImageStyle,
+        ResponseFormat,
+    };
     use crate::images::response::ImageResponse;
 
     #[test]
@@ -276,4 +282,228 @@ mod tests {
         let response: ImageResponse = serde_json::from_str(json).expect("Should deserialize multiple images");
         assert_eq!(response.data.len(), 3);
     }
+
+    fn gpt_image_options(model: ImageModel) -> GenerateOptions {
+        GenerateOptions {
+            model: Some(model),
+            background: Some(ImageBackground::Transparent),
+            output_format: Some(ImageOutputFormat::Webp),
+            output_compression: Some(80),
+            moderation: Some(ImageModeration::Low),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_request_gpt_image_options() {
+        let body = serde_json::to_value(GenerateRequest::new("A logo", gpt_image_options(ImageModel::GptImage1))).unwrap();
+        assert_eq!(body["model"], "gpt-image-1");
+        assert_eq!(body["background"], "transparent");
+        assert_eq!(body["output_format"], "webp");
+        assert_eq!(body["output_compression"], 80);
+        assert_eq!(body["moderation"], "low");
+    }
+
+    #[test]
+    fn test_generate_request_drops_gpt_image_options_for_dalle() {
+        let body = serde_json::to_value(GenerateRequest::new("A logo", gpt_image_options(ImageModel::DallE3))).unwrap();
+        assert_eq!(body["model"], "dall-e-3");
+        for field in ["background", "output_format", "output_compression", "moderation"] {
+            assert!(body.get(field).is_none(), "{} should not be serialized for dall-e-3", field);
+        }
+
+        // The default model is DALL-E 3 as well
+        let options = GenerateOptions { background: Some(ImageBackground::Opaque), ..Default::default() };
+        let body = serde_json::to_value(GenerateRequest::new("A logo", options)).unwrap();
+        assert!(body.get("background").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_save_all_decodes_b64_images() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0];
+        let json = format!(r#"{{"created": 1, "data": [{{"b64_json": "{}"}}, {{"b64_json": "{}"}}]}}"#, STANDARD.encode(png), STANDARD.encode(jpeg));
+        let response: ImageResponse = serde_json::from_str(&json).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("openai-tools-images-b64-{}", std::process::id()));
+        let paths = response.save_all(&dir, "cat").await.unwrap();
+
+        assert_eq!(paths, vec![dir.join("cat_0.png"), dir.join("cat_1.jpg")]);
+        assert_eq!(std::fs::read(&paths[0]).unwrap(), png);
+        assert_eq!(std::fs::read(&paths[1]).unwrap(), jpeg);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_all_downloads_url_images() {
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, "image-bytes".to_string()), (403, "expired".to_string())]).await;
+        let json = format!(r#"{{"created": 1, "data": [{{"url": "{0}/img/0"}}, {{"url": "{0}/img/1"}}]}}"#, base_url);
+        let response: ImageResponse = serde_json::from_str(&json).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("openai-tools-images-url-{}", std::process::id()));
+        let err = response.save_all(&dir, "dog").await.unwrap_err();
+        assert!(err.to_string().contains("403"));
+        assert_eq!(std::fs::read(dir.join("dog_0.png")).unwrap(), b"image-bytes");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /v1/img/0 HTTP/1.1");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_generate_validation_rejects_long_prompts() {
+        let dalle2 = GenerateOptions { model: Some(ImageModel::DallE2), ..Default::default() };
+        assert!(GenerateRequest::validate(&"a".repeat(1000), &dalle2).is_ok());
+        let err = GenerateRequest::validate(&"a".repeat(1001), &dalle2).unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)));
+        assert!(err.to_string().contains("dall-e-2 accepts at most 1000 characters"));
+
+        // The default model is DALL-E 3
+        assert!(GenerateRequest::validate(&"a".repeat(4000), &GenerateOptions::default()).is_ok());
+        assert!(GenerateRequest::validate(&"a".repeat(4001), &GenerateOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_generate_validation_rejects_multiple_images_for_dalle3() {
+        let dalle3 = GenerateOptions { model: Some(ImageModel::DallE3), n: Some(2), ..Default::default() };
+        let err = GenerateRequest::validate("A cat", &dalle3).unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)));
+
+        let dalle2 = GenerateOptions { model: Some(ImageModel::DallE2), n: Some(2), ..Default::default() };
+        assert!(GenerateRequest::validate("A cat", &dalle2).is_ok());
+    }
+
+    #[test]
+    fn test_edit_validation_allows_multiple_images_only_for_gpt_image_1() {
+        use crate::images::request::{validate_edit_images, EditOptions, MAX_EDIT_IMAGES};
+
+        let gpt_image = EditOptions { model: Some(ImageModel::GptImage1), ..Default::default() };
+        assert!(validate_edit_images(1, &gpt_image).is_ok());
+        assert!(validate_edit_images(MAX_EDIT_IMAGES, &gpt_image).is_ok());
+        assert!(validate_edit_images(MAX_EDIT_IMAGES + 1, &gpt_image).is_err());
+        assert!(validate_edit_images(0, &gpt_image).is_err());
+
+        let dalle2 = EditOptions { model: Some(ImageModel::DallE2), ..Default::default() };
+        assert!(validate_edit_images(1, &dalle2).is_ok());
+        let err = validate_edit_images(2, &dalle2).unwrap_err();
+        assert!(matches!(err, OpenAIToolError::InvalidParameter(_)));
+        assert!(err.to_string().contains("requires gpt-image-1"), "{}", err);
+
+        // Without a model, the API edits with DALL-E 2
+        let err = validate_edit_images(2, &EditOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("dall-e-2 accepts a single input image"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_edit_multi_sends_image_array_parts() {
+        use crate::images::request::{EditOptions, Images};
+
+        let dir = std::env::temp_dir().join(format!("openai-tools-images-edit-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let product = dir.join("product.png");
+        let scene = dir.join("scene.jpg");
+        std::fs::write(&product, "product-bytes").unwrap();
+        std::fs::write(&scene, "scene-bytes").unwrap();
+
+        let body = r#"{"created": 1, "data": [{"b64_json": "aGVsbG8="}]}"#.to_string();
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, body.clone()), (200, body)]).await;
+        let images = Images::with_url(base_url, "sk-test".to_string());
+
+        let options = EditOptions { model: Some(ImageModel::GptImage1), ..Default::default() };
+        let paths = [product.to_str().unwrap(), scene.to_str().unwrap()];
+        let response = images.edit_multi(&paths, "Put the product in the scene", options).await.unwrap();
+        assert_eq!(response.data.len(), 1);
+
+        // A single image keeps the plain `image` field
+        images.edit(paths[0], "Add a shadow", EditOptions::default()).await.unwrap();
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /v1/images/edits HTTP/1.1");
+        let multi = &requests[0].body;
+        assert_eq!(multi.matches(r#"name="image[]""#).count(), 2);
+        assert!(multi.contains("product-bytes") && multi.contains("scene-bytes"));
+        assert!(multi.contains("Content-Type: image/jpeg"));
+        assert!(multi.contains("gpt-image-1"));
+
+        let single = &requests[1].body;
+        assert!(single.contains(r#"name="image"; filename="product.png""#));
+        assert!(!single.contains("image[]"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_generate_reports_content_policy_violation() {
+        use crate::images::request::Images;
+
+        let body = r#"{"error": {"code": "content_policy_violation", "message": "Your request was rejected as a result of our safety system.", "param": null, "type": "invalid_request_error"}}"#;
+        let (base_url, server) = crate::common::mock_server::serve(vec![(400, body.to_string())]).await;
+        let images = Images::with_url(base_url, "sk-test".to_string());
+
+        let err = images.generate("A forbidden prompt", GenerateOptions::default()).await.unwrap_err();
+        match err {
+            OpenAIToolError::ContentPolicyViolation { message, .. } => assert!(message.contains("safety system")),
+            other => panic!("unexpected error: {:?}", other),
+        }
+        server.await.unwrap();
+    }
+
+    #[test]
+    fn test_streaming_requires_gpt_image_1() {
+        let gpt_image = GenerateOptions { model: Some(ImageModel::GptImage1), ..Default::default() };
+        let request = GenerateRequest::streaming("A fox", gpt_image.clone(), 2).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["stream"], true);
+        assert_eq!(json["partial_images"], 2);
+
+        assert!(GenerateRequest::streaming("A fox", gpt_image, 4).is_err());
+        let err = GenerateRequest::streaming("A fox", GenerateOptions::default(), 1).unwrap_err();
+        assert!(err.to_string().contains("not supported by dall-e-3"), "{}", err);
+
+        // Non-streaming requests do not send the fields
+        let json = serde_json::to_value(GenerateRequest::new("A fox", GenerateOptions::default())).unwrap();
+        assert!(json.get("stream").is_none() && json.get("partial_images").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_generate_stream_decodes_partial_and_final_images() {
+        use crate::images::request::Images;
+        use crate::images::stream::ImageStreamEvent;
+        use futures_util::StreamExt;
+
+        // "cGFydGlhbA==" = "partial", "ZmluYWw=" = "final"
+        let sse = [
+            r#"data: {"type": "image_generation.partial_image", "b64_json": "cGFydGlhbA==", "partial_image_index": 0, "size": "1024x1024", "output_format": "png"}"#,
+            r#"data: {"type": "image_generation.something_new"}"#,
+            r#"data: {"type": "image_generation.completed", "b64_json": "ZmluYWw=", "output_format": "png", "usage": {"input_tokens": 10, "output_tokens": 272, "total_tokens": 282}}"#,
+        ]
+        .map(|event| format!("event: message\r\n{}\r\n\r\n: ping\r\n\r\n", event))
+        .concat()
+            + "data: [DONE]\r\n\r\n";
+        let (base_url, server) = crate::common::mock_server::serve(vec![(200, sse)]).await;
+        let images = Images::with_url(base_url, "sk-test".to_string());
+
+        let options = GenerateOptions { model: Some(ImageModel::GptImage1), ..Default::default() };
+        let events: Vec<_> = images.generate_stream("A fox", options, 1).await.unwrap().collect().await;
+        let events = events.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            ImageStreamEvent::Partial { partial_image_index, image } => {
+                assert_eq!(*partial_image_index, 0);
+                assert_eq!(image.bytes, b"partial");
+                assert_eq!(image.size.as_deref(), Some("1024x1024"));
+            }
+            other => panic!("expected a partial image, got {:?}", other),
+        }
+        assert!(events[1].is_completed());
+        assert_eq!(events[1].image().bytes, b"final");
+        assert!(matches!(&events[1], ImageStreamEvent::Completed { usage: Some(usage), .. } if usage.total_tokens == 282));
+
+        let requests = server.await.unwrap();
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!((body["stream"].clone(), body["partial_images"].clone()), (serde_json::json!(true), serde_json::json!(1)));
+    }
 }
@@ -27,9 +27,10 @@
 //! ```
 
 use crate::common::auth::AuthProvider;
-use crate::common::client::create_http_client;
-use crate::common::errors::{ErrorResponse, OpenAIToolError, Result};
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
 use crate::images::response::ImageResponse;
+use crate::images::stream::{parse_image_event_stream, ImageEventStream};
 use request::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -38,6 +39,12 @@ use std::time::Duration;
 /// Default API path for Images
 const IMAGES_PATH: &str = "images";
 
+/// Maximum number of input images gpt-image-1 accepts in one edit request
+pub const MAX_EDIT_IMAGES: usize = 16;
+
+/// Maximum number of partial images gpt-image-1 streams before the final image
+pub const MAX_PARTIAL_IMAGES: u8 = 3;
+
 /// Image generation models.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum ImageModel {
@@ -62,6 +69,23 @@ impl ImageModel {
             Self::GptImage1 => "gpt-image-1",
         }
     }
+
+    /// Returns the maximum prompt length in characters accepted by the model.
+    pub fn max_prompt_length(&self) -> usize {
+        match self {
+            Self::DallE2 => 1000,
+            Self::DallE3 => 4000,
+            Self::GptImage1 => 32000,
+        }
+    }
+
+    /// Returns the maximum number of images the model can generate per request.
+    pub fn max_images(&self) -> u32 {
+        match self {
+            Self::DallE3 => 1,
+            Self::DallE2 | Self::GptImage1 => 10,
+        }
+    }
 }
 
 impl std::fmt::Display for ImageModel {
@@ -173,6 +197,75 @@ impl ResponseFormat {
     }
 }
 
+/// Background options for generated images (gpt-image-1 only).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageBackground {
+    /// Let the model choose the background
+    #[default]
+    Auto,
+    /// Transparent background (requires `png` or `webp` output)
+    Transparent,
+    /// Opaque background
+    Opaque,
+}
+
+impl ImageBackground {
+    /// Returns the background string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Transparent => "transparent",
+            Self::Opaque => "opaque",
+        }
+    }
+}
+
+/// Output file formats for generated images (gpt-image-1 only).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageOutputFormat {
+    /// PNG format
+    #[default]
+    Png,
+    /// JPEG format
+    Jpeg,
+    /// WebP format
+    Webp,
+}
+
+impl ImageOutputFormat {
+    /// Returns the format string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+/// Content moderation levels for image generation (gpt-image-1 only).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageModeration {
+    /// Standard filtering
+    #[default]
+    Auto,
+    /// Less restrictive filtering
+    Low,
+}
+
+impl ImageModeration {
+    /// Returns the moderation string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Low => "low",
+        }
+    }
+}
+
 /// Options for image generation.
 #[derive(Debug, Clone, Default)]
 pub struct GenerateOptions {
@@ -190,6 +283,14 @@ pub struct GenerateOptions {
     pub style: Option<ImageStyle>,
     /// User identifier for abuse monitoring
     pub user: Option<String>,
+    /// Background transparency (gpt-image-1 only)
+    pub background: Option<ImageBackground>,
+    /// Output file format (gpt-image-1 only)
+    pub output_format: Option<ImageOutputFormat>,
+    /// Compression level 0-100 for `jpeg` and `webp` output (gpt-image-1 only)
+    pub output_compression: Option<u8>,
+    /// Content moderation level (gpt-image-1 only)
+    pub moderation: Option<ImageModeration>,
 }
 
 /// Options for image editing.
@@ -197,7 +298,8 @@ pub struct GenerateOptions {
 pub struct EditOptions {
     /// Path to the mask image (transparent areas will be edited)
     pub mask: Option<String>,
-    /// The model to use (only DALL-E 2 supports editing)
+    /// The model to use (DALL-E 2 or gpt-image-1, defaults to DALL-E 2).
+    /// Only gpt-image-1 accepts multiple input images.
     pub model: Option<ImageModel>,
     /// Number of images to generate (1-10)
     pub n: Option<u32>,
@@ -224,9 +326,37 @@ pub struct VariationOptions {
     pub user: Option<String>,
 }
 
+/// Checks the number of input images of an edit request against the selected model.
+pub(crate) fn validate_edit_images(count: usize, options: &EditOptions) -> Result<()> {
+    if count == 0 {
+        return Err(OpenAIToolError::InvalidParameter("At least one image is required for an edit".into()));
+    }
+    match options.model {
+        Some(ImageModel::GptImage1) if count > MAX_EDIT_IMAGES => {
+            Err(OpenAIToolError::InvalidParameter(format!("{} input images were given, but gpt-image-1 accepts at most {}", count, MAX_EDIT_IMAGES)))
+        }
+        Some(ImageModel::GptImage1) => Ok(()),
+        model if count > 1 => Err(OpenAIToolError::InvalidParameter(format!(
+            "{} accepts a single input image; editing with {} images requires gpt-image-1",
+            model.map_or("dall-e-2", |model| model.as_str()),
+            count
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the MIME type of an input image from its file extension, defaulting to PNG.
+fn image_mime_type(path: &str) -> &'static str {
+    match Path::new(path).extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_ascii_lowercase()).as_deref() {
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        _ => "image/png",
+    }
+}
+
 /// Request payload for image generation.
 #[derive(Debug, Clone, Serialize)]
-struct GenerateRequest {
+pub(crate) struct GenerateRequest {
     prompt: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     model: Option<String>,
@@ -242,6 +372,101 @@ struct GenerateRequest {
     style: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_compression: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moderation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partial_images: Option<u8>,
+}
+
+impl GenerateRequest {
+    /// Checks the prompt length and image count against the limits of the selected model.
+    pub(crate) fn validate(prompt: &str, options: &GenerateOptions) -> Result<()> {
+        let model = options.model.unwrap_or_default();
+        let length = prompt.chars().count();
+        if length > model.max_prompt_length() {
+            return Err(OpenAIToolError::InvalidParameter(format!(
+                "Prompt is {} characters long, but {} accepts at most {} characters",
+                length,
+                model,
+                model.max_prompt_length()
+            )));
+        }
+        if let Some(n) = options.n {
+            if n == 0 || n > model.max_images() {
+                return Err(OpenAIToolError::InvalidParameter(format!(
+                    "n = {} is not supported by {}; it must be between 1 and {}",
+                    n,
+                    model,
+                    model.max_images()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the request payload, dropping gpt-image-1 options for other models.
+    pub(crate) fn new(prompt: &str, options: GenerateOptions) -> Self {
+        let mut request = Self {
+            prompt: prompt.to_string(),
+            model: options.model.map(|m| m.as_str().to_string()),
+            n: options.n,
+            quality: options.quality.map(|q| q.as_str().to_string()),
+            response_format: options.response_format.map(|f| f.as_str().to_string()),
+            size: options.size.map(|s| s.as_str().to_string()),
+            style: options.style.map(|s| s.as_str().to_string()),
+            user: options.user,
+            background: options.background.map(|b| b.as_str().to_string()),
+            output_format: options.output_format.map(|f| f.as_str().to_string()),
+            output_compression: options.output_compression,
+            moderation: options.moderation.map(|m| m.as_str().to_string()),
+            stream: None,
+            partial_images: None,
+        };
+
+        let model = options.model.unwrap_or_default();
+        if model != ImageModel::GptImage1
+            && (request.background.is_some()
+                || request.output_format.is_some()
+                || request.output_compression.is_some()
+                || request.moderation.is_some())
+        {
+            tracing::warn!("Model '{}' does not support background, output_format, output_compression or moderation. Ignoring them.", model);
+            request.background = None;
+            request.output_format = None;
+            request.output_compression = None;
+            request.moderation = None;
+        }
+        request
+    }
+
+    /// Checks a streaming request and builds its payload.
+    ///
+    /// Streaming is only supported by gpt-image-1, with at most [`MAX_PARTIAL_IMAGES`] partial images.
+    pub(crate) fn streaming(prompt: &str, options: GenerateOptions, partial_images: u8) -> Result<Self> {
+        Self::validate(prompt, &options)?;
+        let model = options.model.unwrap_or_default();
+        if model != ImageModel::GptImage1 {
+            return Err(OpenAIToolError::InvalidParameter(format!("Streaming is not supported by {}; use gpt-image-1", model)));
+        }
+        if partial_images > MAX_PARTIAL_IMAGES {
+            return Err(OpenAIToolError::InvalidParameter(format!(
+                "partial_images = {} is not supported; it must be between 0 and {}",
+                partial_images, MAX_PARTIAL_IMAGES
+            )));
+        }
+        let mut request = Self::new(prompt, options);
+        request.stream = Some(true);
+        request.partial_images = Some(partial_images);
+        Ok(request)
+    }
 }
 
 /// Client for interacting with the OpenAI Images API.
@@ -275,6 +500,8 @@ pub struct Images {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl Images {
@@ -298,36 +525,36 @@ impl Images {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Images client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Images client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Images client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Images client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Images client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -337,6 +564,12 @@ impl Images {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -350,8 +583,8 @@ impl Images {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("User-Agent", request::header::HeaderValue::from_static("openai-tools-rust"));
@@ -370,6 +603,8 @@ impl Images {
     /// # Returns
     ///
     /// * `Ok(ImageResponse)` - The generated image(s)
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the prompt is too long or `n` is
+    ///   out of range for the model; no request is sent
     /// * `Err(OpenAIToolError)` - If the request fails
     ///
     /// # Example
@@ -397,47 +632,109 @@ impl Images {
     /// }
     /// ```
     pub async fn generate(&self, prompt: &str, options: GenerateOptions) -> Result<ImageResponse> {
+        GenerateRequest::validate(prompt, &options)?;
+
         let (client, mut headers) = self.create_client()?;
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
 
-        let request_body = GenerateRequest {
-            prompt: prompt.to_string(),
-            model: options.model.map(|m| m.as_str().to_string()),
-            n: options.n,
-            quality: options.quality.map(|q| q.as_str().to_string()),
-            response_format: options.response_format.map(|f| f.as_str().to_string()),
-            size: options.size.map(|s| s.as_str().to_string()),
-            style: options.style.map(|s| s.as_str().to_string()),
-            user: options.user,
-        };
+        let request_body = GenerateRequest::new(prompt, options);
 
         let body = serde_json::to_string(&request_body).map_err(OpenAIToolError::SerdeJsonError)?;
 
         let url = format!("{}/generations", self.auth.endpoint(IMAGES_PATH));
 
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ImageResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
+    /// Generates an image with gpt-image-1, streaming partial renders as they are produced.
+    ///
+    /// The stream yields up to `partial_images` [`ImageStreamEvent::Partial`]
+    /// events, each a progressively refined render, followed by one
+    /// [`ImageStreamEvent::Completed`] event with the final image. The base64
+    /// image data of each event is decoded into bytes. With `partial_images = 0`,
+    /// only the final image is sent. Fewer partials may be sent if the final
+    /// image is ready sooner.
+    ///
+    /// [`ImageStreamEvent::Partial`]: crate::images::stream::ImageStreamEvent::Partial
+    /// [`ImageStreamEvent::Completed`]: crate::images::stream::ImageStreamEvent::Completed
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt` - Text description of the desired image
+    /// * `options` - Generation options; `model` must be gpt-image-1 and `n` 1
+    /// * `partial_images` - Number of partial images to stream (0 to [`MAX_PARTIAL_IMAGES`])
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ImageEventStream)` - The stream of events
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If the model is not gpt-image-1, the
+    ///   prompt is too long or `partial_images` is out of range; no request is sent
+    /// * `Err(OpenAIToolError)` - If the request fails or the API returns an error status
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use openai_tools::images::request::{GenerateOptions, ImageModel, Images};
+    /// use openai_tools::images::stream::ImageStreamEvent;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let images = Images::new()?;
+    ///     let options = GenerateOptions { model: Some(ImageModel::GptImage1), ..Default::default() };
+    ///
+    ///     let mut events = images.generate_stream("A watercolor fox", options, 2).await?;
+    ///     while let Some(event) = events.next().await {
+    ///         match event? {
+    ///             ImageStreamEvent::Partial { partial_image_index, image } => {
+    ///                 std::fs::write(format!("fox_partial_{}.png", partial_image_index), &image.bytes)?;
+    ///             }
+    ///             ImageStreamEvent::Completed { image, .. } => std::fs::write("fox.png", &image.bytes)?,
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn generate_stream(&self, prompt: &str, options: GenerateOptions, partial_images: u8) -> Result<ImageEventStream> {
+        let request_body = GenerateRequest::streaming(prompt, options, partial_images)?;
+        let body = serde_json::to_string(&request_body).map_err(OpenAIToolError::SerdeJsonError)?;
+
+        let (client, mut headers) = self.create_client()?;
+        headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
+        headers.insert("Accept", request::header::HeaderValue::from_static("text/event-stream"));
+
+        let url = format!("{}/generations", self.auth.endpoint(IMAGES_PATH));
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
+
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        if !status.is_success() {
+            let content = response.text().await.map_err(OpenAIToolError::from)?;
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
+        }
+
+        Ok(Box::pin(parse_image_event_stream(Box::pin(response.bytes_stream()))))
+    }
+
     /// Edits an existing image based on a prompt.
     ///
     /// Creates edited versions of an image by replacing areas indicated by
-    /// a transparent mask. Only available with DALL-E 2.
+    /// a transparent mask. Available with DALL-E 2 and gpt-image-1; to
+    /// combine several input images, use [`edit_multi`](Self::edit_multi).
     ///
     /// # Arguments
     ///
@@ -471,19 +768,67 @@ impl Images {
     /// }
     /// ```
     pub async fn edit(&self, image_path: &str, prompt: &str, options: EditOptions) -> Result<ImageResponse> {
+        self.edit_multi(&[image_path], prompt, options).await
+    }
+
+    /// Edits or composes several input images based on a prompt.
+    ///
+    /// gpt-image-1 uses all input images as references, e.g. to place a
+    /// product photo into a scene. The images are sent as `image[]` parts;
+    /// a single image is sent like [`edit`](Self::edit) does. PNG, JPEG and
+    /// WebP files are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_paths` - Paths to the input images (at most [`MAX_EDIT_IMAGES`])
+    /// * `prompt` - Text description of the desired result
+    /// * `options` - Edit options; `model` must be gpt-image-1 for more than one image
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ImageResponse)` - The edited image(s)
+    /// * `Err(OpenAIToolError::InvalidParameter)` - If no image is given, or several
+    ///   images are given for a model other than gpt-image-1; no request is sent
+    /// * `Err(OpenAIToolError)` - If an image cannot be read or the request fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::images::request::{EditOptions, ImageModel, Images};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let images = Images::new()?;
+    ///
+    ///     let options = EditOptions { model: Some(ImageModel::GptImage1), ..Default::default() };
+    ///     let response = images.edit_multi(&["product.png", "living_room.jpg"], "Place the lamp on the side table", options).await?;
+    ///     response.save_all(std::path::Path::new("output"), "composite").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn edit_multi<S: AsRef<str>>(&self, image_paths: &[S], prompt: &str, options: EditOptions) -> Result<ImageResponse> {
+        validate_edit_images(image_paths.len(), &options)?;
+
         let (client, headers) = self.create_client()?;
 
-        // Read the image file
-        let image_content = tokio::fs::read(image_path).await.map_err(|e| OpenAIToolError::Error(format!("Failed to read image: {}", e)))?;
+        let field = if image_paths.len() == 1 { "image" } else { "image[]" };
+        let mut form = Form::new();
+        for image_path in image_paths {
+            let image_path = image_path.as_ref();
+            let image_content =
+                tokio::fs::read(image_path).await.map_err(|e| OpenAIToolError::Error(format!("Failed to read image {}: {}", image_path, e)))?;
 
-        let image_filename = Path::new(image_path).file_name().and_then(|n| n.to_str()).unwrap_or("image.png").to_string();
+            let image_filename = Path::new(image_path).file_name().and_then(|n| n.to_str()).unwrap_or("image.png").to_string();
 
-        let image_part = Part::bytes(image_content)
-            .file_name(image_filename)
-            .mime_str("image/png")
-            .map_err(|e| OpenAIToolError::Error(format!("Failed to set MIME type: {}", e)))?;
+            let image_part = Part::bytes(image_content)
+                .file_name(image_filename)
+                .mime_str(image_mime_type(image_path))
+                .map_err(|e| OpenAIToolError::Error(format!("Failed to set MIME type: {}", e)))?;
 
-        let mut form = Form::new().part("image", image_part).text("prompt", prompt.to_string());
+            form = form.part(field, image_part);
+        }
+        form = form.text("prompt", prompt.to_string());
 
         // Add mask if provided
         if let Some(mask_path) = options.mask {
@@ -518,20 +863,18 @@ impl Images {
 
         let url = format!("{}/edits", self.auth.endpoint(IMAGES_PATH));
 
-        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ImageResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -609,20 +952,18 @@ impl Images {
 
         let url = format!("{}/variations", self.auth.endpoint(IMAGES_PATH));
 
-        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).multipart(form).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ImageResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -2,7 +2,9 @@
 //!
 //! This module defines the response structures for the OpenAI Images API.
 
+use crate::common::errors::{OpenAIToolError, Result};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 /// Response structure from image generation/edit/variation endpoints.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +15,69 @@ pub struct ImageResponse {
     pub data: Vec<ImageData>,
 }
 
+impl ImageResponse {
+    /// Saves every generated image to `dir` as `{prefix}_0.png`, `{prefix}_1.png`, ...
+    ///
+    /// Base64 images are decoded directly; URL images are downloaded. The file
+    /// extension follows the image bytes, so JPEG and WebP output from `gpt-image-1`
+    /// is saved as `.jpg` and `.webp`. `dir` is created if it does not exist.
+    ///
+    /// Image URLs are only valid for 60 minutes after generation, so URL
+    /// responses must be saved within that window.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory to write the images into
+    /// * `prefix` - File name prefix; the image index is appended to it
+    ///
+    /// # Returns
+    ///
+    /// The paths of the written files, in the order of `data`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::images::request::{GenerateOptions, ImageModel, Images};
+    /// use std::path::Path;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let images = Images::new()?;
+    /// let options = GenerateOptions { model: Some(ImageModel::DallE2), n: Some(3), ..Default::default() };
+    /// let response = images.generate("A lighthouse at dawn", options).await?;
+    ///
+    /// for path in response.save_all(Path::new("out"), "lighthouse").await? {
+    ///     println!("Saved {}", path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn save_all(&self, dir: &Path, prefix: &str) -> Result<Vec<PathBuf>> {
+        tokio::fs::create_dir_all(dir).await.map_err(|e| OpenAIToolError::Error(format!("Failed to create {}: {}", dir.display(), e)))?;
+
+        let client = request::Client::new();
+        let mut paths = Vec::with_capacity(self.data.len());
+        for (index, image) in self.data.iter().enumerate() {
+            let bytes = image.fetch_bytes(&client).await?;
+            let path = dir.join(format!("{}_{}.{}", prefix, index, image_extension(&bytes)));
+            tokio::fs::write(&path, &bytes).await.map_err(|e| OpenAIToolError::Error(format!("Failed to write {}: {}", path.display(), e)))?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Returns the file extension matching the magic bytes of an image, defaulting to `png`.
+fn image_extension(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "jpg"
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        "webp"
+    } else {
+        "png"
+    }
+}
+
 /// Individual image data from generation response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageData {
@@ -56,6 +121,23 @@ impl ImageData {
         self.b64_json.as_ref().map(|b64| STANDARD.decode(b64))
     }
 
+    /// Returns the image bytes, decoding base64 data or downloading the URL.
+    async fn fetch_bytes(&self, client: &request::Client) -> Result<Vec<u8>> {
+        if let Some(decoded) = self.as_bytes() {
+            return decoded.map_err(|e| OpenAIToolError::Error(format!("Failed to decode image: {}", e)));
+        }
+        let Some(url) = &self.url else {
+            return Err(OpenAIToolError::Error("Image data has neither b64_json nor url".to_string()));
+        };
+        let response = client.get(url).send().await.map_err(OpenAIToolError::from)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(OpenAIToolError::Error(format!("Failed to download image ({}): the URL may have expired", status)));
+        }
+        let bytes = response.bytes().await.map_err(OpenAIToolError::from)?;
+        Ok(bytes.to_vec())
+    }
+
     /// Returns true if this image data contains a URL.
     pub fn has_url(&self) -> bool {
         self.url.is_some()
@@ -0,0 +1,164 @@
+//! Streaming support for gpt-image-1 image generation.
+//!
+//! With `stream: true`, gpt-image-1 sends server-sent events carrying partial
+//! renders of the image while it is generated, followed by the final image.
+//! [`Images::generate_stream`] returns these as [`ImageStreamEvent`]s with the
+//! base64 image data already decoded.
+//!
+//! [`Images::generate_stream`]: crate::images::request::Images::generate_stream
+
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::responses::stream::sse_data_stream;
+use base64::prelude::*;
+use bytes::Bytes;
+use futures_util::stream::{Stream, StreamExt};
+use serde::Deserialize;
+use std::pin::Pin;
+
+/// Stream of events returned by [`Images::generate_stream`].
+///
+/// [`Images::generate_stream`]: crate::images::request::Images::generate_stream
+pub type ImageEventStream = Pin<Box<dyn Stream<Item = Result<ImageStreamEvent>> + Send>>;
+
+/// An image rendered while streaming, with its base64 data decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamedImage {
+    /// The image bytes, in `output_format`
+    pub bytes: Vec<u8>,
+    /// Unix timestamp when the image was created
+    pub created_at: Option<i64>,
+    /// The image size, e.g. "1024x1024"
+    pub size: Option<String>,
+    /// The rendering quality, e.g. "high"
+    pub quality: Option<String>,
+    /// The background, "transparent" or "opaque"
+    pub background: Option<String>,
+    /// The file format of `bytes`, e.g. "png"
+    pub output_format: Option<String>,
+}
+
+/// Token usage reported with the final streamed image.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ImageUsage {
+    /// Number of input tokens (text and images)
+    #[serde(default)]
+    pub input_tokens: u64,
+    /// Number of output (image) tokens
+    #[serde(default)]
+    pub output_tokens: u64,
+    /// Total number of tokens
+    #[serde(default)]
+    pub total_tokens: u64,
+}
+
+/// An event emitted while streaming an image generation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageStreamEvent {
+    /// A progressive render of the image; later partials are more refined.
+    Partial {
+        /// The index of the partial image, starting at 0
+        partial_image_index: u32,
+        /// The partial render
+        image: StreamedImage,
+    },
+    /// The final image; the last event of the stream.
+    Completed {
+        /// The final image
+        image: StreamedImage,
+        /// Token usage of the generation, if reported
+        usage: Option<ImageUsage>,
+    },
+}
+
+impl ImageStreamEvent {
+    /// Returns the image carried by the event.
+    pub fn image(&self) -> &StreamedImage {
+        match self {
+            Self::Partial { image, .. } | Self::Completed { image, .. } => image,
+        }
+    }
+
+    /// Returns `true` for the final image.
+    pub fn is_completed(&self) -> bool {
+        matches!(self, Self::Completed { .. })
+    }
+}
+
+/// An image event as sent by the API, before decoding.
+#[derive(Debug, Deserialize)]
+struct RawImageEvent {
+    #[serde(rename = "type")]
+    type_name: String,
+    #[serde(default)]
+    b64_json: Option<String>,
+    #[serde(default)]
+    partial_image_index: Option<u32>,
+    #[serde(default)]
+    created_at: Option<i64>,
+    #[serde(default)]
+    size: Option<String>,
+    #[serde(default)]
+    quality: Option<String>,
+    #[serde(default)]
+    background: Option<String>,
+    #[serde(default)]
+    output_format: Option<String>,
+    #[serde(default)]
+    usage: Option<ImageUsage>,
+    /// Set on `error` events
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl RawImageEvent {
+    /// Decodes the event, returning `None` for event types not modelled by [`ImageStreamEvent`].
+    fn decode(self) -> Option<Result<ImageStreamEvent>> {
+        if self.type_name == "error" {
+            let message = self
+                .message
+                .or_else(|| self.error.as_ref().and_then(|error| error.get("message")).and_then(|message| message.as_str()).map(str::to_string))
+                .unwrap_or_else(|| "unknown error".to_string());
+            return Some(Err(OpenAIToolError::Error(format!("Image stream error: {}", message))));
+        }
+        // `image_generation.partial_image`, `image_generation.completed`, and the `image_edit.*` equivalents
+        let kind = self.type_name.rsplit('.').next().unwrap_or_default().to_string();
+        if kind != "partial_image" && kind != "completed" {
+            return None;
+        }
+
+        let bytes = match BASE64_STANDARD.decode(self.b64_json.unwrap_or_default()) {
+            Ok(bytes) => bytes,
+            Err(e) => return Some(Err(OpenAIToolError::Error(format!("Failed to decode streamed image: {}", e)))),
+        };
+        let image = StreamedImage {
+            bytes,
+            created_at: self.created_at,
+            size: self.size,
+            quality: self.quality,
+            background: self.background,
+            output_format: self.output_format,
+        };
+        Some(Ok(if kind == "completed" {
+            ImageStreamEvent::Completed { image, usage: self.usage }
+        } else {
+            ImageStreamEvent::Partial { partial_image_index: self.partial_image_index.unwrap_or_default(), image }
+        }))
+    }
+}
+
+/// Converts a streamed response body into a stream of [`ImageStreamEvent`]s.
+///
+/// Events of other types are skipped.
+pub(crate) fn parse_image_event_stream<S>(body: S) -> impl Stream<Item = Result<ImageStreamEvent>>
+where
+    S: Stream<Item = std::result::Result<Bytes, request::Error>> + Unpin,
+{
+    sse_data_stream(body).filter_map(|data| async move {
+        match data.and_then(|data| serde_json::from_str::<RawImageEvent>(&data).map_err(OpenAIToolError::SerdeJsonError)) {
+            Ok(raw) => raw.decode(),
+            Err(e) => Some(Err(e)),
+        }
+    })
+}
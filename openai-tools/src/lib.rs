@@ -105,15 +105,32 @@
 //!         .chat()
 //!         .await?;
 //!
-//!     let person: PersonInfo = serde_json::from_str(
-//!         response.choices[0].message.content.as_ref().unwrap().text.as_ref().unwrap()
-//!     )?;
+//!     let person: PersonInfo = response.parse_content()?;
 //!
 //!     println!("Extracted: {} ({}), {}", person.name, person.age, person.occupation);
 //!     Ok(())
 //! }
 //! ```
 //!
+//! With the `derive` feature, the schema can be generated from the struct
+//! instead, using doc comments as property descriptions:
+//!
+//! ```rust,ignore
+//! use openai_tools::common::structured_output::{JsonSchema, Schema};
+//!
+//! #[derive(Debug, Deserialize, JsonSchema)]
+//! struct PersonInfo {
+//!     /// Person's full name
+//!     name: String,
+//!     /// Person's age
+//!     age: u32,
+//!     /// Person's job
+//!     occupation: String,
+//! }
+//!
+//! let schema = Schema::chat_json_schema_for::<PersonInfo>("person_info");
+//! ```
+//!
 //! ## Function Calling with Tools
 //!
 //! ```rust,no_run
@@ -247,10 +264,12 @@
 //! - [`chat`] - Chat Completions API (`/v1/chat/completions`)
 //!   - [`chat::request`] - `ChatCompletion` builder
 //!   - [`chat::response`] - Response types
+//!   - [`chat::stored`] - `StoredCompletions` client for stored completions
 //!
 //! - [`responses`] - Responses API (`/v1/responses`)
 //!   - [`responses::request`] - `Responses` builder with CRUD operations
 //!   - [`responses::response`] - Response types
+//!   - [`responses::session`] - `ChatSession` for multi-turn conversations
 //!
 //! - [`conversations`] - Conversations API (`/v1/conversations`)
 //!   - [`conversations::request`] - `Conversations` client
@@ -297,8 +316,12 @@
 //!   - [`common::role`] - User roles (User, Assistant, System, Tool)
 //!   - [`common::tool`] - Function calling definitions
 //!   - [`common::auth`] - Authentication (OpenAI, Azure, custom)
+//!   - [`common::openai_client`] - Unified client sharing configuration and connections across APIs
 //!   - [`common::errors`] - Error types
 //!   - [`common::structured_output`] - JSON schema utilities
+//!   - [`common::template`] - Prompt templates with variable substitution
+//!   - [`common::transport`] - Pluggable HTTP transport and `MockTransport` for offline tests
+//!   - [`common::cassette`] - `CassetteTransport` recording and replaying API interactions
 //!
 //! ## Error Handling
 //!
@@ -315,7 +338,20 @@
 //!     Ok(response) => {
 //!         println!("Success: {:?}", response.choices[0].message.content);
 //!     },
-//!     // Network/HTTP errors (connection failed, timeout, etc.)
+//!     // Error responses from the API; quote the request id when contacting support
+//!     Err(OpenAIToolError::ApiError { status, message, request_id, .. }) => {
+//!         eprintln!("API error {} (request id {:?}): {}", status, request_id, message);
+//!     },
+//!     // The prompt was rejected by the safety system; ask the user to rephrase it
+//!     Err(OpenAIToolError::ContentPolicyViolation { message, .. }) => {
+//!         eprintln!("Please try a different prompt: {}", message);
+//!     },
+//!     // The request did not complete within the client's timeout, or the
+//!     // server sent no data for 120s
+//!     Err(OpenAIToolError::Timeout(e)) => {
+//!         eprintln!("Timed out: {}", e);
+//!     },
+//!     // Network/HTTP errors (connection failed, etc.)
 //!     Err(OpenAIToolError::RequestError(e)) => {
 //!         eprintln!("Network error: {}", e);
 //!     },
@@ -331,14 +367,22 @@
 //!     Err(OpenAIToolError::RealtimeError { code, message }) => {
 //!         eprintln!("Realtime error [{}]: {}", code, message);
 //!     },
+//!     // Parameters rejected locally before sending the request
+//!     Err(OpenAIToolError::InvalidParameter(msg)) => {
+//!         eprintln!("Invalid parameter: {}", msg);
+//!     },
+//!     // A required field such as the model or messages was not set
+//!     Err(OpenAIToolError::MissingField(field)) => {
+//!         eprintln!("Missing required field: {}", field);
+//!     },
 //!     // Other errors
 //!     Err(e) => eprintln!("Error: {}", e),
 //! }
 //! # }
 //! ```
 //!
-//! For API errors (rate limits, invalid requests), check the HTTP response status
-//! in `RequestError`.
+//! API errors (rate limits, invalid requests) are returned as `ApiError`, which
+//! carries the HTTP status, the error code and the `x-request-id` of the request.
 //!
 //! ## Provider Configuration
 //!
@@ -433,3 +477,12 @@ pub mod models;
 pub mod moderations;
 pub mod realtime;
 pub mod responses;
+
+// Lets code generated by `openai-tools-derive` refer to `::openai_tools` inside this crate.
+extern crate self as openai_tools;
+
+/// Re-exports used by code generated by `openai-tools-derive`. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
@@ -0,0 +1,104 @@
+//! Static capability metadata for known chat models.
+//!
+//! The Models API only returns a model's ID, creation time and owner. This
+//! module adds the limits and features needed to route requests, such as the
+//! context window size and whether images or tools are accepted. Values are
+//! taken from the model documentation and may change as models are updated.
+
+use crate::common::models::{strip_snapshot_date, ChatModel};
+use serde::{Deserialize, Serialize};
+
+/// Limits and supported features of a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelCapabilities {
+    /// Maximum number of tokens in the context window (input plus output)
+    pub context_window: usize,
+    /// Maximum number of output tokens per response
+    pub max_output: usize,
+    /// Whether image inputs are accepted
+    pub supports_vision: bool,
+    /// Whether function calling is supported
+    pub supports_tools: bool,
+    /// Whether structured outputs with a JSON schema are supported
+    pub supports_json_schema: bool,
+}
+
+impl ModelCapabilities {
+    const fn new(context_window: usize, max_output: usize, supports_vision: bool, supports_tools: bool, supports_json_schema: bool) -> Self {
+        Self { context_window, max_output, supports_vision, supports_tools, supports_json_schema }
+    }
+
+    /// Returns the capabilities of a model, or `None` for custom and unknown models.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::models::ChatModel;
+    /// use openai_tools::models::capabilities::ModelCapabilities;
+    ///
+    /// let capabilities = ModelCapabilities::for_model(&ChatModel::Gpt4oMini).unwrap();
+    /// assert_eq!(capabilities.context_window, 128_000);
+    /// assert!(capabilities.supports_vision);
+    /// ```
+    pub fn for_model(model: &ChatModel) -> Option<Self> {
+        let capabilities = match model {
+            // GPT-5 Series
+            ChatModel::Gpt5_2 | ChatModel::Gpt5_2Pro | ChatModel::Gpt5_1 | ChatModel::Gpt5_1CodexMax | ChatModel::Gpt5Mini | ChatModel::Gpt5Nano => {
+                Self::new(400_000, 128_000, true, true, true)
+            }
+            ChatModel::Gpt5_2ChatLatest | ChatModel::Gpt5_1ChatLatest => Self::new(128_000, 16_384, true, true, true),
+            // GPT-4.1 Series
+            ChatModel::Gpt4_1 | ChatModel::Gpt4_1Mini | ChatModel::Gpt4_1Nano => Self::new(1_047_576, 32_768, true, true, true),
+            // GPT-4o Series
+            ChatModel::Gpt4o | ChatModel::Gpt4oMini => Self::new(128_000, 16_384, true, true, true),
+            ChatModel::Gpt4oAudioPreview => Self::new(128_000, 16_384, false, true, false),
+            // GPT-4 Series
+            ChatModel::Gpt4Turbo => Self::new(128_000, 4_096, true, true, false),
+            ChatModel::Gpt4 => Self::new(8_192, 8_192, false, true, false),
+            // GPT-3.5 Series
+            ChatModel::Gpt3_5Turbo => Self::new(16_385, 4_096, false, true, false),
+            // Reasoning Models
+            ChatModel::O1 | ChatModel::O1Pro | ChatModel::O3 | ChatModel::O4Mini => Self::new(200_000, 100_000, true, true, true),
+            ChatModel::O3Mini => Self::new(200_000, 100_000, false, true, true),
+            // Custom
+            ChatModel::Custom(_) => return None,
+        };
+        Some(capabilities)
+    }
+
+    /// Returns the capabilities of a model by its ID.
+    ///
+    /// Dated snapshots such as `gpt-4o-2024-08-06` resolve to their base model.
+    pub fn for_id(model_id: &str) -> Option<Self> {
+        Self::for_model(&ChatModel::from(model_id)).or_else(|| Self::for_model(&ChatModel::from(strip_snapshot_date(model_id)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_id_known_models() {
+        let gpt41 = ModelCapabilities::for_id("gpt-4.1").unwrap();
+        assert_eq!(gpt41.context_window, 1_047_576);
+        assert_eq!(gpt41.max_output, 32_768);
+
+        let o3_mini = ModelCapabilities::for_id("o3-mini").unwrap();
+        assert!(!o3_mini.supports_vision);
+        assert!(o3_mini.supports_json_schema);
+    }
+
+    #[test]
+    fn test_for_id_resolves_snapshots() {
+        assert_eq!(ModelCapabilities::for_id("gpt-4o-2024-08-06"), ModelCapabilities::for_model(&ChatModel::Gpt4o));
+        assert_eq!(ModelCapabilities::for_id("gpt-4o-mini-2024-07-18"), ModelCapabilities::for_model(&ChatModel::Gpt4oMini));
+    }
+
+    #[test]
+    fn test_for_id_unknown_models() {
+        assert_eq!(ModelCapabilities::for_id("ft:gpt-4o-mini:org::abc"), None);
+        assert_eq!(ModelCapabilities::for_id("my-model-2024-08-06"), None);
+        assert_eq!(ModelCapabilities::for_id("x"), None);
+    }
+}
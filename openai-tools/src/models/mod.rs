@@ -101,6 +101,7 @@
 //! - `object`: Always "model"
 //! - `deleted`: Boolean indicating success
 
+pub mod capabilities;
 pub mod request;
 pub mod response;
 
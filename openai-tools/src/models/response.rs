@@ -2,6 +2,7 @@
 //!
 //! This module defines the response structures for the OpenAI Models API.
 
+use crate::common::pagination::Paginated;
 use serde::{Deserialize, Serialize};
 
 /// Response structure for listing all available models.
@@ -15,6 +16,27 @@ pub struct ModelsListResponse {
     pub data: Vec<Model>,
 }
 
+/// The models endpoint returns every model in a single page.
+impl Paginated for ModelsListResponse {
+    type Item = Model;
+
+    fn data(&self) -> &[Model] {
+        &self.data
+    }
+
+    fn has_more(&self) -> bool {
+        false
+    }
+
+    fn last_id(&self) -> Option<&str> {
+        self.data.last().map(|model| model.id.as_str())
+    }
+
+    fn into_data(self) -> Vec<Model> {
+        self.data
+    }
+}
+
 /// Represents an OpenAI model.
 ///
 /// Contains basic information about a model including its ID, creation time,
@@ -31,15 +53,5 @@ pub struct Model {
     pub owned_by: String,
 }
 
-/// Response structure for model deletion.
-///
-/// Returned when a fine-tuned model is successfully deleted.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteResponse {
-    /// The model identifier that was deleted
-    pub id: String,
-    /// Object type, always "model"
-    pub object: String,
-    /// Whether the model was successfully deleted
-    pub deleted: bool,
-}
+/// Response structure for model deletion, shared with the other delete endpoints.
+pub use crate::common::delete::DeleteResponse;
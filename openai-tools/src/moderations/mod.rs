@@ -125,8 +125,8 @@ pub mod response;
 
 #[cfg(test)]
 mod tests {
-    use crate::moderations::request::ModerationModel;
-    use crate::moderations::response::ModerationResponse;
+    use crate::moderations::request::{ModerationInput, ModerationModel, ModerationRequest};
+    use crate::moderations::response::{ModerationResponse, ModerationResult};
 
     #[test]
     fn test_moderation_response_deserialization() {
@@ -362,4 +362,82 @@ mod tests {
         assert!(response.results[1].flagged);
         assert!(response.results[1].categories.violence);
     }
+
+    #[test]
+    fn test_moderation_request_multimodal_serialization() {
+        let inputs = vec![ModerationInput::Text("A caption".to_string()), ModerationInput::ImageUrl("https://example.com/a.png".to_string())];
+        let request = ModerationRequest::new(inputs, Some(ModerationModel::OmniModerationLatest)).unwrap();
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "input": [
+                    {"type": "text", "text": "A caption"},
+                    {"type": "image_url", "image_url": {"url": "https://example.com/a.png"}}
+                ],
+                "model": "omni-moderation-latest"
+            })
+        );
+    }
+
+    #[test]
+    fn test_moderation_request_text_only_uses_strings() {
+        let request = ModerationRequest::new(vec![ModerationInput::Text("Hello".to_string())], None).unwrap();
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({"input": "Hello"}));
+
+        let inputs = vec![ModerationInput::Text("One".to_string()), ModerationInput::Text("Two".to_string())];
+        let request = ModerationRequest::new(inputs, None).unwrap();
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({"input": ["One", "Two"]}));
+    }
+
+    #[test]
+    fn test_moderation_request_rejects_images_for_text_model() {
+        let inputs = vec![ModerationInput::ImageUrl("https://example.com/a.png".to_string())];
+        assert!(ModerationRequest::new(inputs, Some(ModerationModel::TextModerationLatest)).is_err());
+    }
+
+    #[test]
+    fn test_moderation_result_flagged_summary() {
+        let json = r#"{
+            "flagged": true,
+            "categories": {
+                "hate": true, "hate/threatening": false, "harassment": true, "harassment/threatening": false,
+                "self-harm": false, "self-harm/intent": false, "self-harm/instructions": false,
+                "sexual": false, "sexual/minors": false, "violence": false, "violence/graphic": false,
+                "illicit": false, "illicit/violent": true
+            },
+            "category_scores": {
+                "hate": 0.85, "hate/threatening": 0.02, "harassment": 0.75, "harassment/threatening": 0.01,
+                "self-harm": 0.001, "self-harm/intent": 0.001, "self-harm/instructions": 0.001,
+                "sexual": 0.001, "sexual/minors": 0.001, "violence": 0.05, "violence/graphic": 0.01,
+                "illicit": 0.2, "illicit/violent": 0.9
+            }
+        }"#;
+
+        let result: ModerationResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.flagged_categories(), vec!["hate", "harassment", "illicit/violent"]);
+        assert_eq!(result.highest_category(), ("illicit/violent", 0.9));
+    }
+
+    #[test]
+    fn test_moderation_result_summary_without_illicit_fields() {
+        let json = r#"{
+            "flagged": false,
+            "categories": {
+                "hate": false, "hate/threatening": false, "harassment": false, "harassment/threatening": false,
+                "self-harm": false, "self-harm/intent": false, "self-harm/instructions": false,
+                "sexual": false, "sexual/minors": false, "violence": false, "violence/graphic": false
+            },
+            "category_scores": {
+                "hate": 0.001, "hate/threatening": 0.0, "harassment": 0.003, "harassment/threatening": 0.0,
+                "self-harm": 0.0, "self-harm/intent": 0.0, "self-harm/instructions": 0.0,
+                "sexual": 0.002, "sexual/minors": 0.0, "violence": 0.0, "violence/graphic": 0.0
+            }
+        }"#;
+
+        let result: ModerationResult = serde_json::from_str(json).unwrap();
+        assert!(result.flagged_categories().is_empty());
+        assert_eq!(result.highest_category(), ("harassment", 0.003));
+    }
 }
@@ -7,6 +7,7 @@
 //!
 //! - **Single Text Moderation**: Check a single text string
 //! - **Batch Moderation**: Check multiple texts at once
+//! - **Multi-modal Moderation**: Check images and text together with omni-moderation models
 //! - **Model Selection**: Choose between omni-moderation and text-moderation models
 //!
 //! # Quick Start
@@ -31,10 +32,10 @@
 //! ```
 
 use crate::common::auth::AuthProvider;
-use crate::common::client::create_http_client;
-use crate::common::errors::{ErrorResponse, OpenAIToolError, Result};
+use crate::common::client::{shared_or_new_http_client, HttpClient};
+use crate::common::errors::{request_id_header, OpenAIToolError, Result};
 use crate::moderations::response::ModerationResponse;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
 use std::time::Duration;
 
 /// Default API path for Moderations
@@ -71,24 +72,93 @@ impl std::fmt::Display for ModerationModel {
     }
 }
 
+/// A single input for multi-modal moderation.
+///
+/// Image inputs are only supported by omni-moderation models.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModerationInput {
+    /// Text to classify
+    Text(String),
+    /// URL (or base64 data URL) of an image to classify
+    ImageUrl(String),
+}
+
+impl Serialize for ModerationInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct ImageUrl<'a> {
+            url: &'a str,
+        }
+
+        let mut state = serializer.serialize_struct("ModerationInput", 2)?;
+        match self {
+            Self::Text(text) => {
+                state.serialize_field("type", "text")?;
+                state.serialize_field("text", text)?;
+            }
+            Self::ImageUrl(url) => {
+                state.serialize_field("type", "image_url")?;
+                state.serialize_field("image_url", &ImageUrl { url })?;
+            }
+        }
+        state.end()
+    }
+}
+
 /// Request payload for moderation endpoint.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct ModerationRequest {
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ModerationRequest {
     /// The input to classify
-    input: ModerationInput,
+    input: RequestInput,
     /// The model to use for classification
     #[serde(skip_serializing_if = "Option::is_none")]
     model: Option<String>,
 }
 
+impl ModerationRequest {
+    /// Builds the request payload for `inputs`.
+    ///
+    /// Text-only inputs are sent as plain strings, which every moderation model
+    /// accepts; inputs containing images use the array-of-objects format.
+    pub(crate) fn new(inputs: Vec<ModerationInput>, model: Option<ModerationModel>) -> Result<Self> {
+        let has_image = inputs.iter().any(|input| matches!(input, ModerationInput::ImageUrl(_)));
+        if has_image && model == Some(ModerationModel::TextModerationLatest) {
+            return Err(OpenAIToolError::Error(format!("Model '{}' does not support image inputs", ModerationModel::TextModerationLatest)));
+        }
+
+        let input = if has_image {
+            RequestInput::MultiModal(inputs)
+        } else {
+            let mut texts: Vec<String> = inputs
+                .into_iter()
+                .filter_map(|input| match input {
+                    ModerationInput::Text(text) => Some(text),
+                    ModerationInput::ImageUrl(_) => None,
+                })
+                .collect();
+            if texts.len() == 1 {
+                RequestInput::Single(texts.remove(0))
+            } else {
+                RequestInput::Multiple(texts)
+            }
+        };
+        Ok(Self { input, model: model.map(|m| m.as_str().to_string()) })
+    }
+}
+
 /// Input types for moderation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
-enum ModerationInput {
+enum RequestInput {
     /// Single text string
     Single(String),
     /// Multiple text strings
     Multiple(Vec<String>),
+    /// Text and image inputs
+    MultiModal(Vec<ModerationInput>),
 }
 
 /// Client for interacting with the OpenAI Moderations API.
@@ -122,6 +192,8 @@ pub struct Moderations {
     auth: AuthProvider,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
 }
 
 impl Moderations {
@@ -145,36 +217,36 @@ impl Moderations {
     /// ```
     pub fn new() -> Result<Self> {
         let auth = AuthProvider::openai_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Moderations client with a custom authentication provider
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Moderations client for Azure OpenAI API
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Moderations client by auto-detecting the provider
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Creates a new Moderations client with URL-based provider detection
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, timeout: None }
+        Self { auth, timeout: None, http_client: None }
     }
 
     /// Creates a new Moderations client from URL using environment variables
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, timeout: None })
+        Ok(Self { auth, timeout: None, http_client: None })
     }
 
     /// Returns the authentication provider
@@ -184,6 +256,12 @@ impl Moderations {
 
     /// Sets the request timeout duration.
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -197,8 +275,8 @@ impl Moderations {
     }
 
     /// Creates the HTTP client with default headers.
-    fn create_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = shared_or_new_http_client(self.http_client.as_ref(), self.timeout)?;
         let mut headers = request::header::HeaderMap::new();
         self.auth.apply_headers(&mut headers)?;
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
@@ -239,9 +317,7 @@ impl Moderations {
     /// }
     /// ```
     pub async fn moderate_text(&self, text: &str, model: Option<ModerationModel>) -> Result<ModerationResponse> {
-        let request_body = ModerationRequest { input: ModerationInput::Single(text.to_string()), model: model.map(|m| m.as_str().to_string()) };
-
-        self.send_request(&request_body).await
+        self.moderate_multimodal(vec![ModerationInput::Text(text.to_string())], model).await
     }
 
     /// Moderates multiple text strings.
@@ -279,7 +355,46 @@ impl Moderations {
     /// }
     /// ```
     pub async fn moderate_texts(&self, texts: Vec<String>, model: Option<ModerationModel>) -> Result<ModerationResponse> {
-        let request_body = ModerationRequest { input: ModerationInput::Multiple(texts), model: model.map(|m| m.as_str().to_string()) };
+        let request_body = ModerationRequest { input: RequestInput::Multiple(texts), model: model.map(|m| m.as_str().to_string()) };
+
+        self.send_request(&request_body).await
+    }
+
+    /// Moderates a mix of text and image inputs.
+    ///
+    /// Image inputs require an omni-moderation model. Inputs that contain only
+    /// text are sent as plain strings, so this also works with text-only models.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The text and image inputs to moderate
+    /// * `model` - Optional model to use (defaults to `omni-moderation-latest`)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ModerationResponse)` - The moderation results
+    /// * `Err(OpenAIToolError)` - If the model does not support images, the request fails, or response parsing fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::moderations::request::{ModerationInput, Moderations};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let moderations = Moderations::new()?;
+    ///     let inputs = vec![
+    ///         ModerationInput::Text("Caption for the uploaded photo".to_string()),
+    ///         ModerationInput::ImageUrl("https://example.com/upload.png".to_string()),
+    ///     ];
+    ///     let response = moderations.moderate_multimodal(inputs, None).await?;
+    ///
+    ///     println!("Flagged: {}", response.results[0].flagged);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn moderate_multimodal(&self, inputs: Vec<ModerationInput>, model: Option<ModerationModel>) -> Result<ModerationResponse> {
+        let request_body = ModerationRequest::new(inputs, model)?;
 
         self.send_request(&request_body).await
     }
@@ -291,20 +406,18 @@ impl Moderations {
         let body = serde_json::to_string(request_body).map_err(OpenAIToolError::SerdeJsonError)?;
 
         let url = self.auth.endpoint(MODERATIONS_PATH);
-        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError)?;
+        let response = client.post(&url).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
 
         let status = response.status();
-        let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let request_id = request_id_header(response.headers());
+        let content = response.text().await.map_err(OpenAIToolError::from)?;
 
         if cfg!(test) {
             tracing::info!("Response content: {}", content);
         }
 
         if !status.is_success() {
-            if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(&content) {
-                return Err(OpenAIToolError::Error(error_resp.error.message.unwrap_or_default()));
-            }
-            return Err(OpenAIToolError::Error(format!("API error ({}): {}", status, content)));
+            return Err(OpenAIToolError::from_api_response(status, request_id, &content));
         }
 
         serde_json::from_str::<ModerationResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
@@ -28,6 +28,42 @@ pub struct ModerationResult {
     pub category_scores: ModerationCategoryScores,
 }
 
+impl ModerationResult {
+    /// Returns the API names (e.g. `"hate/threatening"`) of all flagged categories.
+    ///
+    /// Categories missing from the response, such as `illicit` on older models,
+    /// are treated as not flagged.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::moderations::request::Moderations;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let response = Moderations::new()?.moderate_text("some user text", None).await?;
+    /// let result = &response.results[0];
+    /// if result.flagged {
+    ///     println!("Flagged for: {}", result.flagged_categories().join(", "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flagged_categories(&self) -> Vec<&'static str> {
+        self.categories.entries().into_iter().filter(|(_, flagged)| *flagged == Some(true)).map(|(name, _)| name).collect()
+    }
+
+    /// Returns the API name and score of the category with the highest score.
+    ///
+    /// Categories missing from the response are skipped.
+    pub fn highest_category(&self) -> (&'static str, f64) {
+        self.category_scores
+            .entries()
+            .into_iter()
+            .filter_map(|(name, score)| score.map(|score| (name, score)))
+            .fold(("hate", f64::MIN), |best, current| if current.1 > best.1 { current } else { best })
+    }
+}
+
 /// Category flags for content moderation.
 ///
 /// Each field indicates whether that category of content was detected.
@@ -70,6 +106,27 @@ pub struct ModerationCategories {
     pub illicit_violent: Option<bool>,
 }
 
+impl ModerationCategories {
+    /// Returns every category flag keyed by its API name; `None` for categories absent from the response.
+    fn entries(&self) -> [(&'static str, Option<bool>); 13] {
+        [
+            ("hate", Some(self.hate)),
+            ("hate/threatening", Some(self.hate_threatening)),
+            ("harassment", Some(self.harassment)),
+            ("harassment/threatening", Some(self.harassment_threatening)),
+            ("self-harm", Some(self.self_harm)),
+            ("self-harm/intent", Some(self.self_harm_intent)),
+            ("self-harm/instructions", Some(self.self_harm_instructions)),
+            ("sexual", Some(self.sexual)),
+            ("sexual/minors", Some(self.sexual_minors)),
+            ("violence", Some(self.violence)),
+            ("violence/graphic", Some(self.violence_graphic)),
+            ("illicit", self.illicit),
+            ("illicit/violent", self.illicit_violent),
+        ]
+    }
+}
+
 /// Confidence scores for each moderation category.
 ///
 /// Values range from 0.0 to 1.0, where higher values indicate
@@ -112,3 +169,24 @@ pub struct ModerationCategoryScores {
     #[serde(rename = "illicit/violent", skip_serializing_if = "Option::is_none")]
     pub illicit_violent: Option<f64>,
 }
+
+impl ModerationCategoryScores {
+    /// Returns every category score keyed by its API name; `None` for categories absent from the response.
+    fn entries(&self) -> [(&'static str, Option<f64>); 13] {
+        [
+            ("hate", Some(self.hate)),
+            ("hate/threatening", Some(self.hate_threatening)),
+            ("harassment", Some(self.harassment)),
+            ("harassment/threatening", Some(self.harassment_threatening)),
+            ("self-harm", Some(self.self_harm)),
+            ("self-harm/intent", Some(self.self_harm_intent)),
+            ("self-harm/instructions", Some(self.self_harm_instructions)),
+            ("sexual", Some(self.sexual)),
+            ("sexual/minors", Some(self.sexual_minors)),
+            ("violence", Some(self.violence)),
+            ("violence/graphic", Some(self.violence_graphic)),
+            ("illicit", self.illicit),
+            ("illicit/violent", self.illicit_violent),
+        ]
+    }
+}
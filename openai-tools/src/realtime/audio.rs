@@ -1,7 +1,19 @@
 //! Audio types for the Realtime API.
 
+use base64::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::events::client::ClientEvent;
+
+/// Sample rate of PCM16 audio expected by the Realtime API, in Hz.
+pub const REALTIME_SAMPLE_RATE: u32 = 24_000;
+
+/// Longest audio duration sent in a single `input_audio_buffer.append` event, in seconds.
+pub const MAX_APPEND_SECONDS: u32 = 15;
+
+/// Number of PCM16 samples in the longest single append.
+const MAX_APPEND_SAMPLES: usize = (REALTIME_SAMPLE_RATE * MAX_APPEND_SECONDS) as usize;
+
 /// Audio formats supported by the Realtime API.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -90,3 +102,64 @@ pub enum NoiseReductionType {
     /// Optimized for far-field audio (distant microphone).
     FarField,
 }
+
+/// Streaming linear-interpolation resampler for mono PCM16 audio.
+///
+/// Keeps the last input sample and the fractional read position between calls,
+/// so consecutive frames are resampled without discontinuities at their edges.
+#[derive(Debug, Clone)]
+pub(crate) struct Resampler {
+    /// Input samples consumed per output sample
+    step: f64,
+    /// Read position of the next output sample, relative to `previous`
+    position: f64,
+    /// Last sample of the previous frame
+    previous: Option<i16>,
+}
+
+impl Resampler {
+    /// Create a resampler converting from `from_rate` Hz to `to_rate` Hz.
+    pub(crate) fn new(from_rate: u32, to_rate: u32) -> Self {
+        Self { step: from_rate as f64 / to_rate as f64, position: 0.0, previous: None }
+    }
+
+    /// Resample the next frame of input samples.
+    pub(crate) fn process(&mut self, input: &[i16]) -> Vec<i16> {
+        if self.step == 1.0 {
+            return input.to_vec();
+        }
+
+        let buffer: Vec<i16> = self.previous.iter().copied().chain(input.iter().copied()).collect();
+        if buffer.len() < 2 {
+            self.previous = buffer.last().copied();
+            return Vec::new();
+        }
+
+        let last = (buffer.len() - 1) as f64;
+        let mut output = Vec::with_capacity((input.len() as f64 / self.step).ceil() as usize);
+        while self.position < last {
+            let index = self.position as usize;
+            let frac = self.position - index as f64;
+            let sample = buffer[index] as f64 * (1.0 - frac) + buffer[index + 1] as f64 * frac;
+            output.push(sample.round() as i16);
+            self.position += self.step;
+        }
+        self.position -= last;
+        self.previous = buffer.last().copied();
+        output
+    }
+}
+
+/// Encode 24kHz PCM16 samples as `input_audio_buffer.append` events.
+///
+/// Each event carries at most [`MAX_APPEND_SECONDS`] of audio, encoded as
+/// little-endian PCM16 in base64.
+pub(crate) fn pcm16_append_events(samples: &[i16]) -> Vec<ClientEvent> {
+    samples
+        .chunks(MAX_APPEND_SAMPLES)
+        .map(|chunk| {
+            let bytes: Vec<u8> = chunk.iter().flat_map(|sample| sample.to_le_bytes()).collect();
+            ClientEvent::append_audio(BASE64_STANDARD.encode(bytes))
+        })
+        .collect()
+}
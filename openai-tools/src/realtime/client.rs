@@ -1,11 +1,11 @@
 //! Realtime API client implementation.
 
 use base64::prelude::*;
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{SinkExt, Stream, StreamExt};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{
     connect_async_with_config,
-    tungstenite::{client::IntoClientRequest, Message as WsMessage},
+    tungstenite::{client::IntoClientRequest, handshake::client::Request, http::HeaderValue, Message as WsMessage},
     MaybeTlsStream, WebSocketStream,
 };
 
@@ -14,11 +14,12 @@ use crate::common::errors::{OpenAIToolError, Result};
 use crate::common::models::RealtimeModel;
 use crate::common::tool::Tool;
 
-use super::audio::{AudioFormat, InputAudioTranscription, TranscriptionModel, Voice};
-use super::conversation::{ConversationItem, FunctionCallOutputItem, MessageItem};
+use super::audio::{pcm16_append_events, AudioFormat, InputAudioTranscription, Resampler, TranscriptionModel, Voice, REALTIME_SAMPLE_RATE};
+use super::conversation::{ConversationItem, ConversationLog, FunctionCallOutputItem, MessageItem};
 use super::events::client::ClientEvent;
 use super::events::server::ServerEvent;
 use super::session::{Modality, RealtimeTool, ResponseCreateConfig, SessionConfig};
+use super::stream::{dispatch_event, RealtimeEventHandler};
 use super::vad::{SemanticVadConfig, ServerVadConfig, TurnDetection};
 
 /// The Realtime API WebSocket endpoint path.
@@ -105,6 +106,11 @@ impl RealtimeClient {
         &self.auth
     }
 
+    /// Returns the session configuration sent on connect.
+    pub fn session_config(&self) -> &SessionConfig {
+        &self.session_config
+    }
+
     /// Set the model for the Realtime API.
     ///
     /// # Example
@@ -172,6 +178,50 @@ impl RealtimeClient {
         self
     }
 
+    /// Configure the session for live transcription only.
+    ///
+    /// Sets a text-only modality, enables input audio transcription (Whisper
+    /// unless configured otherwise) with PCM16 input, and turns on Server VAD
+    /// with response generation disabled, so committed speech is transcribed
+    /// but never answered. Use [`RealtimeSession::recv_transcription`] to receive
+    /// only the transcription events.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::realtime::RealtimeClient;
+    /// use openai_tools::realtime::events::server::ServerEvent;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = RealtimeClient::new();
+    ///     client.transcription_only();
+    ///
+    ///     let mut session = client.connect().await?;
+    ///     // Stream microphone audio with session.append_audio_stream(...)
+    ///     while let Some(event) = session.recv_transcription().await? {
+    ///         match event {
+    ///             ServerEvent::InputAudioTranscriptionDelta(e) => print!("{}", e.delta),
+    ///             ServerEvent::InputAudioTranscriptionCompleted(_) => println!(),
+    ///             _ => {}
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn transcription_only(&mut self) -> &mut Self {
+        let config = &mut self.session_config;
+        config.modalities = Some(vec![Modality::Text]);
+        config.input_audio_format.get_or_insert(AudioFormat::Pcm16);
+        config.input_audio_transcription.get_or_insert_with(|| InputAudioTranscription::new(TranscriptionModel::Whisper1));
+        match &mut config.turn_detection {
+            Some(TurnDetection::ServerVad(vad)) => vad.create_response = Some(false),
+            Some(TurnDetection::SemanticVad(vad)) => vad.create_response = Some(false),
+            None => config.turn_detection = Some(TurnDetection::ServerVad(ServerVadConfig::new().with_create_response(false))),
+        }
+        self
+    }
+
     /// Set Server VAD turn detection.
     pub fn server_vad(&mut self, config: ServerVadConfig) -> &mut Self {
         self.session_config.turn_detection = Some(TurnDetection::ServerVad(config));
@@ -214,33 +264,10 @@ impl RealtimeClient {
     ///
     /// Returns a `RealtimeSession` for sending and receiving events.
     pub async fn connect(&self) -> Result<RealtimeSession> {
-        // Get the WebSocket URL based on auth provider
-        let url = self.ws_endpoint();
-
-        // Build WebSocket request with headers
-        let mut request = url.into_client_request().map_err(|e| OpenAIToolError::Error(format!("Failed to build request: {}", e)))?;
-
-        let headers = request.headers_mut();
-
-        // Apply auth headers based on provider
-        match &self.auth {
-            AuthProvider::OpenAI(auth) => {
-                headers.insert(
-                    "Authorization",
-                    format!("Bearer {}", auth.api_key()).parse().map_err(|e| OpenAIToolError::Error(format!("Invalid header value: {}", e)))?,
-                );
-            }
-            AuthProvider::Azure(auth) => {
-                headers.insert("api-key", auth.api_key().parse().map_err(|e| OpenAIToolError::Error(format!("Invalid header value: {}", e)))?);
-            }
-        }
-        headers.insert("OpenAI-Beta", "realtime=v1".parse().map_err(|e| OpenAIToolError::Error(format!("Invalid header value: {}", e)))?);
-
-        let (ws_stream, _response) = connect_async_with_config(request, None, false)
-            .await
-            .map_err(|e| OpenAIToolError::Error(format!("WebSocket connection failed: {}", e)))?;
+        self.session_config.validate()?;
 
-        let mut session = RealtimeSession::new(ws_stream);
+        let ws_stream = self.open_socket().await?;
+        let mut session = RealtimeSession::new(ws_stream, self.clone());
 
         // Wait for session.created event
         session.wait_for_session_created().await?;
@@ -258,6 +285,28 @@ impl RealtimeClient {
         Ok(session)
     }
 
+    /// Open an authenticated WebSocket connection to the Realtime endpoint.
+    async fn open_socket(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+        let request = self.handshake_request()?;
+        let (ws_stream, _response) = connect_async_with_config(request, None, false)
+            .await
+            .map_err(|e| OpenAIToolError::Error(format!("WebSocket connection failed: {}", e)))?;
+
+        Ok(ws_stream)
+    }
+
+    /// Build the WebSocket handshake request for the Realtime endpoint.
+    ///
+    /// Carries the same authentication headers as the HTTP clients, including
+    /// `OpenAI-Organization` and `OpenAI-Project` when set.
+    pub(crate) fn handshake_request(&self) -> Result<Request> {
+        let mut request = self.ws_endpoint().into_client_request().map_err(|e| OpenAIToolError::Error(format!("Failed to build request: {}", e)))?;
+        let headers = request.headers_mut();
+        self.auth.apply_headers(headers)?;
+        headers.insert("OpenAI-Beta", HeaderValue::from_static("realtime=v1"));
+        Ok(request)
+    }
+
     /// Get the WebSocket endpoint URL based on auth provider.
     fn ws_endpoint(&self) -> String {
         match &self.auth {
@@ -290,15 +339,24 @@ impl Default for RealtimeClient {
 
 /// An active Realtime API session.
 ///
-/// Provides methods for sending events and receiving responses.
+/// Provides methods for sending events and receiving responses. The session
+/// keeps track of its configuration and conversation items so that it can
+/// [`reconnect`](Self::reconnect) after the connection drops.
 pub struct RealtimeSession {
     ws_stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    /// The client used to open the connection, reused to reconnect
+    client: RealtimeClient,
+    /// The session configuration with all updates applied
+    session_config: SessionConfig,
+    /// Conversation items reported by the server
+    conversation: ConversationLog,
 }
 
 impl RealtimeSession {
     /// Create a new session from a WebSocket stream.
-    fn new(ws_stream: WebSocketStream<MaybeTlsStream<TcpStream>>) -> Self {
-        Self { ws_stream }
+    fn new(ws_stream: WebSocketStream<MaybeTlsStream<TcpStream>>, client: RealtimeClient) -> Self {
+        let session_config = client.session_config.clone();
+        Self { ws_stream, client, session_config, conversation: ConversationLog::default() }
     }
 
     /// Send a client event to the server.
@@ -316,6 +374,7 @@ impl RealtimeSession {
             match self.ws_stream.next().await {
                 Some(Ok(WsMessage::Text(text))) => {
                     let event: ServerEvent = serde_json::from_str(&text)?;
+                    self.conversation.apply(&event);
                     return Ok(Some(event));
                 }
                 Some(Ok(WsMessage::Close(_))) => {
@@ -336,6 +395,41 @@ impl RealtimeSession {
         }
     }
 
+    /// Receive the next input audio transcription event, skipping all others.
+    ///
+    /// Returns `conversation.item.input_audio_transcription.delta` and
+    /// `.completed` events; server errors are returned as
+    /// `OpenAIToolError::RealtimeError`. Returns `None` if the connection is closed.
+    pub async fn recv_transcription(&mut self) -> Result<Option<ServerEvent>> {
+        while let Some(event) = self.recv().await? {
+            match event {
+                ServerEvent::Error(e) => {
+                    return Err(OpenAIToolError::RealtimeError { code: e.error.code.unwrap_or_default(), message: e.error.message });
+                }
+                event if event.is_transcription() => return Ok(Some(event)),
+                _ => continue,
+            }
+        }
+        Ok(None)
+    }
+
+    /// Receive events and dispatch them to `handler` until the response is done.
+    ///
+    /// Returns once a `response.done` event has been handled or the connection
+    /// is closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The handler receiving the dispatched events
+    pub async fn run<H: RealtimeEventHandler + ?Sized>(&mut self, handler: &mut H) -> Result<()> {
+        while let Some(event) = self.recv().await? {
+            if dispatch_event(handler, &event) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     /// Wait for the session.created event.
     async fn wait_for_session_created(&mut self) -> Result<()> {
         match self.recv().await? {
@@ -350,9 +444,59 @@ impl RealtimeSession {
 
     /// Update the session configuration.
     pub async fn update_session(&mut self, config: SessionConfig) -> Result<()> {
+        self.session_config.merge(config.clone());
         self.send(ClientEvent::SessionUpdate { event_id: None, session: config }).await
     }
 
+    /// Returns the conversation items tracked so far, in conversation order.
+    ///
+    /// Items are tracked from the server's `conversation.item.created`,
+    /// `response.output_item.done`, transcription and deletion events, so they
+    /// reflect the events received through [`recv`](Self::recv).
+    pub fn conversation_items(&self) -> impl Iterator<Item = &ConversationItem> {
+        self.conversation.items()
+    }
+
+    /// Re-establish a dropped connection and restore the conversation.
+    ///
+    /// Opens a new WebSocket, re-sends the session configuration (including all
+    /// updates made with [`update_session`](Self::update_session)) and replays the
+    /// tracked conversation items in order. Audio content is replayed as its
+    /// transcript; user audio that was never transcribed, the input audio buffer
+    /// and any in-progress response are lost.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::realtime::RealtimeClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut session = RealtimeClient::new().connect().await?;
+    /// session.send_text("Remember the number 42.").await?;
+    ///
+    /// loop {
+    ///     match session.recv().await {
+    ///         Ok(Some(event)) => println!("{:?}", event),
+    ///         Ok(None) | Err(_) => {
+    ///             // The connection dropped; resume where we left off
+    ///             session.reconnect().await?;
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn reconnect(&mut self) -> Result<()> {
+        self.ws_stream = self.client.open_socket().await?;
+        self.wait_for_session_created().await?;
+
+        self.send(ClientEvent::SessionUpdate { event_id: None, session: self.session_config.clone() }).await?;
+        for item in self.conversation.take_replayable() {
+            self.create_item(item).await?;
+        }
+        Ok(())
+    }
+
     /// Append base64-encoded audio to the input buffer.
     pub async fn append_audio(&mut self, audio_base64: &str) -> Result<()> {
         self.send(ClientEvent::InputAudioBufferAppend { event_id: None, audio: audio_base64.to_string() }).await
@@ -366,6 +510,53 @@ impl RealtimeSession {
         self.append_audio(&encoded).await
     }
 
+    /// Append a stream of mono PCM16 samples to the input buffer.
+    ///
+    /// Audio is resampled to 24kHz when `sample_rate` differs, and each frame
+    /// yielded by the stream is sent as one or more `input_audio_buffer.append`
+    /// events of at most [`MAX_APPEND_SECONDS`](super::MAX_APPEND_SECONDS) each. Appends are sent as frames
+    /// arrive, so a microphone stream is forwarded at its own pace.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - Stream of frames of mono 16-bit samples
+    /// * `sample_rate` - Sample rate of the input audio, in Hz
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream;
+    /// use openai_tools::realtime::RealtimeClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut session = RealtimeClient::new().connect().await?;
+    ///
+    /// // One second of silence captured at 16kHz, in 20ms frames
+    /// let frames = stream::iter(vec![vec![0i16; 320]; 50]);
+    /// session.append_audio_stream(frames, 16_000).await?;
+    /// session.commit_audio().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn append_audio_stream<S>(&mut self, samples: S, sample_rate: u32) -> Result<()>
+    where
+        S: Stream<Item = Vec<i16>>,
+    {
+        if sample_rate == 0 {
+            return Err(OpenAIToolError::InvalidParameter("sample_rate must be greater than 0".to_string()));
+        }
+
+        futures_util::pin_mut!(samples);
+        let mut resampler = Resampler::new(sample_rate, REALTIME_SAMPLE_RATE);
+        while let Some(frame) = samples.next().await {
+            for event in pcm16_append_events(&resampler.process(&frame)) {
+                self.send(event).await?;
+            }
+        }
+        Ok(())
+    }
+
     /// Commit the input audio buffer.
     ///
     /// Creates a user message item from the buffered audio.
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::events::server::{ResponseItem, ServerEvent};
+
 /// A conversation item in the Realtime API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -153,3 +155,106 @@ pub enum ItemStatus {
     /// Item processing was incomplete.
     Incomplete,
 }
+
+impl ConversationItem {
+    /// Convert an item reported by the server into an item that can be sent back.
+    ///
+    /// Audio content is converted to text using its transcript, since the server
+    /// does not echo audio data. Returns `None` for unsupported item types.
+    fn from_response_item(item: &ResponseItem) -> Option<Self> {
+        match item.item_type.as_str() {
+            "message" => {
+                let role = match item.role.as_deref()? {
+                    "system" => MessageRole::System,
+                    "user" => MessageRole::User,
+                    "assistant" => MessageRole::Assistant,
+                    _ => return None,
+                };
+                let content = item
+                    .content
+                    .iter()
+                    .filter_map(|part| match part.content_type.as_str() {
+                        "input_text" => part.text.clone().map(ContentPart::input_text),
+                        "input_audio" => part.transcript.clone().map(ContentPart::input_text),
+                        "text" => part.text.clone().map(ContentPart::text),
+                        "audio" => part.transcript.clone().map(ContentPart::text),
+                        _ => None,
+                    })
+                    .collect();
+                Some(Self::Message(MessageItem { id: None, role, content }))
+            }
+            "function_call" => Some(Self::FunctionCall(FunctionCallItem {
+                id: None,
+                call_id: item.call_id.clone()?,
+                name: item.name.clone()?,
+                arguments: item.arguments.clone().unwrap_or_default(),
+            })),
+            "function_call_output" => Some(Self::FunctionCallOutput(FunctionCallOutputItem::new(item.call_id.clone()?, item.output.clone()?))),
+            _ => None,
+        }
+    }
+}
+
+/// Conversation items tracked from server events, in conversation order.
+///
+/// Used by [`RealtimeSession::reconnect`](super::RealtimeSession::reconnect) to replay
+/// the conversation on a new connection.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConversationLog {
+    /// Items keyed by their server-assigned ID
+    items: Vec<(String, ConversationItem)>,
+}
+
+impl ConversationLog {
+    /// Update the log from a server event.
+    pub(crate) fn apply(&mut self, event: &ServerEvent) {
+        match event {
+            ServerEvent::ConversationItemCreated(e) => {
+                let Some(item) = ConversationItem::from_response_item(&e.item) else { return };
+                let position = match &e.previous_item_id {
+                    Some(previous) => self.position(previous).map_or(self.items.len(), |index| index + 1),
+                    None => self.items.len(),
+                };
+                self.items.insert(position, (e.item.id.clone(), item));
+            }
+            ServerEvent::ResponseOutputItemDone(e) => {
+                let Some(item) = ConversationItem::from_response_item(&e.item) else { return };
+                match self.position(&e.item.id) {
+                    Some(index) => self.items[index].1 = item,
+                    None => self.items.push((e.item.id.clone(), item)),
+                }
+            }
+            ServerEvent::InputAudioTranscriptionCompleted(e) => {
+                if let Some(index) = self.position(&e.item_id) {
+                    if let ConversationItem::Message(message) = &mut self.items[index].1 {
+                        message.content.push(ContentPart::input_text(e.transcript.clone()));
+                    }
+                }
+            }
+            ServerEvent::ConversationItemDeleted(e) => {
+                self.items.retain(|(id, _)| id != &e.item_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the tracked items in conversation order.
+    pub(crate) fn items(&self) -> impl Iterator<Item = &ConversationItem> {
+        self.items.iter().map(|(_, item)| item)
+    }
+
+    /// Removes and returns the items that can be replayed.
+    ///
+    /// Messages without any text (e.g. untranscribed audio) are dropped.
+    pub(crate) fn take_replayable(&mut self) -> Vec<ConversationItem> {
+        std::mem::take(&mut self.items)
+            .into_iter()
+            .map(|(_, item)| item)
+            .filter(|item| !matches!(item, ConversationItem::Message(message) if message.content.is_empty()))
+            .collect()
+    }
+
+    fn position(&self, item_id: &str) -> Option<usize> {
+        self.items.iter().position(|(id, _)| id == item_id)
+    }
+}
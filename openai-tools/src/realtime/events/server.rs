@@ -45,6 +45,10 @@ pub enum ServerEvent {
     #[serde(rename = "conversation.item.input_audio_transcription.completed")]
     InputAudioTranscriptionCompleted(InputAudioTranscriptionCompletedEvent),
 
+    /// Incremental input audio transcription text.
+    #[serde(rename = "conversation.item.input_audio_transcription.delta")]
+    InputAudioTranscriptionDelta(InputAudioTranscriptionDeltaEvent),
+
     /// Input audio transcription failed.
     #[serde(rename = "conversation.item.input_audio_transcription.failed")]
     InputAudioTranscriptionFailed(InputAudioTranscriptionFailedEvent),
@@ -241,6 +245,16 @@ pub struct InputAudioTranscriptionCompletedEvent {
     pub transcript: String,
 }
 
+/// Input audio transcription delta event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct InputAudioTranscriptionDeltaEvent {
+    pub event_id: String,
+    pub item_id: String,
+    #[serde(default)]
+    pub content_index: u32,
+    pub delta: String,
+}
+
 /// Input audio transcription failed event.
 #[derive(Debug, Clone, Deserialize)]
 pub struct InputAudioTranscriptionFailedEvent {
@@ -569,6 +583,13 @@ impl ServerEvent {
         matches!(self, Self::Error(_))
     }
 
+    /// Check if this is an input audio transcription delta or completed event.
+    ///
+    /// Useful for filtering a transcription-only session down to its transcripts.
+    pub fn is_transcription(&self) -> bool {
+        matches!(self, Self::InputAudioTranscriptionDelta(_) | Self::InputAudioTranscriptionCompleted(_))
+    }
+
     /// Get the event ID if available.
     pub fn event_id(&self) -> Option<&str> {
         match self {
@@ -580,6 +601,7 @@ impl ServerEvent {
             Self::ConversationItemDeleted(e) => Some(&e.event_id),
             Self::ConversationItemTruncated(e) => Some(&e.event_id),
             Self::InputAudioTranscriptionCompleted(e) => Some(&e.event_id),
+            Self::InputAudioTranscriptionDelta(e) => Some(&e.event_id),
             Self::InputAudioTranscriptionFailed(e) => Some(&e.event_id),
             Self::InputAudioBufferCommitted(e) => Some(&e.event_id),
             Self::InputAudioBufferCleared(e) => Some(&e.event_id),
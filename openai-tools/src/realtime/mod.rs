@@ -15,9 +15,7 @@
 //! ## Quick Start
 //!
 //! ```rust,no_run
-//! use openai_tools::realtime::{RealtimeClient, Modality, Voice};
-//! use openai_tools::realtime::vad::ServerVadConfig;
-//! use openai_tools::realtime::events::server::ServerEvent;
+//! use openai_tools::realtime::{EventHandler, RealtimeClient, Modality, Voice};
 //! use openai_tools::common::models::RealtimeModel;
 //!
 //! #[tokio::main]
@@ -35,16 +33,10 @@
 //!     session.send_text("Hello!").await?;
 //!     session.create_response(None).await?;
 //!
-//!     // Process events
-//!     while let Some(event) = session.recv().await? {
-//!         match event {
-//!             ServerEvent::ResponseTextDelta(e) => {
-//!                 print!("{}", e.delta);
-//!             }
-//!             ServerEvent::ResponseDone(_) => break,
-//!             _ => {}
-//!         }
-//!     }
+//!     // Process events until the response is done
+//!     let mut handler = EventHandler::new();
+//!     handler.on_text_delta(|e| print!("{}", e.delta));
+//!     handler.run(&mut session).await?;
 //!
 //!     session.close().await?;
 //!     Ok(())
@@ -154,13 +146,13 @@ mod stream;
 pub mod vad;
 
 // Re-export main types
-pub use audio::{AudioFormat, InputAudioTranscription, TranscriptionModel, Voice};
+pub use audio::{AudioFormat, InputAudioTranscription, TranscriptionModel, Voice, MAX_APPEND_SECONDS, REALTIME_SAMPLE_RATE};
 pub use client::{RealtimeClient, RealtimeSession};
 pub use conversation::{ContentPart, ConversationItem, FunctionCallItem, FunctionCallOutputItem, MessageItem, MessageRole};
 pub use events::client::ClientEvent;
 pub use events::server::ServerEvent;
 pub use session::{MaxTokens, Modality, NamedFunction, NamedToolChoice, RealtimeTool, SessionConfig, SimpleToolChoice, ToolChoice};
-pub use stream::EventHandler;
+pub use stream::{EventHandler, RealtimeEventHandler};
 pub use vad::{Eagerness, SemanticVadConfig, ServerVadConfig, TurnDetection};
 
 #[cfg(test)]
@@ -253,6 +245,15 @@ mod tests {
         assert!(!json.contains("\"function\":{"));
     }
 
+    #[test]
+    fn test_tool_conversion_round_trips_through_realtime_tool() {
+        let chat_tool =
+            Tool::function("get_weather", "Get weather for location", vec![("location", ParameterProperty::from_string("City name"))], false);
+
+        let tool = Tool::from(RealtimeTool::from(chat_tool.clone()));
+        assert_eq!(serde_json::to_value(&tool).unwrap(), serde_json::to_value(&chat_tool).unwrap());
+    }
+
     #[test]
     fn test_conversation_item_serialization() {
         let item = ConversationItem::Message(MessageItem {
@@ -357,4 +358,305 @@ mod tests {
             _ => panic!("Expected ResponseTextDelta event"),
         }
     }
+
+    #[test]
+    fn test_realtime_event_handler_dispatch() {
+        use events::server::{ErrorEvent, ResponseDoneEvent, ResponseFunctionCallArgumentsDoneEvent, ResponseTextDeltaEvent};
+
+        #[derive(Default)]
+        struct Recorder {
+            events: Vec<String>,
+        }
+
+        impl RealtimeEventHandler for Recorder {
+            fn on_text_delta(&mut self, event: &ResponseTextDeltaEvent) {
+                self.events.push(format!("text:{}", event.delta));
+            }
+
+            fn on_function_call(&mut self, event: &ResponseFunctionCallArgumentsDoneEvent) {
+                self.events.push(format!("call:{}", event.name));
+            }
+
+            fn on_error(&mut self, event: &ErrorEvent) {
+                self.events.push(format!("error:{}", event.error.message));
+            }
+
+            fn on_done(&mut self, event: &ResponseDoneEvent) {
+                self.events.push(format!("done:{}", event.response.id));
+            }
+        }
+
+        let raw = [
+            r#"{"type":"response.text.delta","event_id":"evt_1","response_id":"resp_1","item_id":"item_1","output_index":0,"content_index":0,"delta":"Hel"}"#,
+            r#"{"type":"response.audio.delta","event_id":"evt_2","response_id":"resp_1","item_id":"item_1","output_index":0,"content_index":0,"delta":"AAAA"}"#,
+            r#"{"type":"response.text.delta","event_id":"evt_3","response_id":"resp_1","item_id":"item_1","output_index":0,"content_index":0,"delta":"lo"}"#,
+            r#"{"type":"response.function_call_arguments.done","event_id":"evt_4","response_id":"resp_1","item_id":"item_2","output_index":1,"call_id":"call_1","name":"get_weather","arguments":"{}"}"#,
+            r#"{"type":"error","event_id":"evt_5","error":{"type":"server_error","code":null,"message":"oops"}}"#,
+            r#"{"type":"response.done","event_id":"evt_6","response":{"id":"resp_1","object":"realtime.response","status":"completed"}}"#,
+        ];
+
+        let mut recorder = Recorder::default();
+        let mut done_flags = Vec::new();
+        for json in raw {
+            let event: ServerEvent = serde_json::from_str(json).unwrap();
+            done_flags.push(stream::dispatch_event(&mut recorder, &event));
+        }
+
+        // The audio delta uses the default no-op implementation
+        assert_eq!(recorder.events, vec!["text:Hel", "text:lo", "call:get_weather", "error:oops", "done:resp_1"]);
+        assert_eq!(done_flags, vec![false, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_handshake_request_sends_organization_and_project() {
+        use crate::common::auth::{AuthProvider, OpenAIAuth};
+
+        let auth = OpenAIAuth::new("sk-test").with_organization("org-123").with_project("proj_456");
+        let request = RealtimeClient::with_auth(AuthProvider::OpenAI(auth)).handshake_request().unwrap();
+        let headers = request.headers();
+        assert_eq!(headers["Authorization"], "Bearer sk-test");
+        assert_eq!(headers["OpenAI-Organization"], "org-123");
+        assert_eq!(headers["OpenAI-Project"], "proj_456");
+        assert_eq!(headers["OpenAI-Beta"], "realtime=v1");
+    }
+
+    #[test]
+    fn test_session_config_validate_modalities() {
+        // The server falls back to its default output audio format
+        let config = SessionConfig::new().with_modalities(vec![Modality::Text, Modality::Audio]);
+        assert!(config.validate().is_ok());
+
+        let err = SessionConfig::new().with_modalities(vec![]).validate().unwrap_err();
+        assert!(err.to_string().contains("at least one modality"));
+    }
+
+    #[test]
+    fn test_session_config_validate_rejects_text_only_vad_responses() {
+        let config = SessionConfig::new()
+            .with_modalities(vec![Modality::Text])
+            .with_turn_detection(TurnDetection::ServerVad(ServerVadConfig::new().with_create_response(true)));
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, crate::common::errors::OpenAIToolError::InvalidParameter(_)));
+        assert!(err.to_string().contains("text-only"));
+    }
+
+    #[test]
+    fn test_session_config_validate_rejects_unknown_forced_tool_and_temperature() {
+        let config = SessionConfig::new().with_tool_choice(ToolChoice::function("get_weather"));
+        assert!(config.validate().is_err());
+
+        let tool = RealtimeTool::function("get_weather", "Get the weather", vec![("city", ParameterProperty::from_string("City"))]);
+        assert!(config.with_realtime_tools(vec![tool]).validate().is_ok());
+
+        assert!(SessionConfig::new().with_temperature(2.0).validate().is_err());
+        assert!(SessionConfig::new().validate().is_ok());
+    }
+
+    #[test]
+    fn test_conversation_log_tracks_server_events() {
+        use conversation::ConversationLog;
+        use events::server::ServerEvent;
+
+        let events = [
+            r#"{"type":"conversation.item.created","event_id":"e1","previous_item_id":null,"item":{"id":"item_1","object":"realtime.item","type":"message","role":"user","content":[{"type":"input_text","text":"Hi"}]}}"#,
+            r#"{"type":"conversation.item.created","event_id":"e2","previous_item_id":"item_1","item":{"id":"item_2","object":"realtime.item","type":"message","role":"user","content":[{"type":"input_audio","transcript":null}]}}"#,
+            r#"{"type":"conversation.item.input_audio_transcription.completed","event_id":"e3","item_id":"item_2","content_index":0,"transcript":"What time is it?"}"#,
+            r#"{"type":"conversation.item.created","event_id":"e4","previous_item_id":"item_2","item":{"id":"item_3","object":"realtime.item","type":"message","role":"assistant","content":[]}}"#,
+            r#"{"type":"response.output_item.done","event_id":"e5","response_id":"resp_1","output_index":0,"item":{"id":"item_3","object":"realtime.item","type":"message","role":"assistant","content":[{"type":"audio","transcript":"It is noon."}]}}"#,
+            r#"{"type":"conversation.item.created","event_id":"e6","previous_item_id":"item_3","item":{"id":"item_4","object":"realtime.item","type":"message","role":"user","content":[{"type":"input_text","text":"Thanks"}]}}"#,
+            r#"{"type":"conversation.item.deleted","event_id":"e7","item_id":"item_1"}"#,
+        ];
+        let mut log = ConversationLog::default();
+        for event in events {
+            log.apply(&serde_json::from_str::<ServerEvent>(event).unwrap());
+        }
+
+        let items = serde_json::to_value(log.take_replayable()).unwrap();
+        assert_eq!(
+            items,
+            serde_json::json!([
+                {"type": "message", "role": "user", "content": [{"type": "input_text", "text": "What time is it?"}]},
+                {"type": "message", "role": "assistant", "content": [{"type": "text", "text": "It is noon."}]},
+                {"type": "message", "role": "user", "content": [{"type": "input_text", "text": "Thanks"}]},
+            ])
+        );
+        assert_eq!(log.items().count(), 0);
+    }
+
+    #[test]
+    fn test_session_config_merge_keeps_unset_fields() {
+        let mut config = SessionConfig::new().with_instructions("Be brief.").with_voice(Voice::Alloy);
+        config.merge(SessionConfig::new().with_voice(Voice::Echo));
+
+        assert_eq!(config.instructions.as_deref(), Some("Be brief."));
+        assert_eq!(config.voice, Some(Voice::Echo));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_session_config_and_items() {
+        use crate::common::auth::{AuthProvider, AzureAuth};
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+        const SESSION_CREATED: &str = r#"{"type":"session.created","event_id":"e0","session":{"id":"sess_1","object":"realtime.session","model":"gpt-realtime","voice":null,"input_audio_format":null,"output_audio_format":null,"turn_detection":null,"tool_choice":null,"temperature":null,"max_response_output_tokens":null}}"#;
+        const ITEM_CREATED: &str = r#"{"type":"conversation.item.created","event_id":"e1","previous_item_id":null,"item":{"id":"item_1","object":"realtime.item","type":"message","role":"user","content":[{"type":"input_text","text":"Remember 42"}]}}"#;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/v1/realtime", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            // First connection: echo the created item, then drop the socket
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.send(WsMessage::Text(SESSION_CREATED.into())).await.unwrap();
+            // session.update (client config), session.update (voice), conversation.item.create
+            for _ in 0..3 {
+                ws.next().await.unwrap().unwrap();
+            }
+            ws.send(WsMessage::Text(ITEM_CREATED.into())).await.unwrap();
+            drop(ws);
+
+            // Second connection: capture the replayed events
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.send(WsMessage::Text(SESSION_CREATED.into())).await.unwrap();
+            let mut replayed = Vec::new();
+            for _ in 0..2 {
+                let message = ws.next().await.unwrap().unwrap();
+                replayed.push(serde_json::from_str::<serde_json::Value>(message.to_text().unwrap()).unwrap());
+            }
+            replayed
+        });
+
+        let mut client = RealtimeClient::with_auth(AuthProvider::Azure(AzureAuth::new("test-key".to_string(), url)));
+        client.instructions("You are a helpful assistant.");
+        let mut session = client.connect().await.unwrap();
+        session.update_session(SessionConfig::new().with_voice(Voice::Echo)).await.unwrap();
+        session.send_text("Remember 42").await.unwrap();
+
+        assert!(matches!(session.recv().await.unwrap(), Some(events::server::ServerEvent::ConversationItemCreated(_))));
+        assert_eq!(session.conversation_items().count(), 1);
+
+        session.reconnect().await.unwrap();
+        let replayed = server.await.unwrap();
+
+        assert_eq!(replayed[0]["type"], "session.update");
+        assert_eq!(replayed[0]["session"]["instructions"], "You are a helpful assistant.");
+        assert_eq!(replayed[0]["session"]["voice"], "echo");
+        assert_eq!(replayed[1]["type"], "conversation.item.create");
+        assert_eq!(replayed[1]["item"]["content"][0]["text"], "Remember 42");
+    }
+
+    #[test]
+    fn test_large_audio_buffer_is_split_into_multiple_appends() {
+        use base64::prelude::*;
+
+        // 40 seconds of 24kHz audio
+        let samples: Vec<i16> = (0..REALTIME_SAMPLE_RATE * 40).map(|i| (i % 1000) as i16).collect();
+        let events = audio::pcm16_append_events(&samples);
+        assert_eq!(events.len(), 3);
+
+        let mut decoded = Vec::new();
+        for event in &events {
+            let json = serde_json::to_value(event).unwrap();
+            assert_eq!(json["type"], "input_audio_buffer.append");
+            let bytes = BASE64_STANDARD.decode(json["audio"].as_str().unwrap()).unwrap();
+            assert!(bytes.len() <= (REALTIME_SAMPLE_RATE * MAX_APPEND_SECONDS * 2) as usize);
+            decoded.extend(bytes.chunks(2).map(|b| i16::from_le_bytes([b[0], b[1]])));
+        }
+        assert_eq!(decoded, samples);
+    }
+
+    #[test]
+    fn test_resampler_converts_to_realtime_rate_across_frames() {
+        let mut resampler = audio::Resampler::new(16_000, REALTIME_SAMPLE_RATE);
+        let ramp: Vec<i16> = (0..16_000).map(|i| i as i16).collect();
+
+        let output: Vec<i16> = ramp.chunks(320).flat_map(|frame| resampler.process(frame)).collect();
+        assert!((output.len() as i64 - 24_000).abs() <= 2);
+        // A linear ramp stays linear: every output step is 2/3 of an input step
+        assert!(output.windows(2).all(|w| (w[1] - w[0]) == 0 || (w[1] - w[0]) == 1));
+        assert_eq!(output[3], 2);
+
+        let mut passthrough = audio::Resampler::new(REALTIME_SAMPLE_RATE, REALTIME_SAMPLE_RATE);
+        assert_eq!(passthrough.process(&[1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_event_handler_run_dispatches_until_response_done() {
+        use crate::common::auth::{AuthProvider, AzureAuth};
+        use futures_util::SinkExt;
+        use std::sync::{Arc, Mutex};
+        use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+        let events = [
+            r#"{"type":"session.created","event_id":"e0","session":{"id":"sess_1","object":"realtime.session","model":"gpt-realtime","voice":null,"input_audio_format":null,"output_audio_format":null,"turn_detection":null,"tool_choice":null,"temperature":null,"max_response_output_tokens":null}}"#,
+            r#"{"type":"response.text.delta","event_id":"e1","response_id":"resp_1","item_id":"item_1","output_index":0,"content_index":0,"delta":"Hi"}"#,
+            r#"{"type":"response.function_call_arguments.done","event_id":"e2","response_id":"resp_1","item_id":"item_2","output_index":1,"call_id":"call_1","name":"get_weather","arguments":"{}"}"#,
+            r#"{"type":"response.done","event_id":"e3","response":{"id":"resp_1","object":"realtime.response","status":"completed"}}"#,
+            r#"{"type":"response.text.delta","event_id":"e4","response_id":"resp_2","item_id":"item_3","output_index":0,"content_index":0,"delta":"after"}"#,
+        ];
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/v1/realtime", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            for event in events {
+                ws.send(WsMessage::Text(event.into())).await.unwrap();
+            }
+            // Keep the connection open until the client is done
+            let _ = futures_util::StreamExt::next(&mut ws).await;
+        });
+
+        let client = RealtimeClient::with_auth(AuthProvider::Azure(AzureAuth::new("test-key".to_string(), url)));
+        let mut session = client.connect().await.unwrap();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut handler = EventHandler::new();
+        let text_seen = seen.clone();
+        let call_seen = seen.clone();
+        handler
+            .on_text_delta(move |e| text_seen.lock().unwrap().push(format!("text:{}", e.delta)))
+            .on_function_call(move |e| call_seen.lock().unwrap().push(format!("call:{}", e.name)));
+        handler.run(&mut session).await.unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec!["text:Hi".to_string(), "call:get_weather".to_string()]);
+        // Events after response.done are left for the next run
+        assert!(matches!(session.recv().await.unwrap(), Some(events::server::ServerEvent::ResponseTextDelta(_))));
+    }
+
+    #[test]
+    fn test_transcription_only_session_config() {
+        let mut client = RealtimeClient::with_auth(crate::common::auth::AuthProvider::OpenAI(crate::common::auth::OpenAIAuth::new("sk-test")));
+        client.transcription_only();
+        let config = client.session_config();
+
+        config.validate().unwrap();
+        let json = serde_json::to_value(config).unwrap();
+        assert_eq!(json["modalities"], serde_json::json!(["text"]));
+        assert_eq!(json["input_audio_format"], "pcm16");
+        assert_eq!(json["input_audio_transcription"]["model"], "whisper-1");
+        assert_eq!(json["turn_detection"], serde_json::json!({"type": "server_vad", "create_response": false}));
+    }
+
+    #[test]
+    fn test_server_event_transcription_filter() {
+        use events::server::ServerEvent;
+
+        let raw = [
+            (
+                r#"{"type":"conversation.item.input_audio_transcription.delta","event_id":"e1","item_id":"item_1","content_index":0,"delta":"Hel"}"#,
+                true,
+            ),
+            (
+                r#"{"type":"conversation.item.input_audio_transcription.completed","event_id":"e2","item_id":"item_1","content_index":0,"transcript":"Hello"}"#,
+                true,
+            ),
+            (r#"{"type":"input_audio_buffer.speech_started","event_id":"e3","audio_start_ms":0,"item_id":"item_2"}"#, false),
+        ];
+        for (json, expected) in raw {
+            let event: ServerEvent = serde_json::from_str(json).unwrap();
+            assert_eq!(event.is_transcription(), expected, "{}", json);
+        }
+    }
 }
@@ -1,5 +1,7 @@
 //! Session configuration types for the Realtime API.
 
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::function::Function;
 use crate::common::parameters::{Name, ParameterProperty, Parameters};
 use crate::common::tool::Tool;
 use serde::{Deserialize, Serialize};
@@ -73,6 +75,17 @@ impl From<Tool> for RealtimeTool {
     }
 }
 
+impl From<RealtimeTool> for Tool {
+    /// Convert a Realtime API tool to a Chat API tool.
+    fn from(tool: RealtimeTool) -> Self {
+        if tool.type_name == "function" {
+            Function { name: tool.name, description: tool.description, parameters: tool.parameters, ..Default::default() }.into()
+        } else {
+            Self { type_name: tool.type_name, name: Some(tool.name), parameters: tool.parameters, ..Default::default() }
+        }
+    }
+}
+
 /// Session modality - what types of input/output are supported.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -214,6 +227,69 @@ impl SessionConfig {
         self.max_response_output_tokens = Some(max);
         self
     }
+
+    /// Apply a partial update, overwriting the fields set in `update`.
+    ///
+    /// Mirrors how the server applies `session.update` events.
+    pub(crate) fn merge(&mut self, update: SessionConfig) {
+        self.modalities = update.modalities.or(self.modalities.take());
+        self.instructions = update.instructions.or(self.instructions.take());
+        self.voice = update.voice.or(self.voice.take());
+        self.input_audio_format = update.input_audio_format.or(self.input_audio_format.take());
+        self.output_audio_format = update.output_audio_format.or(self.output_audio_format.take());
+        self.input_audio_transcription = update.input_audio_transcription.or(self.input_audio_transcription.take());
+        self.input_audio_noise_reduction = update.input_audio_noise_reduction.or(self.input_audio_noise_reduction.take());
+        self.turn_detection = update.turn_detection.or(self.turn_detection.take());
+        self.tools = update.tools.or(self.tools.take());
+        self.tool_choice = update.tool_choice.or(self.tool_choice.take());
+        self.temperature = update.temperature.or(self.temperature.take());
+        self.max_response_output_tokens = update.max_response_output_tokens.or(self.max_response_output_tokens.take());
+    }
+
+    /// Check the configuration for combinations the server is known to reject.
+    ///
+    /// Called by [`RealtimeClient::connect`](super::RealtimeClient::connect) so invalid
+    /// sessions fail fast with a descriptive error instead of a server error event.
+    /// The following are rejected:
+    ///
+    /// - an empty modality list
+    /// - turn detection with `create_response` enabled in a text-only session
+    /// - a temperature outside 0.6 to 1.2
+    /// - a tool choice forcing a function that is not among the session tools
+    ///
+    /// # Returns
+    ///
+    /// `Err(OpenAIToolError::InvalidParameter)` describing the first problem found
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(OpenAIToolError::InvalidParameter(message));
+
+        if let Some(modalities) = &self.modalities {
+            if modalities.is_empty() {
+                return invalid("modalities must contain at least one modality".to_string());
+            }
+            let audio = modalities.contains(&Modality::Audio);
+            let create_response = match &self.turn_detection {
+                Some(TurnDetection::ServerVad(config)) => config.create_response,
+                Some(TurnDetection::SemanticVad(config)) => config.create_response,
+                None => None,
+            };
+            if !audio && create_response == Some(true) {
+                return invalid("turn detection with create_response requires the audio modality, but the session is text-only".to_string());
+            }
+        }
+        if let Some(temperature) = self.temperature {
+            if !(0.6..=1.2).contains(&temperature) {
+                return invalid(format!("temperature must be between 0.6 and 1.2, got {}", temperature));
+            }
+        }
+        if let Some(ToolChoice::Function(choice)) = &self.tool_choice {
+            let name = &choice.function.name;
+            if !self.tools.iter().flatten().any(|tool| &tool.name == name) {
+                return invalid(format!("tool_choice forces function '{}', which is not among the session tools", name));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Maximum tokens configuration.
@@ -1,35 +1,51 @@
 //! Event handler utilities for the Realtime API.
 
+use super::client::RealtimeSession;
 use super::events::server::*;
+use crate::common::errors::Result;
 
 /// Callback-based event handler for processing server events.
 ///
+/// Register closures for the events of interest, then either pass each event to
+/// [`handle`](Self::handle) from your own loop or let [`run`](Self::run) receive
+/// and dispatch events until the response is done.
+///
 /// # Example
 ///
 /// ```rust,no_run
-/// use openai_tools::realtime::EventHandler;
-/// use openai_tools::realtime::events::server::ServerEvent;
+/// use openai_tools::realtime::{EventHandler, RealtimeClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut session = RealtimeClient::new().connect().await?;
 ///
-/// let mut handler = EventHandler::new();
-/// handler
-///     .on_text_delta(|e| {
-///         print!("{}", e.delta);
-///     })
-///     .on_audio_delta(|e| {
-///         // Play audio: base64::decode(&e.delta)
-///     })
-///     .on_error(|e| {
-///         eprintln!("Error: {}", e.error.message);
-///     });
+///     let mut handler = EventHandler::new();
+///     handler
+///         .on_text_delta(|e| {
+///             print!("{}", e.delta);
+///         })
+///         .on_audio_delta(|e| {
+///             // Play audio: base64::decode(&e.delta)
+///         })
+///         .on_function_call(|e| {
+///             println!("Call {}({})", e.name, e.arguments);
+///         })
+///         .on_error(|e| {
+///             eprintln!("Error: {}", e.error.message);
+///         });
 ///
-/// // In your event loop:
-/// // handler.handle(&event);
+///     session.send_text("Hello!").await?;
+///     session.create_response(None).await?;
+///     handler.run(&mut session).await?;
+///     Ok(())
+/// }
 /// ```
 #[allow(clippy::type_complexity)]
 pub struct EventHandler {
     on_session_created: Option<Box<dyn Fn(&SessionCreatedEvent) + Send + Sync>>,
     on_session_updated: Option<Box<dyn Fn(&SessionUpdatedEvent) + Send + Sync>>,
     on_conversation_item_created: Option<Box<dyn Fn(&ConversationItemCreatedEvent) + Send + Sync>>,
+    on_input_audio_transcription_delta: Option<Box<dyn Fn(&InputAudioTranscriptionDeltaEvent) + Send + Sync>>,
     on_input_audio_transcription_completed: Option<Box<dyn Fn(&InputAudioTranscriptionCompletedEvent) + Send + Sync>>,
     on_speech_started: Option<Box<dyn Fn(&SpeechStartedEvent) + Send + Sync>>,
     on_speech_stopped: Option<Box<dyn Fn(&SpeechStoppedEvent) + Send + Sync>>,
@@ -54,6 +70,7 @@ impl EventHandler {
             on_session_created: None,
             on_session_updated: None,
             on_conversation_item_created: None,
+            on_input_audio_transcription_delta: None,
             on_input_audio_transcription_completed: None,
             on_speech_started: None,
             on_speech_stopped: None,
@@ -99,6 +116,15 @@ impl EventHandler {
         self
     }
 
+    /// Set callback for input audio transcription delta events.
+    pub fn on_input_audio_transcription_delta<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&InputAudioTranscriptionDeltaEvent) + Send + Sync + 'static,
+    {
+        self.on_input_audio_transcription_delta = Some(Box::new(f));
+        self
+    }
+
     /// Set callback for input audio transcription completed events.
     pub fn on_input_audio_transcription_completed<F>(&mut self, f: F) -> &mut Self
     where
@@ -225,6 +251,17 @@ impl EventHandler {
         self
     }
 
+    /// Set callback for completed function calls.
+    ///
+    /// Equivalent to [`on_function_call_arguments_done`](Self::on_function_call_arguments_done):
+    /// the callback receives the function name, call ID and complete arguments.
+    pub fn on_function_call<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&ResponseFunctionCallArgumentsDoneEvent) + Send + Sync + 'static,
+    {
+        self.on_function_call_arguments_done(f)
+    }
+
     /// Set callback for error events.
     pub fn on_error<F>(&mut self, f: F) -> &mut Self
     where
@@ -252,6 +289,11 @@ impl EventHandler {
                     f(e);
                 }
             }
+            ServerEvent::InputAudioTranscriptionDelta(e) => {
+                if let Some(f) = &self.on_input_audio_transcription_delta {
+                    f(e);
+                }
+            }
             ServerEvent::InputAudioTranscriptionCompleted(e) => {
                 if let Some(f) = &self.on_input_audio_transcription_completed {
                     f(e);
@@ -331,6 +373,24 @@ impl EventHandler {
             _ => {}
         }
     }
+
+    /// Receive events from `session` and dispatch them until the response is done.
+    ///
+    /// Shorthand for [`RealtimeSession::run`] with this handler. Returns once a
+    /// `response.done` event has been handled or the connection is closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The session to receive events from
+    pub async fn run(&mut self, session: &mut RealtimeSession) -> Result<()> {
+        session.run(self).await
+    }
+}
+
+impl RealtimeEventHandler for EventHandler {
+    fn on_event(&mut self, event: &ServerEvent) {
+        self.handle(event);
+    }
 }
 
 impl Default for EventHandler {
@@ -338,3 +398,80 @@ impl Default for EventHandler {
         Self::new()
     }
 }
+
+/// Trait-based event handler for driving a session with [`RealtimeSession::run`].
+///
+/// Every callback has a default no-op implementation, so implementors only
+/// override the events they care about.
+///
+/// [`RealtimeSession::run`]: super::RealtimeSession::run
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use openai_tools::realtime::{RealtimeClient, RealtimeEventHandler};
+/// use openai_tools::realtime::events::server::{ResponseDoneEvent, ResponseTextDeltaEvent};
+///
+/// struct Printer;
+///
+/// impl RealtimeEventHandler for Printer {
+///     fn on_text_delta(&mut self, event: &ResponseTextDeltaEvent) {
+///         print!("{}", event.delta);
+///     }
+///
+///     fn on_done(&mut self, _event: &ResponseDoneEvent) {
+///         println!();
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = RealtimeClient::new();
+///     let mut session = client.connect().await?;
+///
+///     session.send_text("Hello!").await?;
+///     session.create_response(None).await?;
+///     session.run(&mut Printer).await?;
+///
+///     session.close().await?;
+///     Ok(())
+/// }
+/// ```
+pub trait RealtimeEventHandler {
+    /// Called for every server event, before the event-specific callback below.
+    fn on_event(&mut self, _event: &ServerEvent) {}
+
+    /// Called for each `response.text.delta` event.
+    fn on_text_delta(&mut self, _event: &ResponseTextDeltaEvent) {}
+
+    /// Called for each `response.audio.delta` event.
+    fn on_audio_delta(&mut self, _event: &ResponseAudioDeltaEvent) {}
+
+    /// Called when a function call's arguments are complete (`response.function_call_arguments.done`).
+    fn on_function_call(&mut self, _event: &ResponseFunctionCallArgumentsDoneEvent) {}
+
+    /// Called for each `error` event.
+    fn on_error(&mut self, _event: &ErrorEvent) {}
+
+    /// Called when the response is complete (`response.done`).
+    fn on_done(&mut self, _event: &ResponseDoneEvent) {}
+}
+
+/// Dispatch a server event to a [`RealtimeEventHandler`].
+///
+/// Returns `true` when the event is `response.done`, signalling the end of the response.
+pub(crate) fn dispatch_event<H: RealtimeEventHandler + ?Sized>(handler: &mut H, event: &ServerEvent) -> bool {
+    handler.on_event(event);
+    match event {
+        ServerEvent::ResponseTextDelta(e) => handler.on_text_delta(e),
+        ServerEvent::ResponseAudioDelta(e) => handler.on_audio_delta(e),
+        ServerEvent::ResponseFunctionCallArgumentsDone(e) => handler.on_function_call(e),
+        ServerEvent::Error(e) => handler.on_error(e),
+        ServerEvent::ResponseDone(e) => {
+            handler.on_done(e);
+            return true;
+        }
+        _ => {}
+    }
+    false
+}
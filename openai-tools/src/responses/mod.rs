@@ -13,6 +13,7 @@
 //! - **Tool Integration**: Function calling capabilities with custom tools
 //! - **Flexible Instructions**: System-level instructions for AI behavior
 //! - **Rich Content Handling**: Support for complex message structures
+//! - **Streaming**: Server-sent events, including live reasoning summaries
 //!
 //! # Quick Start
 //!
@@ -253,6 +254,8 @@
 
 pub mod request;
 pub mod response;
+pub mod session;
+pub mod stream;
 
 #[cfg(test)]
 mod tests {
@@ -262,6 +265,7 @@ mod tests {
         parameters::ParameterProperty,
         role::Role,
         structured_output::Schema,
+        test_utils::null_paths,
         tool::Tool,
     };
     use crate::responses::request::{Include, ReasoningEffort, ReasoningSummary, Responses, TextConfig, TextVerbosity, Truncation};
@@ -296,6 +300,16 @@ mod tests {
         assert_eq!(responses.request_body.messages_input.as_ref().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_responses_builder_messages_from() {
+        let mut responses = Responses::new();
+        responses.messages_from([(Role::System, "Be brief."), (Role::User, "Hello!")]);
+        let messages = responses.request_body.messages_input.as_ref().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].role, Role::System);
+        assert_eq!(messages[1].content.as_ref().unwrap().text.as_deref(), Some("Hello!"));
+    }
+
     #[test]
     fn test_responses_builder_tools() {
         let mut responses = Responses::new();
@@ -310,6 +324,25 @@ mod tests {
         assert_eq!(responses.request_body.tools.as_ref().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_function_tools_are_flattened() {
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4oMini).str_message("What's the weather in Tokyo?").tools(vec![
+            Tool::function("get_weather", "Get the weather", vec![("city", ParameterProperty::from_string("City name"))], true),
+            Tool::web_search_preview(),
+        ]);
+
+        let json: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        let function = &json["tools"][0];
+        assert_eq!(function["type"], "function");
+        assert_eq!(function["name"], "get_weather");
+        assert_eq!(function["description"], "Get the weather");
+        assert_eq!(function["parameters"]["properties"]["city"]["type"], "string");
+        assert_eq!(function["strict"], true);
+        assert!(function.get("function").is_none());
+        assert_eq!(json["tools"][1], serde_json::json!({"type": "web_search_preview"}));
+    }
+
     #[test]
     fn test_responses_builder_structured_output() {
         let mut responses = Responses::new();
@@ -619,6 +652,29 @@ mod tests {
         assert!(json_body.contains("\"verbosity\":\"high\""));
     }
 
+    #[test]
+    fn test_structured_output_and_verbosity_share_text_object() {
+        let mut schema = Schema::responses_json_schema("answer");
+        schema.add_property("value", "string", "The answer");
+
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt5_2).str_message("Test").structured_output(schema).text_verbosity(TextVerbosity::Low);
+
+        let json_body = responses.build_request_json().unwrap();
+        assert_eq!(json_body.matches("\"text\":").count(), 1, "duplicate text key in {}", json_body);
+        let json: serde_json::Value = serde_json::from_str(&json_body).unwrap();
+        assert_eq!(json["text"]["verbosity"], "low");
+        assert_eq!(json["text"]["format"]["type"], "json_schema");
+        assert_eq!(json["text"]["format"]["name"], "answer");
+
+        // Each also serializes on its own
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt5_2).str_message("Test").structured_output(Schema::responses_json_schema("answer"));
+        let json: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["text"]["format"]["name"], "answer");
+        assert!(json["text"].get("verbosity").is_none());
+    }
+
     // ================================================
     // Tests for new ToolChoice, Prompt, and endpoint types
     // ================================================
@@ -1097,6 +1153,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_metadata_limits_checked_before_sending() {
+        use crate::common::errors::OpenAIToolError;
+        use crate::common::metadata::Metadata;
+
+        let mut metadata = Metadata::new();
+        metadata.insert("user_id", "user123").unwrap();
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4oMini).str_message("Hi").metadata_map(metadata);
+        let body: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        assert_eq!(body["metadata"], serde_json::json!({"user_id": "user123"}));
+
+        responses.metadata("note".to_string(), serde_json::Value::String("x".repeat(513)));
+        let err = responses.build_request_json().unwrap_err();
+        assert!(matches!(&err, OpenAIToolError::InvalidParameter(message) if message.contains("'note'")), "unexpected error: {}", err);
+
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4oMini).str_message("Hi");
+        for i in 0..17 {
+            responses.metadata(format!("key{}", i), serde_json::Value::String(i.to_string()));
+        }
+        assert!(matches!(responses.build_request_json(), Err(OpenAIToolError::InvalidParameter(_))));
+
+        // Plain maps are accepted as well, and non-string values are rejected
+        let mut responses = Responses::new();
+        responses
+            .model(ChatModel::Gpt4oMini)
+            .str_message("Hi")
+            .metadata_map(std::collections::HashMap::from([("topic".to_string(), "demo".to_string())]));
+        assert!(responses.build_request_json().is_ok());
+        responses.metadata("priority".to_string(), serde_json::Value::from(1));
+        let err = responses.build_request_json().unwrap_err();
+        assert!(matches!(&err, OpenAIToolError::InvalidParameter(message) if message.contains("must be a string")), "unexpected error: {}", err);
+    }
+
     #[test]
     fn test_include_accepted_by_all_models() {
         let models = vec![ChatModel::Gpt4o, ChatModel::O1Pro, ChatModel::Gpt5_2Pro];
@@ -1109,4 +1200,652 @@ mod tests {
             assert!(responses.request_body.include.is_some(), "Model {} should accept include parameter", model.as_str());
         }
     }
+
+    #[test]
+    fn test_responses_preview() {
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4oMini);
+        responses.str_message("Hello, world!");
+
+        // 3 (message overhead) + 1 (role) + 4 (content) + 3 (reply priming)
+        let preview = responses.preview();
+        assert_eq!(preview.estimated_prompt_tokens, 11);
+        assert!(preview.estimated_cost.is_some());
+
+        // Instructions add their own message
+        responses.instructions("Be brief.");
+        assert!(responses.preview().estimated_prompt_tokens > 11);
+    }
+
+    // =============================================================================
+    // Streaming Tests
+    // =============================================================================
+
+    #[test]
+    fn test_stream_reasoning_summary_events_parse() {
+        use crate::responses::stream::ResponseStreamEvent;
+
+        let delta = r#"{"type":"response.reasoning_summary_text.delta","sequence_number":4,"item_id":"rs_123","output_index":0,"summary_index":0,"delta":"**Considering"}"#;
+        match serde_json::from_str::<ResponseStreamEvent>(delta).unwrap() {
+            ResponseStreamEvent::ReasoningSummaryTextDelta(e) => {
+                assert_eq!(e.item_id, "rs_123");
+                assert_eq!(e.summary_index, 0);
+                assert_eq!(e.delta, "**Considering");
+                assert_eq!(e.sequence_number, Some(4));
+            }
+            other => panic!("Expected ReasoningSummaryTextDelta, got {:?}", other),
+        }
+
+        let done = r#"{"type":"response.reasoning_summary_text.done","sequence_number":9,"item_id":"rs_123","output_index":0,"summary_index":0,"text":"**Considering the question**"}"#;
+        match serde_json::from_str::<ResponseStreamEvent>(done).unwrap() {
+            ResponseStreamEvent::ReasoningSummaryTextDone(e) => assert_eq!(e.text, "**Considering the question**"),
+            other => panic!("Expected ReasoningSummaryTextDone, got {:?}", other),
+        }
+
+        let part = r#"{"type":"response.reasoning_summary_part.added","item_id":"rs_123","output_index":0,"summary_index":1,"part":{"type":"summary_text","text":""}}"#;
+        match serde_json::from_str::<ResponseStreamEvent>(part).unwrap() {
+            ResponseStreamEvent::ReasoningSummaryPartAdded(e) => {
+                assert_eq!(e.summary_index, 1);
+                assert_eq!(e.part.type_name, "summary_text");
+            }
+            other => panic!("Expected ReasoningSummaryPartAdded, got {:?}", other),
+        }
+
+        let unknown = r#"{"type":"response.some_future_event","item_id":"x"}"#;
+        assert!(matches!(serde_json::from_str::<ResponseStreamEvent>(unknown).unwrap(), ResponseStreamEvent::Unknown));
+    }
+
+    #[test]
+    fn test_reasoning_summary_accumulator() {
+        use crate::responses::stream::{ReasoningSummaryAccumulator, ResponseStreamEvent};
+
+        let events = [
+            r#"{"type":"response.reasoning_summary_text.delta","item_id":"rs_1","output_index":0,"summary_index":0,"delta":"First "}"#,
+            r#"{"type":"response.output_text.delta","item_id":"msg_1","output_index":1,"content_index":0,"delta":"ignored"}"#,
+            r#"{"type":"response.reasoning_summary_text.delta","item_id":"rs_1","output_index":0,"summary_index":0,"delta":"thought"}"#,
+            r#"{"type":"response.reasoning_summary_text.delta","item_id":"rs_1","output_index":0,"summary_index":1,"delta":"Second"}"#,
+            r#"{"type":"response.reasoning_summary_text.done","item_id":"rs_1","output_index":0,"summary_index":1,"text":"Second thought"}"#,
+        ];
+
+        let mut accumulator = ReasoningSummaryAccumulator::default();
+        let mut live = String::new();
+        for json in events {
+            let event: ResponseStreamEvent = serde_json::from_str(json).unwrap();
+            if let Some(delta) = accumulator.push(&event) {
+                live.push_str(delta);
+            }
+        }
+
+        assert_eq!(live, "First thoughtSecond");
+        assert_eq!(accumulator.parts(), vec!["First thought", "Second thought"]);
+        assert_eq!(accumulator.text(), "First thought\n\nSecond thought");
+    }
+
+    #[test]
+    fn test_sse_parser_handles_split_chunks() {
+        use crate::responses::stream::SseParser;
+
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"event: response.output_text.delta\ndata: {\"a\":").is_empty());
+        assert_eq!(parser.feed(b"1}\n\nevent: x\ndata: {\"b\":2}\n\n"), vec!["{\"a\":1}", "{\"b\":2}"]);
+    }
+
+    #[test]
+    fn test_sse_parser_reassembles_events_split_at_any_byte() {
+        use crate::responses::stream::SseParser;
+
+        // CRLF and CR line breaks, a comment, a multi-line data field and multi-byte characters
+        let body = "event: a\r\ndata: {\"text\": \"caf\u{e9} \u{1f600}\"}\r\n\r\n: keep-alive\n\ndata: line 1\ndata:line 2\n\ndata: cr\r\r";
+        let expected = vec!["{\"text\": \"caf\u{e9} \u{1f600}\"}", "line 1\nline 2", "cr"];
+
+        for split in 0..=body.len() {
+            let mut parser = SseParser::default();
+            let mut events = parser.feed(&body.as_bytes()[..split]);
+            events.extend(parser.feed(&body.as_bytes()[split..]));
+            assert_eq!(events, expected, "split at byte {}", split);
+        }
+
+        let mut parser = SseParser::default();
+        let events: Vec<String> = body.as_bytes().chunks(1).flat_map(|byte| parser.feed(byte)).collect();
+        assert_eq!(events, expected);
+    }
+
+    #[test]
+    fn test_sse_parser_ends_on_done_sentinel() {
+        use crate::responses::stream::SseParser;
+
+        let mut parser = SseParser::default();
+        let events = parser.feed(b": ping\r\n\r\n\r\ndata: {\"a\":1}  \r\n\r\ndata:   \r\n\r\n: ping\r\ndata: [DONE]\r\n\r\ndata: {\"b\":2}\r\n\r\n");
+        assert_eq!(events, vec!["{\"a\":1}  "]);
+        assert!(parser.is_done());
+        assert!(parser.feed(b"data: {\"c\":3}\n\n").is_empty());
+        assert_eq!(parser.finish(), None);
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_ends_cleanly_on_done() {
+        use crate::responses::stream::{parse_event_stream, ResponseStreamEvent};
+        use futures_util::StreamExt;
+
+        let body = concat!(
+            ": keep-alive\r\n\r\n",
+            "event: response.output_text.delta\r\n",
+            "data: {\"type\":\"response.output_text.delta\",\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Hi\"}\r\n\r\n",
+            ": ping\r\n\r\n",
+            "data: [DONE]\r\n\r\n",
+            "\r\n \r\n",
+        );
+        // The body never ends: the stream must stop at [DONE] instead of waiting for more data
+        let chunks =
+            futures_util::stream::iter([Ok::<_, request::Error>(bytes::Bytes::from_static(body.as_bytes()))]).chain(futures_util::stream::pending());
+
+        let events: Vec<_> = parse_event_stream(Box::pin(chunks)).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], Ok(ResponseStreamEvent::OutputTextDelta(e)) if e.delta == "Hi"));
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_across_chunk_boundaries() {
+        use crate::responses::stream::{parse_event_stream, ResponseStreamEvent};
+        use futures_util::StreamExt;
+
+        let body = concat!(
+            "event: response.output_text.delta\n",
+            "data: {\"type\":\"response.output_text.delta\",\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Gr\u{fc}\u{df}e\"}\n\n",
+            "event: response.output_text.delta\n",
+            "data: {\"type\":\"response.output_text.delta\",\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"!\"}",
+        );
+        // 3-byte chunks split the events, the lines and the UTF-8 characters; the last event has no trailing blank line
+        let chunks: Vec<std::result::Result<bytes::Bytes, request::Error>> =
+            body.as_bytes().chunks(3).map(|chunk| Ok(bytes::Bytes::copy_from_slice(chunk))).collect();
+
+        let events: Vec<_> = parse_event_stream(futures_util::stream::iter(chunks)).collect().await;
+        let deltas: Vec<String> = events
+            .into_iter()
+            .map(|event| match event.unwrap() {
+                ResponseStreamEvent::OutputTextDelta(e) => e.delta,
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect();
+        assert_eq!(deltas, ["Gr\u{fc}\u{df}e", "!"]);
+    }
+
+    #[test]
+    fn test_serialized_body_has_no_null_fields() {
+        use crate::responses::request::Reasoning;
+
+        let mut responses = Responses::new();
+        let mut schema = Schema::responses_json_schema("answer");
+        schema.add_property("value", "string", "");
+        let mut image_only = Message::from_string(Role::User, "");
+        image_only.content = Some(Content::from_image_url("https://example.com/a.png"));
+        responses
+            .model(ChatModel::Gpt4oMini)
+            .messages(vec![Message::from_string(Role::User, "Describe this image"), image_only])
+            .tools(vec![Tool::function("lookup", "Look something up", vec![("q", ParameterProperty::from_string("Query"))], false)])
+            .structured_output(schema)
+            .max_output_tokens(100);
+        responses.request_body.reasoning = Some(Reasoning { effort: Some(ReasoningEffort::Low), summary: None });
+        responses.request_body.text = Some(TextConfig { verbosity: None });
+
+        let value = serde_json::to_value(&responses.request_body).unwrap();
+        assert_eq!(null_paths(&value, "$"), Vec::<String>::new());
+        assert_eq!(value["reasoning"], serde_json::json!({"effort": "low"}));
+        assert_eq!(value["input"][1]["content"][0]["type"], "input_image");
+        assert!(value.get("temperature").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_with_new_conversation_wires_created_id() {
+        use crate::common::mock_server;
+        use crate::conversations::request::Conversations;
+        use std::collections::HashMap;
+
+        let created = r#"{"id": "conv_abc123", "object": "conversation", "created_at": 1741900000, "metadata": {"topic": "demo"}}"#;
+        let (base_url, server) = mock_server::serve(vec![(200, created.to_string())]).await;
+        let conversations = Conversations::with_url(base_url, "sk-test".to_string());
+
+        let mut responses = Responses::new();
+        let metadata = HashMap::from([("topic".to_string(), "demo".to_string())]);
+        responses.with_new_conversation(&conversations, Some(metadata)).await.unwrap();
+
+        assert_eq!(responses.request_body.conversation.as_deref(), Some("conv_abc123"));
+        assert_eq!(responses.request_body.store, Some(true));
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /v1/conversations HTTP/1.1");
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["metadata"]["topic"], "demo");
+    }
+
+    #[tokio::test]
+    async fn test_with_new_conversation_propagates_error() {
+        use crate::common::mock_server;
+        use crate::conversations::request::Conversations;
+
+        let error = r#"{"error": {"message": "Invalid API key", "type": "invalid_request_error"}}"#;
+        let (base_url, _server) = mock_server::serve(vec![(401, error.to_string())]).await;
+        let conversations = Conversations::with_url(base_url, "sk-test".to_string());
+
+        let mut responses = Responses::new();
+        let err = responses.with_new_conversation(&conversations, None).await.unwrap_err();
+        assert!(err.to_string().contains("Invalid API key"));
+        assert!(responses.request_body.conversation.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_polls_until_terminal() {
+        use crate::common::mock_server;
+        use std::time::Duration;
+
+        let queued = r#"{"id": "resp_bg1", "object": "response", "status": "queued", "background": true}"#;
+        let in_progress = r#"{"id": "resp_bg1", "object": "response", "status": "in_progress", "background": true}"#;
+        let completed = r#"{"id": "resp_bg1", "object": "response", "status": "completed", "background": true, "output": [
+            {"type": "message", "id": "msg_1", "role": "assistant", "content": [{"type": "output_text", "text": "Done."}]}
+        ]}"#;
+        let (base_url, server) =
+            mock_server::serve(vec![(200, queued.to_string()), (200, in_progress.to_string()), (200, completed.to_string())]).await;
+
+        let responses = Responses::with_url(base_url, "sk-test".to_string());
+        let response = responses.wait_for_completion("resp_bg1", Duration::from_millis(1), None).await.unwrap();
+
+        assert!(response.is_terminal());
+        assert_eq!(response.status.as_deref(), Some("completed"));
+        assert_eq!(response.output_text().as_deref(), Some("Done."));
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|r| r.request_line == "GET /v1/responses/resp_bg1 HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_rejects_missing_status() {
+        use crate::common::mock_server;
+        use std::time::Duration;
+
+        let no_status = r#"{"id": "resp_bg1", "object": "response"}"#;
+        let (base_url, _server) = mock_server::serve(vec![(200, no_status.to_string())]).await;
+
+        let responses = Responses::with_url(base_url, "sk-test".to_string());
+        let err = responses.wait_for_completion("resp_bg1", Duration::from_millis(1), None).await.unwrap_err();
+        assert!(err.to_string().contains("no status"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_times_out() {
+        use crate::common::mock_server;
+        use std::time::Duration;
+
+        let queued = r#"{"id": "resp_bg1", "object": "response", "status": "queued", "background": true}"#;
+        let (base_url, _server) = mock_server::serve(vec![(200, queued.to_string()), (200, queued.to_string())]).await;
+
+        let responses = Responses::with_url(base_url, "sk-test".to_string());
+        let err = responses.wait_for_completion("resp_bg1", Duration::from_millis(50), Some(Duration::from_millis(10))).await.unwrap_err();
+        assert!(err.to_string().contains("Timed out"), "unexpected error: {}", err);
+        assert!(err.to_string().contains("queued"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_delete_stored_response() {
+        use crate::common::mock_server;
+
+        let deleted = r#"{"id": "resp_abc123", "object": "response.deleted", "deleted": true}"#;
+        let (base_url, server) = mock_server::serve(vec![(200, deleted.to_string())]).await;
+
+        let responses = Responses::with_url(base_url, "sk-test".to_string());
+        let result = responses.delete("resp_abc123").await.unwrap();
+
+        assert_eq!(result.id, "resp_abc123");
+        assert_eq!(result.object, "response.deleted");
+        assert!(result.deleted);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "DELETE /v1/responses/resp_abc123 HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_list_all_input_items_follows_cursor() {
+        use crate::common::mock_server;
+        use futures_util::{pin_mut, StreamExt};
+
+        let first = r#"{"object": "list", "data": [
+            {"id": "msg_1", "type": "message", "role": "user", "status": "completed", "content": [{"type": "input_text", "text": "What is the weather in Tokyo?"}]},
+            {"id": "fc_1", "type": "function_call", "status": "completed", "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Tokyo\"}"}
+        ], "first_id": "msg_1", "last_id": "fc_1", "has_more": true}"#;
+        let second = r#"{"object": "list", "data": [
+            {"id": "fco_1", "type": "function_call_output", "call_id": "call_1", "output": "Sunny"}
+        ], "first_id": "fco_1", "last_id": "fco_1", "has_more": false}"#;
+        let (base_url, server) = mock_server::serve(vec![(200, first.to_string()), (200, second.to_string())]).await;
+
+        let responses = Responses::with_url(base_url, "sk-test".to_string());
+        let stream = responses.list_all_input_items("resp_abc123");
+        pin_mut!(stream);
+        let mut items = Vec::new();
+        while let Some(item) = stream.next().await {
+            items.push(item.unwrap());
+        }
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].text().as_deref(), Some("What is the weather in Tokyo?"));
+        assert_eq!(items[1].name.as_deref(), Some("get_weather"));
+        assert_eq!(items[1].arguments.as_deref(), Some(r#"{"city":"Tokyo"}"#));
+        assert_eq!(items[2].call_id.as_deref(), Some("call_1"));
+        assert_eq!(items[2].output, Some(serde_json::json!("Sunny")));
+        assert_eq!(items[2].text(), None);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /v1/responses/resp_abc123/input_items HTTP/1.1");
+        assert_eq!(requests[1].request_line, "GET /v1/responses/resp_abc123/input_items?after=fc_1 HTTP/1.1");
+    }
+
+    #[test]
+    fn test_input_items_accept_function_call_output_parts() {
+        use crate::responses::response::InputItemsListResponse;
+
+        let json = r#"{"object": "list", "data": [
+            {"id": "fco_1", "type": "function_call_output", "call_id": "call_1", "output": "Sunny"},
+            {"id": "fco_2", "type": "function_call_output", "call_id": "call_2", "output": [
+                {"type": "input_text", "text": "Chart attached"},
+                {"type": "input_image", "image_url": "https://example.com/chart.png"}
+            ]}
+        ], "first_id": "fco_1", "last_id": "fco_2", "has_more": false}"#;
+        let page: InputItemsListResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(page.data[0].output, Some(serde_json::json!("Sunny")));
+        let parts = page.data[1].output.as_ref().and_then(|output| output.as_array()).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0]["text"], "Chart attached");
+    }
+
+    #[tokio::test]
+    async fn test_previous_response_id_sends_only_new_turn() {
+        use crate::common::role::Role;
+        use crate::common::transport::MockTransport;
+
+        let reply = |id: &str| {
+            format!(
+                r#"{{"id": "{}", "object": "response", "status": "completed", "output": [
+                    {{"type": "message", "id": "msg_{}", "role": "assistant", "content": [{{"type": "output_text", "text": "OK"}}]}}
+                ]}}"#,
+                id, id
+            )
+        };
+        let transport = MockTransport::new();
+        transport.push_response(200, reply("resp_1")).push_response(200, reply("resp_2"));
+
+        // The same client is reused for the second turn
+        let mut responses = Responses::with_url("https://api.openai.com/v1", "sk-test");
+        responses.with_transport(transport.clone()).model(ChatModel::Gpt4oMini).str_message("My name is Alice.");
+        let first = responses.complete().await.unwrap();
+
+        responses.previous_response_id(first.id.unwrap()).messages(vec![Message::from_string(Role::User, "What's the weather like today?")]);
+        responses.complete().await.unwrap();
+
+        let second = transport.requests()[1].body_json().unwrap();
+        assert_eq!(second["previous_response_id"], "resp_1");
+        assert_eq!(second["input"], serde_json::json!([{"role": "user", "content": "What's the weather like today?"}]));
+    }
+
+    #[test]
+    fn test_plain_text_and_messages_input_are_exclusive() {
+        use crate::common::errors::OpenAIToolError;
+        use crate::common::role::Role;
+
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4oMini).messages(vec![Message::from_string(Role::User, "Old turn")]).str_message("New turn");
+        assert!(responses.request_body.messages_input.is_none());
+        let json: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["input"], "New turn");
+
+        responses.request_body.messages_input = Some(vec![Message::from_string(Role::User, "Old turn")]);
+        let err = responses.build_request_json().unwrap_err();
+        assert!(
+            matches!(&err, OpenAIToolError::Error(message) if message.contains("Both plain text input and messages")),
+            "unexpected error: {}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_session_chains_turns() {
+        use crate::common::mock_server;
+        use crate::common::role::Role;
+        use crate::responses::session::ChatSession;
+
+        let reply = |id: &str, text: &str| {
+            format!(
+                r#"{{"id": "{}", "object": "response", "status": "completed", "output": [
+                    {{"type": "message", "id": "msg_{}", "role": "assistant", "content": [{{"type": "output_text", "text": "{}"}}]}}
+                ]}}"#,
+                id, id, text
+            )
+        };
+        let (base_url, server) =
+            mock_server::serve(vec![(200, reply("resp_1", "Nice to meet you, Alice.")), (200, reply("resp_2", "Your name is Alice."))]).await;
+
+        let mut responses = Responses::with_url(base_url, "sk-test".to_string());
+        responses.model(ChatModel::Gpt4oMini).instructions("Be concise.");
+        let mut session = ChatSession::new(responses);
+
+        assert_eq!(session.send("My name is Alice.").await.unwrap(), "Nice to meet you, Alice.");
+        assert_eq!(session.send("What is my name?").await.unwrap(), "Your name is Alice.");
+        assert_eq!(session.previous_response_id(), Some("resp_2"));
+
+        let history = session.history();
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[0].role, Role::User);
+        assert_eq!(history[3].role, Role::Assistant);
+
+        let requests = server.await.unwrap();
+        let first: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        let second: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert!(first.get("previous_response_id").is_none());
+        assert_eq!(second["previous_response_id"], "resp_1");
+        assert_eq!(second["input"], "What is my name?");
+        assert_eq!(second["instructions"], "Be concise.");
+
+        session.reset();
+        assert!(session.history().is_empty());
+        assert_eq!(session.previous_response_id(), None);
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_serves_complete_and_retrieve() {
+        use crate::common::errors::OpenAIToolError;
+        use crate::common::transport::MockTransport;
+
+        let transport = MockTransport::new();
+        transport
+            .push_response(
+                200,
+                r#"{"id": "resp_1", "object": "response", "status": "completed", "output": [
+                    {"type": "message", "id": "msg_1", "role": "assistant", "content": [{"type": "output_text", "text": "Hello!"}]}
+                ]}"#,
+            )
+            .push_response(404, r#"{"error": {"message": "No response found", "type": "invalid_request_error"}}"#);
+
+        let mut responses = Responses::with_url("https://api.openai.com/v1", "sk-test");
+        responses.with_transport(transport.clone()).model(ChatModel::Gpt4oMini).str_message("Hi");
+
+        let response = responses.complete().await.unwrap();
+        assert_eq!(response.output_text().as_deref(), Some("Hello!"));
+        let err = responses.retrieve("resp_missing").await.unwrap_err();
+        assert!(matches!(err, OpenAIToolError::ApiError { status: 404, .. }), "unexpected error: {:?}", err);
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].method, request::Method::POST);
+        assert_eq!(requests[0].url, "https://api.openai.com/v1/responses");
+        assert_eq!(requests[0].headers["authorization"], "Bearer sk-test");
+        assert_eq!(requests[0].body_json().unwrap()["input"], "Hi");
+        assert_eq!(requests[1].method, request::Method::GET);
+        assert_eq!(requests[1].url, "https://api.openai.com/v1/responses/resp_missing");
+    }
+
+    #[tokio::test]
+    async fn test_complete_api_error_carries_request_id() {
+        use crate::common::errors::OpenAIToolError;
+        use crate::common::mock_server;
+
+        let error = r#"{"error": {"message": "Rate limit exceeded", "type": "rate_limit_error", "code": "rate_limit_exceeded"}}"#;
+        let (base_url, server) =
+            mock_server::serve_with_headers(vec![(429, vec![("x-request-id", "req_xyz789".to_string())], error.to_string())]).await;
+
+        let mut responses = Responses::with_url(base_url, "sk-test".to_string());
+        responses.model(ChatModel::Gpt4oMini).str_message("Hi");
+        let err = responses.complete().await.unwrap_err();
+
+        assert_eq!(err.request_id(), Some("req_xyz789"));
+        assert!(err.to_string().contains("request id: req_xyz789"));
+        assert!(matches!(err, OpenAIToolError::ApiError { status: 429, .. }));
+        server.await.unwrap();
+    }
+
+    #[test]
+    fn test_responses_build_request_json() {
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4oMini);
+        assert!(responses.build_request_json().is_err());
+
+        responses.instructions("Be brief.").str_message("Hello!");
+        let json: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["model"], "gpt-4o-mini");
+        assert_eq!(json["instructions"], "Be brief.");
+        assert_eq!(json["input"], "Hello!");
+    }
+
+    #[tokio::test]
+    async fn test_responses_missing_fields_fail_before_sending() {
+        use crate::common::errors::OpenAIToolError;
+
+        let mut responses = Responses::with_url("http://127.0.0.1:9/v1", "sk-test");
+        responses.model(ChatModel::Gpt4oMini);
+        assert!(matches!(responses.complete().await, Err(OpenAIToolError::MissingField("messages"))));
+
+        responses.messages(vec![]);
+        assert!(matches!(responses.complete().await, Err(OpenAIToolError::MissingField("messages"))));
+
+        responses.model(ChatModel::Custom(String::new())).str_message("Hello!");
+        assert!(matches!(responses.complete().await, Err(OpenAIToolError::MissingField("model"))));
+    }
+
+    #[test]
+    fn test_with_web_search_and_typed_web_search_outputs() {
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4o).str_message("Latest Rust release?").with_web_search().include(vec![Include::WebSearchCallSources]);
+        let json: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        assert_eq!(json["tools"], serde_json::json!([{"type": "web_search_preview"}]));
+        assert_eq!(json["include"], serde_json::json!(["web_search_call.action.sources"]));
+
+        let response: crate::responses::response::Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "output": [
+                {"id": "ws_1", "type": "web_search_call", "status": "completed", "action": {
+                    "type": "search", "query": "latest rust release",
+                    "sources": [{"type": "url", "url": "https://blog.rust-lang.org/"}, {"type": "url", "url": "https://www.rust-lang.org/"}]
+                }},
+                {"id": "ws_2", "type": "web_search_call", "status": "completed", "action": {"type": "open_page", "url": "https://blog.rust-lang.org/"}},
+                {"id": "msg_1", "type": "message", "role": "assistant", "content": [{
+                    "type": "output_text", "text": "Rust 1.95 was released.",
+                    "annotations": [{"type": "url_citation", "url": "https://blog.rust-lang.org/", "title": "Rust Blog", "start_index": 0, "end_index": 23}]
+                }]}
+            ]
+        }))
+        .unwrap();
+
+        let actions = response.web_search_actions();
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0].query.as_deref(), Some("latest rust release"));
+        assert_eq!(actions[1].type_name.as_deref(), Some("open_page"));
+        let urls: Vec<_> = response.web_search_sources().into_iter().filter_map(|source| source.url).collect();
+        assert_eq!(urls, vec!["https://blog.rust-lang.org/", "https://www.rust-lang.org/"]);
+
+        let output = response.output.as_ref().unwrap();
+        assert!(output[2].web_search_action().is_none());
+        let annotation = &output[2].content.as_ref().unwrap()[0].annotations.as_ref().unwrap()[0];
+        assert_eq!(annotation.type_name.as_deref(), Some("url_citation"));
+        assert_eq!(annotation.title.as_deref(), Some("Rust Blog"));
+        assert_eq!(response.output_text().as_deref(), Some("Rust 1.95 was released."));
+    }
+
+    #[test]
+    fn test_with_code_interpreter_and_typed_code_interpreter_outputs() {
+        let mut responses = Responses::new();
+        responses.model(ChatModel::Gpt4_1).str_message("Plot y = x^2").with_code_interpreter(None).with_code_interpreter(Some("cntr_123".into()));
+        let json: serde_json::Value = serde_json::from_str(&responses.build_request_json().unwrap()).unwrap();
+        assert_eq!(
+            json["tools"],
+            serde_json::json!([{"type": "code_interpreter", "container": {"type": "auto"}}, {"type": "code_interpreter", "container": "cntr_123"}])
+        );
+
+        let response: crate::responses::response::Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "output": [
+                {"id": "ci_1", "type": "code_interpreter_call", "status": "completed", "code": "print(sum(range(4)))", "container_id": "cntr_123",
+                 "outputs": [{"type": "logs", "logs": "6\n"}, {"type": "image", "url": "https://example.com/plot.png"}]},
+                {"id": "msg_1", "type": "message", "role": "assistant", "content": [{
+                    "type": "output_text", "text": "Here is the plot.",
+                    "annotations": [{"type": "container_file_citation", "container_id": "cntr_123", "file_id": "cfile_1", "filename": "plot.png", "start_index": 0, "end_index": 17}]
+                }]}
+            ]
+        }))
+        .unwrap();
+
+        let calls = response.code_interpreter_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].code.as_deref(), Some("print(sum(range(4)))"));
+        assert_eq!(calls[0].container_id.as_deref(), Some("cntr_123"));
+        let outputs = calls[0].outputs.as_ref().unwrap();
+        assert_eq!(outputs[0].logs.as_deref(), Some("6\n"));
+        assert_eq!(outputs[1].url.as_deref(), Some("https://example.com/plot.png"));
+
+        let files = response.container_files();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_id.as_deref(), Some("cfile_1"));
+        assert_eq!(files[0].filename.as_deref(), Some("plot.png"));
+    }
+
+    #[test]
+    fn test_reasoning_summary() {
+        let response: crate::responses::response::Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "output": [
+                {"id": "rs_1", "type": "reasoning", "summary": [
+                    {"type": "summary_text", "text": "**Reading the question**\n\nThe user asks for a sum."},
+                    {"type": "summary_text", "text": "**Computing**\n\n2 + 2 = 4."}
+                ], "encrypted_content": "gAAAA..."},
+                {"id": "rs_2", "type": "reasoning", "summary": []},
+                {"id": "msg_1", "type": "message", "role": "assistant", "content": [{"type": "output_text", "text": "4"}]}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            response.reasoning_summary().as_deref(),
+            Some("**Reading the question**\n\nThe user asks for a sum.\n\n**Computing**\n\n2 + 2 = 4.")
+        );
+
+        let without_summary: crate::responses::response::Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_2",
+            "output": [{"id": "rs_1", "type": "reasoning", "summary": []}]
+        }))
+        .unwrap();
+        assert_eq!(without_summary.reasoning_summary(), None);
+        assert_eq!(crate::responses::response::Response::default().reasoning_summary(), None);
+    }
+
+    #[test]
+    fn test_parse_output() {
+        #[derive(Debug, serde::Deserialize, PartialEq)]
+        struct Answer {
+            value: u32,
+        }
+        let response: crate::responses::response::Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "output": [{"id": "msg_1", "type": "message", "role": "assistant", "content": [{"type": "output_text", "text": "{\"value\": 7}"}]}]
+        }))
+        .unwrap();
+        assert_eq!(response.parse_output::<Answer>().unwrap(), Answer { value: 7 });
+
+        let err = crate::responses::response::Response::default().parse_output::<Answer>().unwrap_err();
+        assert!(matches!(err, crate::common::errors::OpenAIToolError::Error(_)));
+    }
 }
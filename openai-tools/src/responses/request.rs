@@ -1,20 +1,32 @@
 use crate::{
     common::{
-        auth::{AuthProvider, OpenAIAuth},
-        client::create_http_client,
-        errors::{OpenAIToolError, Result},
+        auth::AuthProvider,
+        client::{cached_http_client, run_cancellable, HttpClient},
+        errors::{request_id_header, OpenAIToolError, Result},
         message::Message,
+        metadata,
         models::{ChatModel, ParameterRestriction},
+        pagination::paginate,
+        pricing::RequestPreview,
+        role::Role,
         structured_output::Schema,
         tool::Tool,
+        transport::{send_with, HttpRequest, Transport},
+    },
+    conversations::request::Conversations,
+    responses::{
+        response::{CompactedResponse, DeleteResponse, InputItemsListResponse, InputTokensResponse, Response, ResponseInputItem},
+        stream::{parse_event_stream, ResponseEventStream},
     },
-    responses::response::{CompactedResponse, DeleteResponseResult, InputItemsListResponse, InputTokensResponse, Response},
 };
 use derive_new::new;
+use futures_util::stream::Stream;
 use request;
 use serde::{ser::SerializeStruct, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use strum::{Display, EnumString};
 
 /// Specifies additional data to include in the response output
@@ -38,6 +50,15 @@ pub enum Include {
     #[serde(rename = "web_search_call.results")]
     WebSearchCall,
 
+    /// Include the sources consulted by web search calls
+    ///
+    /// When included, the `action.sources` of each `web_search_call` output
+    /// lists the URLs the search used. See
+    /// [`Response::web_search_sources`](crate::responses::response::Response::web_search_sources).
+    #[strum(serialize = "web_search_call.action.sources")]
+    #[serde(rename = "web_search_call.action.sources")]
+    WebSearchCallSources,
+
     /// Include code interpreter call outputs in the output
     ///
     /// When included, the response will contain outputs from any code
@@ -196,8 +217,10 @@ pub enum ReasoningSummary {
 #[derive(Debug, Clone, Serialize)]
 pub struct Reasoning {
     /// The level of reasoning effort to apply
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub effort: Option<ReasoningEffort>,
     /// The format for the reasoning summary
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<ReasoningSummary>,
 }
 
@@ -245,6 +268,7 @@ pub enum TextVerbosity {
 #[derive(Debug, Clone, Serialize)]
 pub struct TextConfig {
     /// The verbosity level for text output
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub verbosity: Option<TextVerbosity>,
 }
 
@@ -789,7 +813,7 @@ pub struct Body {
     ///
     /// Arbitrary key-value pairs that can be attached to the request for
     /// tracking, logging, or passing additional context that doesn't affect
-    /// the model's behavior.
+    /// the model's behavior. Values must be strings.
     ///
     /// # Common Use Cases
     ///
@@ -806,7 +830,7 @@ pub struct Body {
     /// let mut metadata = HashMap::new();
     /// metadata.insert("user_id".to_string(), Value::String("user123".to_string()));
     /// metadata.insert("session_id".to_string(), Value::String("sess456".to_string()));
-    /// metadata.insert("priority".to_string(), Value::Number(serde_json::Number::from(1)));
+    /// metadata.insert("priority".to_string(), Value::String("high".to_string()));
     /// ```
     pub metadata: Option<HashMap<String, serde_json::Value>>,
 
@@ -1069,6 +1093,45 @@ pub struct Body {
     pub truncation: Option<Truncation>,
 }
 
+/// Serializes a [`Tool`] in the Responses API shape.
+///
+/// Function tools are flattened from `{"type": "function", "function": {...}}`
+/// to `{"type": "function", "name": ..., "description": ..., "parameters": ..., "strict": ...}`;
+/// other tools are serialized unchanged.
+struct ResponsesToolRef<'a>(&'a Tool);
+
+impl Serialize for ResponsesToolRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let function = match &self.0.function {
+            Some(function) if self.0.type_name == "function" => function,
+            _ => return self.0.serialize(serializer),
+        };
+        let mut state = serializer.serialize_struct("Tool", 5)?;
+        state.serialize_field("type", &self.0.type_name)?;
+        state.serialize_field("name", &function.name)?;
+        if let Some(description) = &function.description {
+            state.serialize_field("description", description)?;
+        }
+        if let Some(parameters) = &function.parameters {
+            state.serialize_field("parameters", parameters)?;
+        }
+        state.serialize_field("strict", &function.strict)?;
+        state.end()
+    }
+}
+
+/// The `text` object of the request, combining [`Body::structured_output`] and [`Body::text`].
+#[derive(Serialize)]
+struct TextBlock<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<&'a Schema>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verbosity: Option<&'a TextVerbosity>,
+}
+
 impl Serialize for Body {
     /// Custom serialization implementation for the request body
     ///
@@ -1104,8 +1167,8 @@ impl Serialize for Body {
         if self.instructions.is_some() {
             state.serialize_field("instructions", &self.instructions)?;
         }
-        if self.tools.is_some() {
-            state.serialize_field("tools", &self.tools)?;
+        if let Some(tools) = &self.tools {
+            state.serialize_field("tools", &tools.iter().map(ResponsesToolRef).collect::<Vec<_>>())?;
         }
         if self.tool_choice.is_some() {
             state.serialize_field("tool_choice", &self.tool_choice)?;
@@ -1119,8 +1182,13 @@ impl Serialize for Body {
         if self.prompt_cache_retention.is_some() {
             state.serialize_field("prompt_cache_retention", &self.prompt_cache_retention)?;
         }
-        if self.structured_output.is_some() {
-            state.serialize_field("text", &self.structured_output)?;
+        // The structured output format and the verbosity share the `text` object
+        if self.structured_output.is_some() || self.text.is_some() {
+            let text = TextBlock {
+                format: self.structured_output.as_ref().map(|format| &format.format),
+                verbosity: self.text.as_ref().and_then(|text| text.verbosity.as_ref()),
+            };
+            state.serialize_field("text", &text)?;
         }
         if self.max_output_tokens.is_some() {
             state.serialize_field("max_output_tokens", &self.max_output_tokens)?;
@@ -1149,9 +1217,6 @@ impl Serialize for Body {
         if self.reasoning.is_some() {
             state.serialize_field("reasoning", &self.reasoning)?;
         }
-        if self.text.is_some() {
-            state.serialize_field("text", &self.text)?;
-        }
         if self.safety_identifier.is_some() {
             state.serialize_field("safety_identifier", &self.safety_identifier)?;
         }
@@ -1238,6 +1303,12 @@ pub struct Responses {
     pub request_body: Body,
     /// Optional request timeout duration
     timeout: Option<Duration>,
+    /// HTTP client shared with other API clients, if any
+    pub(crate) http_client: Option<request::Client>,
+    /// HTTP client built on first use and reused by later requests
+    cached_http_client: OnceLock<request::Client>,
+    /// Transport replacing the HTTP client for non-streaming requests, if any
+    transport: Option<Arc<dyn Transport>>,
 }
 
 impl Default for Responses {
@@ -1258,7 +1329,15 @@ impl Responses {
     /// Panics if the `OPENAI_API_KEY` environment variable is not set.
     pub fn new() -> Self {
         let auth = AuthProvider::openai_from_env().map_err(|e| OpenAIToolError::Error(format!("Failed to load OpenAI auth: {}", e))).unwrap();
-        Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None }
+        Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        }
     }
 
     /// Creates a new instance of the Responses client with a custom endpoint
@@ -1266,7 +1345,15 @@ impl Responses {
     pub fn from_endpoint<T: AsRef<str>>(endpoint: T) -> Self {
         let auth = AuthProvider::openai_from_env().map_err(|e| OpenAIToolError::Error(format!("Failed to load OpenAI auth: {}", e))).unwrap();
         // Extract the path from the endpoint and use it
-        let mut responses = Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None };
+        let mut responses = Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        };
         responses.base_url(endpoint.as_ref().trim_end_matches("/responses"));
         responses
     }
@@ -1305,7 +1392,15 @@ impl Responses {
     /// ```
     pub fn with_model(model: ChatModel) -> Self {
         let auth = AuthProvider::openai_from_env().map_err(|e| OpenAIToolError::Error(format!("Failed to load OpenAI auth: {}", e))).unwrap();
-        Self { auth, user_agent: "".into(), request_body: Body { model, ..Default::default() }, timeout: None }
+        Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body { model, ..Default::default() },
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        }
     }
 
     /// Creates a new Responses client with a custom authentication provider
@@ -1336,7 +1431,29 @@ impl Responses {
     /// let mut responses = Responses::with_auth(auth);
     /// ```
     pub fn with_auth(auth: AuthProvider) -> Self {
-        Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None }
+        Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        }
+    }
+
+    /// Creates a new Responses instance that sends requests with the given HTTP client
+    ///
+    /// Use this to reuse a pre-configured `reqwest::Client` (connection limits,
+    /// proxy, TLS settings, ...). The API key is loaded as in [`Responses::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `OPENAI_API_KEY` environment variable is not set.
+    pub fn with_http_client(http_client: request::Client) -> Self {
+        let mut responses = Self::new();
+        responses.http_client = Some(http_client);
+        responses
     }
 
     /// Creates a new Responses client for Azure OpenAI API
@@ -1367,7 +1484,15 @@ impl Responses {
     /// ```
     pub fn azure() -> Result<Self> {
         let auth = AuthProvider::azure_from_env()?;
-        Ok(Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        })
     }
 
     /// Creates a new Responses client by auto-detecting the provider
@@ -1389,7 +1514,15 @@ impl Responses {
     /// ```
     pub fn detect_provider() -> Result<Self> {
         let auth = AuthProvider::from_env()?;
-        Ok(Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        })
     }
 
     /// Creates a new Responses instance with URL-based provider detection
@@ -1404,7 +1537,15 @@ impl Responses {
     /// * `api_key` - The API key or token
     pub fn with_url<S: Into<String>>(base_url: S, api_key: S) -> Self {
         let auth = AuthProvider::from_url_with_key(base_url, api_key);
-        Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None }
+        Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        }
     }
 
     /// Creates a new Responses instance from URL using environment variables
@@ -1413,7 +1554,15 @@ impl Responses {
     /// credentials from the appropriate environment variables.
     pub fn from_url<S: Into<String>>(url: S) -> Result<Self> {
         let auth = AuthProvider::from_url(url)?;
-        Ok(Self { auth, user_agent: "".into(), request_body: Body::default(), timeout: None })
+        Ok(Self {
+            auth,
+            user_agent: "".into(),
+            request_body: Body::default(),
+            timeout: None,
+            http_client: None,
+            cached_http_client: OnceLock::new(),
+            transport: None,
+        })
     }
 
     /// Returns the authentication provider
@@ -1454,7 +1603,7 @@ impl Responses {
     pub fn base_url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         // Only modify if OpenAI provider
         if let AuthProvider::OpenAI(ref openai_auth) = self.auth {
-            let new_auth = OpenAIAuth::new(openai_auth.api_key()).with_base_url(url.as_ref());
+            let new_auth = openai_auth.clone().with_base_url(url.as_ref());
             self.auth = AuthProvider::OpenAI(new_auth);
         } else {
             tracing::warn!("base_url() is only supported for OpenAI provider. Use azure() or with_auth() for Azure.");
@@ -1505,6 +1654,12 @@ impl Responses {
 
     /// Sets the request timeout duration
     ///
+    /// Requests that take longer fail with
+    /// [`OpenAIToolError::Timeout`](crate::common::errors::OpenAIToolError::Timeout).
+    /// The limit covers the whole request, including reading a streamed response.
+    /// Without it, only the [`DEFAULT_TIMEOUT`](crate::common::client::DEFAULT_TIMEOUT)
+    /// read timeout applies.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The maximum time to wait for a response
@@ -1528,6 +1683,26 @@ impl Responses {
         self
     }
 
+    /// Sends non-streaming requests through a custom transport
+    ///
+    /// Use a [`MockTransport`](crate::common::transport::MockTransport) to test
+    /// code built on `Responses` without network access. This covers
+    /// [`complete`](Self::complete) as well as the retrieve, delete, cancel,
+    /// list, compact and token-count calls; [`complete_stream`](Self::complete_stream)
+    /// still uses the HTTP client.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to send requests with
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn with_transport<T: Transport + 'static>(&mut self, transport: T) -> &mut Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     /// Sets the User-Agent string for the request
     ///
     /// # Arguments
@@ -1560,6 +1735,7 @@ impl Responses {
     ///
     /// This method is mutually exclusive with `messages()`. Use this for simple
     /// text-based interactions where you don't need conversation history.
+    /// Replaces any input set before, including messages.
     ///
     /// # Arguments
     ///
@@ -1570,6 +1746,7 @@ impl Responses {
     /// A mutable reference to self for method chaining
     pub fn str_message<T: AsRef<str>>(&mut self, input: T) -> &mut Self {
         self.request_body.plain_text_input = Some(input.as_ref().to_string());
+        self.request_body.messages_input = None;
         self
     }
 
@@ -1577,19 +1754,58 @@ impl Responses {
     ///
     /// This method is mutually exclusive with `plain_text_input()`. Use this
     /// for complex conversations with message history and different roles.
+    /// Replaces any input set before, including plain text input.
+    ///
+    /// When continuing from a [`previous_response_id`](Self::previous_response_id),
+    /// pass only the new turn: the earlier turns are already part of the
+    /// referenced response.
     ///
     /// # Arguments
     ///
-    /// * `messages` - A vector of messages representing the conversation history
+    /// * `messages` - A vector of messages representing the conversation history;
+    ///   text converts into a **user** message with `.into()`
     ///
     /// # Returns
     ///
     /// A mutable reference to self for method chaining
     pub fn messages(&mut self, messages: Vec<Message>) -> &mut Self {
         self.request_body.messages_input = Some(messages);
+        self.request_body.plain_text_input = None;
         self
     }
 
+    /// Sets structured message input from `(role, text)` pairs
+    ///
+    /// Each pair becomes a text message, as with [`Message::from_string`].
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The `(role, text)` pairs, oldest first
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::common::role::Role;
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// let mut client = Responses::new();
+    /// client.messages_from(vec![
+    ///     (Role::System, "Answer in one word.".to_string()),
+    ///     (Role::User, "What color is the sky?".to_string()),
+    /// ]);
+    /// ```
+    pub fn messages_from<I, S>(&mut self, messages: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (Role, S)>,
+        S: AsRef<str>,
+    {
+        self.messages(messages.into_iter().map(|(role, text)| Message::from_string(role, text)).collect())
+    }
+
     /// Sets tools that the model can use during response generation
     ///
     /// # Arguments
@@ -1604,6 +1820,85 @@ impl Responses {
         self
     }
 
+    /// Enables the built-in web search tool
+    ///
+    /// Appends `{"type": "web_search_preview"}` to the tools, letting the model
+    /// search the web before answering. Searches are returned as
+    /// `web_search_call` outputs; request [`Include::WebSearchCallSources`]
+    /// to also receive the sources each search consulted.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::responses::request::{Include, Responses};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Responses::new();
+    /// let response = client
+    ///     .model_id("gpt-4o")
+    ///     .str_message("What happened in the news today?")
+    ///     .with_web_search()
+    ///     .include(vec![Include::WebSearchCallSources])
+    ///     .complete()
+    ///     .await?;
+    ///
+    /// for source in response.web_search_sources() {
+    ///     println!("{}", source.url.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_web_search(&mut self) -> &mut Self {
+        self.request_body.tools.get_or_insert_with(Vec::new).push(Tool::web_search_preview());
+        self
+    }
+
+    /// Enables the built-in code interpreter tool
+    ///
+    /// Appends `{"type": "code_interpreter", "container": ...}` to the tools,
+    /// letting the model write and run Python code. Runs are returned as
+    /// `code_interpreter_call` outputs; request [`Include::CodeInterpreterCall`]
+    /// to also receive their logs and images.
+    ///
+    /// # Arguments
+    ///
+    /// * `container` - ID of an existing container to run code in, or `None`
+    ///   to let the API create one
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::responses::request::{Include, Responses};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Responses::new();
+    /// let response = client
+    ///     .model_id("gpt-4.1")
+    ///     .str_message("What is the standard deviation of 3, 7, 8 and 12?")
+    ///     .with_code_interpreter(None)
+    ///     .include(vec![Include::CodeInterpreterCall])
+    ///     .complete()
+    ///     .await?;
+    ///
+    /// for call in response.code_interpreter_calls() {
+    ///     println!("{}", call.code.as_deref().unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_code_interpreter(&mut self, container: Option<String>) -> &mut Self {
+        self.request_body.tools.get_or_insert_with(Vec::new).push(Tool::code_interpreter(container));
+        self
+    }
+
     /// Sets the tool choice configuration
     ///
     /// Controls how the model selects which tool to use when tools are available.
@@ -1731,6 +2026,9 @@ impl Responses {
     ///
     /// * `text_format` - The schema defining the expected output structure
     ///
+    /// Combines with [`text_verbosity`](Self::text_verbosity): both are sent in
+    /// the request's `text` object.
+    ///
     /// # Returns
     ///
     /// A mutable reference to self for method chaining
@@ -1859,13 +2157,16 @@ impl Responses {
     /// # Arguments
     ///
     /// * `key` - The metadata key (string identifier)
-    /// * `value` - The metadata value (can be string, number, boolean, etc.)
+    /// * `value` - The metadata value; the API only accepts strings
     ///
     /// # Behavior
     ///
     /// - If the key already exists, the old value is replaced with the new one
     /// - If metadata doesn't exist yet, a new metadata map is created
-    /// - Values are stored as `serde_json::Value` for flexibility
+    /// - Values are stored as `serde_json::Value`
+    /// - The API limits (16 entries, keys up to 64 and values up to 512 characters,
+    ///   string values only) are checked when the request is sent; see [`Metadata`](crate::common::metadata::Metadata)
+    ///   for validation at insertion time
     ///
     /// # Returns
     ///
@@ -1879,8 +2180,7 @@ impl Responses {
     ///
     /// let mut client = Responses::new();
     /// client.metadata("user_id".to_string(), Value::String("user123".to_string()));
-    /// client.metadata("priority".to_string(), Value::Number(serde_json::Number::from(1)));
-    /// client.metadata("debug".to_string(), Value::Bool(true));
+    /// client.metadata("priority".to_string(), Value::String("high".to_string()));
     /// ```
     pub fn metadata(&mut self, key: String, value: serde_json::Value) -> &mut Self {
         if self.request_body.metadata.is_none() {
@@ -1893,6 +2193,33 @@ impl Responses {
         self
     }
 
+    /// Replaces all metadata of the request
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The metadata key-value pairs, as a [`Metadata`](crate::common::metadata::Metadata) or a `HashMap`
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openai_tools::common::metadata::Metadata;
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// let mut metadata = Metadata::new();
+    /// metadata.insert("user_id", "user123").unwrap();
+    ///
+    /// let mut client = Responses::new();
+    /// client.metadata_map(metadata);
+    /// ```
+    pub fn metadata_map(&mut self, metadata: impl Into<HashMap<String, String>>) -> &mut Self {
+        self.request_body.metadata = Some(metadata.into().into_iter().map(|(key, value)| (key, serde_json::Value::String(value))).collect());
+        self
+    }
+
     /// Enables or disables parallel tool calls
     ///
     /// When enabled, the model can make multiple tool calls simultaneously
@@ -1940,7 +2267,8 @@ impl Responses {
     ///
     /// # Available Inclusions
     ///
-    /// - `Include::WebSearchCall` - Web search results and sources
+    /// - `Include::WebSearchCall` - Web search results
+    /// - `Include::WebSearchCallSources` - Sources consulted by web searches
     /// - `Include::CodeInterpreterCall` - Code execution outputs
     /// - `Include::FileSearchCall` - File search operation results
     /// - `Include::LogprobsInOutput` - Token log probabilities
@@ -2038,6 +2366,51 @@ impl Responses {
         self
     }
 
+    /// Creates a new conversation and attaches this request to it
+    ///
+    /// Convenience for persisting a multi-turn exchange without creating the
+    /// conversation by hand: the conversation is created through `conversations`,
+    /// its ID is set with [`conversation`](Self::conversation), and `store` is
+    /// enabled so the response is persisted.
+    ///
+    /// # Arguments
+    ///
+    /// * `conversations` - The Conversations API client used to create the conversation
+    /// * `metadata` - Optional metadata to attach to the new conversation: a
+    ///   [`Metadata`](crate::common::metadata::Metadata), a `HashMap`, or `None`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&mut Self)` - A mutable reference to self for method chaining
+    /// * `Err(OpenAIToolError)` - If the metadata exceeds the API limits or the
+    ///   conversation could not be created
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::conversations::request::Conversations;
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let conversations = Conversations::new()?;
+    ///
+    /// let mut client = Responses::new();
+    /// client.with_new_conversation(&conversations, None).await?;
+    /// let response = client.str_message("Remember that my name is Alice.").complete().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_new_conversation(
+        &mut self,
+        conversations: &Conversations,
+        metadata: impl Into<Option<HashMap<String, String>>>,
+    ) -> Result<&mut Self> {
+        let conversation = conversations.create(metadata, None).await?;
+        self.conversation(conversation.id);
+        self.store(true);
+        Ok(self)
+    }
+
     /// Sets the ID of the previous response for context continuation
     ///
     /// References a previous response in the same conversation to maintain
@@ -2048,6 +2421,9 @@ impl Responses {
     ///
     /// * `response_id` - The ID of the previous response to reference
     ///
+    /// The input of the request is sent as is, so set it to the new turn only;
+    /// the history up to the referenced response is not resent.
+    ///
     /// # Use Cases
     ///
     /// - **Multi-turn conversations**: Maintaining context across multiple exchanges
@@ -2138,6 +2514,9 @@ impl Responses {
     ///
     /// This parameter is available on GPT-5.2 and newer models.
     ///
+    /// Combines with [`structured_output`](Self::structured_output), e.g. for
+    /// terse structured answers: both are sent in the request's `text` object.
+    ///
     /// # Use Cases
     ///
     /// - **Low verbosity**: Quick answers, summaries, yes/no questions
@@ -2540,61 +2919,63 @@ impl Responses {
         self.request_body.model.is_reasoning_model()
     }
 
-    /// Executes the request and returns the response
+    /// Estimates the prompt tokens and cost of the request without sending it
     ///
-    /// This method sends the configured request to the OpenAI Responses API
-    /// and returns the parsed response. It performs validation of required
-    /// fields before sending the request.
+    /// Tokens of the instructions and input are counted locally with the model's
+    /// tokenizer, and the cost is computed from the built-in pricing table.
+    /// No network call is made.
     ///
     /// # Returns
     ///
-    /// A `Result` containing the `Response` on success, or an `OpenAIToolError` on failure
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The API key is not set or is empty
-    /// - The model ID is not set or is empty
-    /// - Neither messages nor plain text input is provided
-    /// - Both messages and plain text input are provided (mutually exclusive)
-    /// - The HTTP request fails
-    /// - The response cannot be parsed
-    ///
-    /// # Parameter Validation
-    ///
-    /// For reasoning models (GPT-5, o-series), certain parameters have restrictions:
-    /// - `temperature`: only 1.0 supported
-    /// - `top_p`: only 1.0 supported
-    /// - `top_logprobs`: not supported
-    ///
-    /// **Validation occurs at two points:**
-    /// 1. At setter time (when using `with_model()` constructor) - immediate warning
-    /// 2. At API call time (fallback) - for cases where model is changed after setting params
-    ///
-    /// Unsupported parameter values are ignored with a warning and the request proceeds.
+    /// A `RequestPreview` with the estimated prompt tokens and input cost in USD
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use openai_tools::responses::request::Responses;
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut client = Responses::new();
-    /// let response = client
-    ///     .model_id("gpt-4")
-    ///     .str_message("Hello!")
-    ///     .complete()
-    ///     .await?;
-    /// # Ok(())
-    /// # }
+    /// client.instructions("You are a helpful assistant.").str_message("Hello!");
+    ///
+    /// let preview = client.preview();
+    /// println!("~{} tokens", preview.estimated_prompt_tokens);
     /// ```
-    pub async fn complete(&self) -> Result<Response> {
-        // Validate that either messages or plain text input is set
-        if self.request_body.messages_input.is_none() && self.request_body.plain_text_input.is_none() {
-            return Err(OpenAIToolError::Error("Messages are not set.".into()));
-        } else if self.request_body.plain_text_input.is_none() && self.request_body.messages_input.is_none() {
+    pub fn preview(&self) -> RequestPreview {
+        let body = &self.request_body;
+        let plain_text = body.plain_text_input.as_ref().map(|text| vec![Message::from_string(Role::User, text)]);
+        let messages = body.messages_input.as_ref().or(plain_text.as_ref()).map(Vec::as_slice).unwrap_or_default();
+        RequestPreview::estimate(&body.model, body.instructions.as_deref(), messages)
+    }
+
+    /// Validates the request and returns the body to send
+    ///
+    /// Parameters unsupported by reasoning models are removed with a warning.
+    fn prepare_request_body(&self) -> Result<Body> {
+        // Validate required fields before any HTTP call
+        if self.request_body.model.as_str().is_empty() {
+            return Err(OpenAIToolError::MissingField("model"));
+        }
+        let has_messages = self.request_body.messages_input.as_ref().is_some_and(|messages| !messages.is_empty());
+        if !has_messages && self.request_body.plain_text_input.is_none() {
+            return Err(OpenAIToolError::MissingField("messages"));
+        } else if self.request_body.plain_text_input.is_some() && self.request_body.messages_input.is_some() {
             return Err(OpenAIToolError::Error("Both plain text input and messages are set. Please use one of them.".into()));
         }
+        if let Some(format) = &self.request_body.structured_output {
+            format.format.validate()?;
+        }
+        for tool in self.request_body.tools.iter().flatten() {
+            tool.validate()?;
+        }
+        if let Some(entries) = &self.request_body.metadata {
+            metadata::validate_entry_count(entries.len())?;
+            for (key, value) in entries {
+                let Some(value) = value.as_str() else {
+                    return Err(OpenAIToolError::InvalidParameter(format!("metadata value of key '{}' must be a string, got {}", key, value)));
+                };
+                metadata::validate_entry(key, value)?;
+            }
+        }
 
         // Handle reasoning models that don't support certain parameters
         // See: https://platform.openai.com/docs/guides/reasoning
@@ -2635,19 +3016,87 @@ impl Responses {
             }
         }
 
-        let body = serde_json::to_string(&request_body)?;
-
-        let client = create_http_client(self.timeout)?;
+        Ok(request_body)
+    }
 
-        // Set up headers
-        let mut headers = request::header::HeaderMap::new();
-        headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
-        if !self.user_agent.is_empty() {
-            headers.insert("User-Agent", request::header::HeaderValue::from_str(&self.user_agent).unwrap());
-        }
+    /// Returns the JSON body that [`complete()`](Self::complete) would send, without sending it
+    ///
+    /// All builder state and model-specific adjustments are applied, so the
+    /// output matches the actual request exactly. Useful for debugging and for
+    /// diffing requests.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The serialized request body
+    /// * `Err(OpenAIToolError)` - If the request is invalid, e.g. no input is set
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openai_tools::common::auth::AuthProvider;
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// let mut client = Responses::with_auth(AuthProvider::from_url_with_key("http://localhost:11434/v1", "ollama"));
+    /// client.model_id("gpt-4o-mini").str_message("Hello!");
+    ///
+    /// let json = client.build_request_json().unwrap();
+    /// assert!(json.contains("\"input\":\"Hello!\""));
+    /// ```
+    pub fn build_request_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.prepare_request_body()?)?)
+    }
 
-        // Apply provider-specific authentication headers
-        self.auth.apply_headers(&mut headers)?;
+    /// Executes the request and returns the response
+    ///
+    /// This method sends the configured request to the OpenAI Responses API
+    /// and returns the parsed response. It performs validation of required
+    /// fields before sending the request.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Response` on success, or an `OpenAIToolError` on failure
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The API key is not set or is empty
+    /// - The model ID is not set or is empty
+    /// - Neither messages nor plain text input is provided
+    /// - Both messages and plain text input are provided (mutually exclusive)
+    /// - The HTTP request fails
+    /// - The response cannot be parsed
+    ///
+    /// # Parameter Validation
+    ///
+    /// For reasoning models (GPT-5, o-series), certain parameters have restrictions:
+    /// - `temperature`: only 1.0 supported
+    /// - `top_p`: only 1.0 supported
+    /// - `top_logprobs`: not supported
+    ///
+    /// **Validation occurs at two points:**
+    /// 1. At setter time (when using `with_model()` constructor) - immediate warning
+    /// 2. At API call time (fallback) - for cases where model is changed after setting params
+    ///
+    /// Unsupported parameter values are ignored with a warning and the request proceeds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Responses::new();
+    /// let response = client
+    ///     .model_id("gpt-4")
+    ///     .str_message("Hello!")
+    ///     .complete()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complete(&self) -> Result<Response> {
+        let request_body = self.prepare_request_body()?;
+        let body = serde_json::to_string(&request_body)?;
 
         // Get the endpoint URL from the auth provider
         let endpoint = self.auth.endpoint(RESPONSES_PATH);
@@ -2661,27 +3110,106 @@ impl Responses {
         }
 
         // Send the request and handle the response
-        match client.post(&endpoint).headers(headers).body(body).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                tracing::error!("API error (status: {}): {}", status, error_text);
-                Err(OpenAIToolError::Error(format!("API request failed with status {}: {}", status, error_text)))
-            }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
+        let content = self.send(request::Method::POST, endpoint, Some(body)).await?;
 
-                if cfg!(test) {
-                    tracing::info!("Response content: {}", content);
-                }
+        if cfg!(test) {
+            tracing::info!("Response content: {}", content);
+        }
 
-                serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
-            }
+        serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
+    }
+
+    /// Sends the request, aborting it when `cancel` resolves
+    ///
+    /// `cancel` can be any future, such as `CancellationToken::cancelled()` from
+    /// `tokio-util`, a `tokio::sync::oneshot::Receiver`, or `tokio::signal::ctrl_c()`.
+    /// When it resolves first, the in-flight HTTP request is dropped, which closes
+    /// the connection, and [`OpenAIToolError::Cancelled`] is returned.
+    ///
+    /// For streaming, dropping the [`ResponseEventStream`] closes the connection
+    /// in the same way. Responses created in background mode keep running on the
+    /// server after the client disconnects; stop them with [`cancel`](Self::cancel).
+    ///
+    /// # Arguments
+    ///
+    /// * `cancel` - A future that resolves when the request should be aborted
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response, or `OpenAIToolError::Cancelled`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openai_tools::responses::request::Responses;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Responses::new();
+    /// client.model_id("gpt-4o-mini").str_message("Hello!");
+    ///
+    /// // Give up after 10 seconds
+    /// let response = client.complete_cancellable(tokio::time::sleep(Duration::from_secs(10))).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complete_cancellable<C: Future>(&self, cancel: C) -> Result<Response> {
+        run_cancellable(self.complete(), cancel).await
+    }
+
+    /// Executes the request with streaming enabled and returns the event stream
+    ///
+    /// The request is sent with `stream: true`, and the server-sent events are
+    /// parsed into [`ResponseStreamEvent`]s as they arrive. The same validation
+    /// as [`complete`](Self::complete) is applied before sending.
+    ///
+    /// [`ResponseStreamEvent`]: crate::responses::stream::ResponseStreamEvent
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the event stream on success, or an `OpenAIToolError`
+    /// if the request could not be sent or the API returned an error status
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use openai_tools::responses::request::Responses;
+    /// use openai_tools::responses::stream::ResponseStreamEvent;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Responses::new();
+    /// client.str_message("Tell me a joke.");
+    ///
+    /// let mut events = client.complete_stream().await?;
+    /// while let Some(event) = events.next().await {
+    ///     match event? {
+    ///         ResponseStreamEvent::OutputTextDelta(e) => print!("{}", e.delta),
+    ///         ResponseStreamEvent::ResponseCompleted(_) => break,
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complete_stream(&self) -> Result<ResponseEventStream> {
+        let mut request_body = self.prepare_request_body()?;
+        request_body.stream = Some(true);
+        let body = serde_json::to_string(&request_body)?;
+
+        let (client, mut headers) = self.create_api_client()?;
+        headers.insert("Accept", request::header::HeaderValue::from_static("text/event-stream"));
+        let endpoint = self.auth.endpoint(RESPONSES_PATH);
+
+        let response = client.post(&endpoint).headers(headers).body(body).send().await.map_err(OpenAIToolError::from)?;
+        let status = response.status();
+        let request_id = request_id_header(response.headers());
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
+            return Err(Self::handle_api_error(status, request_id, &error_text));
         }
+
+        Ok(Box::pin(parse_event_stream(Box::pin(response.bytes_stream()))))
     }
 
     // ========================================
@@ -2696,8 +3224,13 @@ impl Responses {
     /// # Returns
     ///
     /// A tuple of the HTTP client and headers
-    fn create_api_client(&self) -> Result<(request::Client, request::header::HeaderMap)> {
-        let client = create_http_client(self.timeout)?;
+    fn create_api_client(&self) -> Result<(HttpClient, request::header::HeaderMap)> {
+        let client = cached_http_client(self.http_client.as_ref(), &self.cached_http_client, self.timeout)?;
+        Ok((client, self.api_headers()?))
+    }
+
+    /// Builds the default headers, including authentication
+    fn api_headers(&self) -> Result<request::header::HeaderMap> {
         let mut headers = request::header::HeaderMap::new();
         headers.insert("Content-Type", request::header::HeaderValue::from_static("application/json"));
         if !self.user_agent.is_empty() {
@@ -2707,7 +3240,22 @@ impl Responses {
             );
         }
         self.auth.apply_headers(&mut headers)?;
-        Ok((client, headers))
+        Ok(headers)
+    }
+
+    /// Sends a request through the transport and returns the body of a successful response
+    ///
+    /// Uses the custom transport if one is set, otherwise the HTTP client.
+    /// Error statuses are converted with [`handle_api_error`](Self::handle_api_error).
+    async fn send(&self, method: request::Method, endpoint: String, body: Option<String>) -> Result<String> {
+        let http_request = HttpRequest { method, url: endpoint, headers: self.api_headers()?, body };
+        let client = cached_http_client(self.http_client.as_ref(), &self.cached_http_client, self.timeout)?;
+        let response = send_with(self.transport.as_ref(), &client, http_request).await.inspect_err(|e| tracing::error!("Request error: {}", e))?;
+
+        if !response.status.is_success() {
+            return Err(Self::handle_api_error(response.status, request_id_header(&response.headers), &response.body));
+        }
+        Ok(response.body)
     }
 
     /// Handles API error responses
@@ -2718,14 +3266,15 @@ impl Responses {
     /// # Arguments
     ///
     /// * `status` - The HTTP status code
+    /// * `request_id` - The `x-request-id` header of the response, if any
     /// * `content` - The error response content
     ///
     /// # Returns
     ///
-    /// An OpenAIToolError containing the error details
-    fn handle_api_error(status: request::StatusCode, content: &str) -> OpenAIToolError {
-        tracing::error!("API error (status: {}): {}", status, content);
-        OpenAIToolError::Error(format!("API request failed with status {}: {}", status, content))
+    /// An `OpenAIToolError::ApiError` containing the error details
+    fn handle_api_error(status: request::StatusCode, request_id: Option<String>, content: &str) -> OpenAIToolError {
+        tracing::error!("API error (status: {}, request id: {}): {}", status, request_id.as_deref().unwrap_or("unknown"), content);
+        OpenAIToolError::from_api_response(status, request_id, content)
     }
 
     /// Retrieves a response by its ID
@@ -2758,29 +3307,81 @@ impl Responses {
     /// # }
     /// ```
     pub async fn retrieve(&self, response_id: &str) -> Result<Response> {
-        let (client, headers) = self.create_api_client()?;
         let endpoint = format!("{}/{}", self.auth.endpoint(RESPONSES_PATH), response_id);
 
-        match client.get(&endpoint).headers(headers).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                Err(Self::handle_api_error(status, &error_text))
+        let content = self.send(request::Method::GET, endpoint, None).await?;
+        serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
+    }
+
+    /// Waits for a background response to finish
+    ///
+    /// Polls [`retrieve`](Self::retrieve) every `poll_interval` until the
+    /// response reaches a terminal status (see [`Response::is_terminal`]).
+    /// Use this with responses created in [`background`](Self::background) mode.
+    ///
+    /// Failed, cancelled and incomplete responses are returned as-is; check
+    /// `status` and `error` on the result to tell them apart from a completed one.
+    ///
+    /// # Arguments
+    ///
+    /// * `response_id` - The ID of the response to wait for
+    /// * `poll_interval` - The interval between status checks
+    /// * `timeout` - The maximum total time to wait, or `None` to wait indefinitely
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Response)` - The response in its terminal state
+    /// * `Err(OpenAIToolError)` - If a request fails, the retrieved response has no
+    ///   `status`, or the timeout elapses
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use openai_tools::common::models::ChatModel;
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Responses::new();
+    /// client.model(ChatModel::O3).str_message("Prove that there are infinitely many primes.").background(true);
+    ///
+    /// let queued = client.complete().await?;
+    /// let response = client.wait_for_completion(queued.id.as_deref().unwrap(), Duration::from_secs(5), Some(Duration::from_secs(600))).await?;
+    /// println!("{:?}", response.output_text());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_completion(&self, response_id: &str, poll_interval: Duration, timeout: Option<Duration>) -> Result<Response> {
+        let started = Instant::now();
+        loop {
+            let response = self.retrieve(response_id).await?;
+            let Some(status) = response.status.as_deref() else {
+                return Err(OpenAIToolError::Error(format!("Response {} has no status to wait on", response_id)));
+            };
+            if response.is_terminal() {
+                return Ok(response);
             }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
-                serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
+
+            let mut interval = poll_interval;
+            if let Some(timeout) = timeout {
+                let elapsed = started.elapsed();
+                if elapsed >= timeout {
+                    return Err(OpenAIToolError::Error(format!(
+                        "Timed out after {:?} waiting for response {} (status: {})",
+                        timeout, response_id, status
+                    )));
+                }
+                interval = interval.min(timeout - elapsed);
             }
+            tokio::time::sleep(interval).await;
         }
     }
 
     /// Deletes a response by its ID
     ///
-    /// Permanently removes a response from the OpenAI platform.
+    /// Permanently removes a response from the OpenAI platform. Responses
+    /// created with [`store(true)`](Self::store) are kept until deleted, so use
+    /// this to purge them.
     ///
     /// # Arguments
     ///
@@ -2788,7 +3389,7 @@ impl Responses {
     ///
     /// # Returns
     ///
-    /// A `Result` containing `DeleteResponseResult` on success
+    /// A `Result` containing `DeleteResponse` on success
     ///
     /// # API Reference
     ///
@@ -2806,25 +3407,11 @@ impl Responses {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete(&self, response_id: &str) -> Result<DeleteResponseResult> {
-        let (client, headers) = self.create_api_client()?;
+    pub async fn delete(&self, response_id: &str) -> Result<DeleteResponse> {
         let endpoint = format!("{}/{}", self.auth.endpoint(RESPONSES_PATH), response_id);
 
-        match client.delete(&endpoint).headers(headers).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                Err(Self::handle_api_error(status, &error_text))
-            }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
-                serde_json::from_str::<DeleteResponseResult>(&content).map_err(OpenAIToolError::SerdeJsonError)
-            }
-        }
+        let content = self.send(request::Method::DELETE, endpoint, None).await?;
+        serde_json::from_str::<DeleteResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
     /// Cancels an in-progress response
@@ -2857,24 +3444,10 @@ impl Responses {
     /// # }
     /// ```
     pub async fn cancel(&self, response_id: &str) -> Result<Response> {
-        let (client, headers) = self.create_api_client()?;
         let endpoint = format!("{}/{}/cancel", self.auth.endpoint(RESPONSES_PATH), response_id);
 
-        match client.post(&endpoint).headers(headers).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                Err(Self::handle_api_error(status, &error_text))
-            }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
-                serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
-            }
-        }
+        let content = self.send(request::Method::POST, endpoint, None).await?;
+        serde_json::from_str::<Response>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
     /// Lists input items for a response
@@ -2918,7 +3491,6 @@ impl Responses {
         after: Option<&str>,
         before: Option<&str>,
     ) -> Result<InputItemsListResponse> {
-        let (client, headers) = self.create_api_client()?;
         let base_endpoint = format!("{}/{}/input_items", self.auth.endpoint(RESPONSES_PATH), response_id);
 
         // Build query parameters
@@ -2935,21 +3507,47 @@ impl Responses {
 
         let endpoint = if query_params.is_empty() { base_endpoint } else { format!("{}?{}", base_endpoint, query_params.join("&")) };
 
-        match client.get(&endpoint).headers(headers).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                Err(Self::handle_api_error(status, &error_text))
-            }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
-                serde_json::from_str::<InputItemsListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
-            }
-        }
+        let content = self.send(request::Method::GET, endpoint, None).await?;
+        serde_json::from_str::<InputItemsListResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
+    }
+
+    /// Lists every input item of a response as a stream, following the pagination cursor
+    ///
+    /// Pages are requested lazily as the stream is consumed, using `has_more` and
+    /// `last_id` from each page to request the next one. If a request fails, the
+    /// error is yielded and the stream ends.
+    ///
+    /// With `previous_response_id` chaining, the input items include the items
+    /// carried over from earlier responses, so this shows the full input the
+    /// model actually saw.
+    ///
+    /// # Arguments
+    ///
+    /// * `response_id` - The ID of the response to get input items for
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding each `ResponseInputItem` across all pages
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use openai_tools::responses::request::Responses;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Responses::new();
+    /// let items = client.list_all_input_items("resp_abc123");
+    /// pin_mut!(items);
+    /// while let Some(item) = items.next().await {
+    ///     let item = item?;
+    ///     println!("{} ({}): {:?}", item.id, item.item_type, item.text());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all_input_items<'a>(&'a self, response_id: &'a str) -> impl Stream<Item = Result<ResponseInputItem>> + 'a {
+        paginate(move |after| async move { self.list_input_items(response_id, None, after.as_deref(), None).await })
     }
 
     /// Compacts a response to reduce its size
@@ -2983,7 +3581,6 @@ impl Responses {
     /// # }
     /// ```
     pub async fn compact(&self, previous_response_id: &str, model: Option<&str>) -> Result<CompactedResponse> {
-        let (client, headers) = self.create_api_client()?;
         let endpoint = format!("{}/compact", self.auth.endpoint(RESPONSES_PATH));
 
         // Build request body
@@ -2994,21 +3591,8 @@ impl Responses {
             body["model"] = serde_json::json!(model);
         }
 
-        match client.post(&endpoint).headers(headers).body(serde_json::to_string(&body)?).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                Err(Self::handle_api_error(status, &error_text))
-            }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
-                serde_json::from_str::<CompactedResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
-            }
-        }
+        let content = self.send(request::Method::POST, endpoint, Some(serde_json::to_string(&body)?)).await?;
+        serde_json::from_str::<CompactedResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 
     /// Counts the number of input tokens for a potential request
@@ -3042,7 +3626,6 @@ impl Responses {
     /// # }
     /// ```
     pub async fn get_input_tokens(&self, model: &str, input: serde_json::Value) -> Result<InputTokensResponse> {
-        let (client, headers) = self.create_api_client()?;
         let endpoint = format!("{}/input_tokens", self.auth.endpoint(RESPONSES_PATH));
 
         let body = serde_json::json!({
@@ -3050,20 +3633,7 @@ impl Responses {
             "input": input
         });
 
-        match client.post(&endpoint).headers(headers).body(serde_json::to_string(&body)?).send().await.map_err(OpenAIToolError::RequestError) {
-            Err(e) => {
-                tracing::error!("Request error: {}", e);
-                Err(e)
-            }
-            Ok(response) if !response.status().is_success() => {
-                let status = response.status();
-                let error_text = response.text().await.unwrap_or_else(|_| "Failed to read error response".to_string());
-                Err(Self::handle_api_error(status, &error_text))
-            }
-            Ok(response) => {
-                let content = response.text().await.map_err(OpenAIToolError::RequestError)?;
-                serde_json::from_str::<InputTokensResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
-            }
-        }
+        let content = self.send(request::Method::POST, endpoint, Some(serde_json::to_string(&body)?)).await?;
+        serde_json::from_str::<InputTokensResponse>(&content).map_err(OpenAIToolError::SerdeJsonError)
     }
 }
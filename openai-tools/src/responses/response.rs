@@ -1,4 +1,8 @@
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::pagination::{impl_paginated, Page};
+use crate::common::structured_output::parse_structured_output;
 use crate::common::{structured_output::Schema, tool::Tool, usage::Usage};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -13,8 +17,8 @@ pub struct Content {
     pub type_name: Option<String>,
     /// The actual text content
     pub text: Option<String>,
-    /// Any annotations associated with the content
-    pub annotations: Option<Vec<String>>,
+    /// Any annotations associated with the content, such as URL citations from web search
+    pub annotations: Option<Vec<Annotation>>,
     /// Log probabilities for the content tokens
     pub logprobs: Option<Vec<String>>,
 }
@@ -33,6 +37,70 @@ pub struct FileSearchCallResult {
     pub text: Option<String>,
 }
 
+/// An annotation on output text, such as a citation.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Annotation {
+    /// The type of annotation, e.g. "url_citation" or "file_citation"
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    /// The cited URL (for url_citation annotations)
+    pub url: Option<String>,
+    /// The title of the cited web page (for url_citation annotations)
+    pub title: Option<String>,
+    /// Index of the first character of the citation in the text
+    pub start_index: Option<usize>,
+    /// Index after the last character of the citation in the text
+    pub end_index: Option<usize>,
+    /// The ID of the cited file (for file_citation annotations)
+    pub file_id: Option<String>,
+    /// The name of the cited file (for file_citation annotations)
+    pub filename: Option<String>,
+    /// Index of the citation in the text (for file_citation annotations)
+    pub index: Option<usize>,
+    /// The container holding the cited file (for container_file_citation annotations)
+    pub container_id: Option<String>,
+}
+
+/// An output produced by a code interpreter call.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CodeInterpreterOutput {
+    /// The type of output: "logs" or "image"
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    /// The logs printed by the code (for logs outputs)
+    pub logs: Option<String>,
+    /// The URL of the generated image (for image outputs)
+    pub url: Option<String>,
+}
+
+/// A source consulted by a web search call.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct WebSearchSource {
+    /// The type of source, typically "url"
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    /// The URL of the source
+    pub url: Option<String>,
+}
+
+/// The action taken by a web search call.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WebSearchAction {
+    /// The type of action: "search", "open_page" or "find"
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    /// The search query (for search actions)
+    pub query: Option<String>,
+    /// The sources consulted by the search (for search actions)
+    ///
+    /// Only returned when [`Include::WebSearchCallSources`](crate::responses::request::Include::WebSearchCallSources) is requested.
+    pub sources: Option<Vec<WebSearchSource>>,
+    /// The URL of the opened page (for open_page and find actions)
+    pub url: Option<String>,
+    /// The pattern searched for within the page (for find actions)
+    pub pattern: Option<String>,
+}
+
 /// Individual output item from the AI response.
 ///
 /// This can represent different types of outputs:
@@ -62,8 +130,9 @@ pub struct Output {
     pub queries: Option<Vec<String>>,
     /// The results of the file search tool call (for file_search_call outputs)
     pub results: Option<Vec<FileSearchCallResult>>,
-    // TODO: implement the action structure
     /// An object describing the specific action taken in this web search call (for web_search_call outputs)
+    ///
+    /// Use [`Output::web_search_action`] for typed access.
     pub action: Option<Value>,
     // TODO: implement the tool_call structure
     /// The pending safety checks for the computer call (for computer_call outputs)
@@ -72,8 +141,15 @@ pub struct Output {
     pub summary: Option<Value>,
     /// The encrypted content of the reasoning item (for reasoning outputs)
     pub encrypted_content: Option<String>,
+    /// The code that was run (for code_interpreter_call outputs)
+    pub code: Option<String>,
+    /// The container the code ran in (for code_interpreter_call outputs)
+    pub container_id: Option<String>,
+    /// The logs and images produced by the code (for code_interpreter_call outputs)
+    ///
+    /// Only returned when [`Include::CodeInterpreterCall`](crate::responses::request::Include::CodeInterpreterCall) is requested.
+    pub outputs: Option<Vec<CodeInterpreterOutput>>,
     // TODO: implement Image generation call
-    // TODO: implement Code interpreter tool call
     // TODO: implement Local shell call
     // TODO: implement MCP tool call
     // TODO: implement MCP list tools
@@ -81,6 +157,18 @@ pub struct Output {
     // TODO: implement Custom tool call
 }
 
+impl Output {
+    /// Returns the typed action of a `web_search_call` output.
+    ///
+    /// Returns `None` for other output types or if the action cannot be parsed.
+    pub fn web_search_action(&self) -> Option<WebSearchAction> {
+        if self.type_name.as_deref() != Some("web_search_call") {
+            return None;
+        }
+        serde_json::from_value(self.action.clone()?).ok()
+    }
+}
+
 /// Reasoning information from the AI model.
 ///
 /// Provides insight into the AI's reasoning process and effort level.
@@ -191,24 +279,101 @@ impl Response {
         };
         content.text.clone()
     }
+
+    /// Deserializes the output text, e.g. a structured output
+    ///
+    /// Uses the same text as [`Response::output_text`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The parsed output
+    /// * `Err(OpenAIToolError::SerdeJsonError)` - If the text does not match `T`; the message quotes the text
+    /// * `Err(OpenAIToolError::Error)` - If the response has no output text
+    pub fn parse_output<T: DeserializeOwned>(&self) -> Result<T> {
+        let text = self.output_text().ok_or_else(|| OpenAIToolError::Error("Response has no output text.".into()))?;
+        parse_structured_output(&text)
+    }
+
+    /// Returns `true` once the response has stopped processing.
+    ///
+    /// A response is terminal when its status is `completed`, `failed`,
+    /// `cancelled` or `incomplete`. Background responses start out as
+    /// `queued` and move through `in_progress` before reaching one of these.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.status.as_deref(), Some("completed" | "failed" | "cancelled" | "incomplete"))
+    }
+
+    /// Returns the reasoning summaries of all `reasoning` outputs, joined by blank lines.
+    ///
+    /// Summaries are only produced when a reasoning summary is requested with
+    /// [`Responses::reasoning`](crate::responses::request::Responses::reasoning).
+    /// Returns `None` if the response contains no summary text.
+    pub fn reasoning_summary(&self) -> Option<String> {
+        let parts = self
+            .output
+            .iter()
+            .flatten()
+            .filter(|output| output.type_name.as_deref() == Some("reasoning"))
+            .filter_map(|output| output.summary.as_ref())
+            .flat_map(|summary| match summary {
+                Value::String(text) => vec![text.as_str()],
+                Value::Array(parts) => parts.iter().filter_map(|part| part.get("text").and_then(Value::as_str)).collect(),
+                _ => vec![],
+            })
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>();
+        (!parts.is_empty()).then(|| parts.join("\n\n"))
+    }
+
+    /// Returns all `code_interpreter_call` outputs, in order.
+    pub fn code_interpreter_calls(&self) -> Vec<&Output> {
+        self.output.iter().flatten().filter(|output| output.type_name.as_deref() == Some("code_interpreter_call")).collect()
+    }
+
+    /// Returns the files created by the code interpreter and cited in the output text.
+    ///
+    /// Each file is a `container_file_citation` annotation carrying the
+    /// container ID, file ID and file name needed to download it.
+    pub fn container_files(&self) -> Vec<&Annotation> {
+        self.output
+            .iter()
+            .flatten()
+            .flat_map(|output| output.content.iter().flatten())
+            .flat_map(|content| content.annotations.iter().flatten())
+            .filter(|annotation| annotation.type_name.as_deref() == Some("container_file_citation"))
+            .collect()
+    }
+
+    /// Returns the actions of all `web_search_call` outputs, in order.
+    pub fn web_search_actions(&self) -> Vec<WebSearchAction> {
+        self.output.iter().flatten().filter_map(Output::web_search_action).collect()
+    }
+
+    /// Returns the sources consulted by all web search calls, without duplicates.
+    ///
+    /// Sources are only returned when [`Include::WebSearchCallSources`](crate::responses::request::Include::WebSearchCallSources)
+    /// is requested; otherwise this is empty.
+    pub fn web_search_sources(&self) -> Vec<WebSearchSource> {
+        let mut sources = Vec::new();
+        for source in self.web_search_actions().into_iter().flat_map(|action| action.sources.unwrap_or_default()) {
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        sources
+    }
 }
 
-/// Response for delete operations
-///
-/// Returned when a response is successfully deleted via the DELETE endpoint.
+/// Response for stored response deletion, shared with the other delete endpoints.
 ///
 /// # API Reference
 ///
 /// <https://platform.openai.com/docs/api-reference/responses/delete>
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
-pub struct DeleteResponseResult {
-    /// The ID of the deleted response
-    pub id: String,
-    /// Object type, typically "response.deleted"
-    pub object: String,
-    /// Whether the deletion was successful
-    pub deleted: bool,
-}
+pub use crate::common::delete::DeleteResponse;
+
+/// Former name of [`DeleteResponse`] for the Responses API.
+#[deprecated(since = "1.1.0", note = "Use `DeleteResponse` instead")]
+pub type DeleteResponseResult = DeleteResponse;
 
 /// Input item in a response
 ///
@@ -229,6 +394,33 @@ pub struct ResponseInputItem {
     /// The status of the input item
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    /// The call ID of a `function_call` or `function_call_output` item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    /// The function name of a `function_call` item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The JSON-encoded arguments of a `function_call` item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<String>,
+    /// The output of a `function_call_output` item: a string, or an array of content parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Value>,
+}
+
+impl ResponseInputItem {
+    /// Returns the text of this item's content parts, joined by newlines.
+    ///
+    /// Covers `input_text` and `output_text` parts as well as plain string
+    /// content. Returns `None` if the item has no text.
+    pub fn text(&self) -> Option<String> {
+        let parts = match self.content.as_ref()? {
+            Value::String(text) => vec![text.as_str()],
+            Value::Array(parts) => parts.iter().filter_map(|part| part.get("text").and_then(Value::as_str)).collect(),
+            _ => vec![],
+        };
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
 }
 
 /// Response for listing input items
@@ -254,6 +446,14 @@ pub struct InputItemsListResponse {
     pub has_more: bool,
 }
 
+impl From<InputItemsListResponse> for Page<ResponseInputItem> {
+    fn from(response: InputItemsListResponse) -> Self {
+        Page { data: response.data, first_id: response.first_id, last_id: response.last_id, has_more: response.has_more }
+    }
+}
+
+impl_paginated!(InputItemsListResponse, ResponseInputItem);
+
 /// Response for compact operation
 ///
 /// Returned when a response is compacted to reduce its size.
@@ -0,0 +1,117 @@
+//! Multi-turn chat sessions on top of the Responses API.
+//!
+//! [`ChatSession`] keeps track of the `previous_response_id` of the last turn
+//! so that each call to [`send`](ChatSession::send) continues the same
+//! conversation, and records the exchanged messages for display or logging.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use openai_tools::common::models::ChatModel;
+//! use openai_tools::responses::request::Responses;
+//! use openai_tools::responses::session::ChatSession;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut responses = Responses::new();
+//!     responses.model(ChatModel::Gpt4oMini).instructions("You are a concise assistant.");
+//!
+//!     let mut session = ChatSession::new(responses);
+//!     println!("{}", session.send("My name is Alice.").await?);
+//!     println!("{}", session.send("What is my name?").await?);
+//!
+//!     for message in session.history() {
+//!         println!("{:?}", message.role);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::common::message::Message;
+use crate::common::role::Role;
+use crate::responses::request::Responses;
+
+/// A multi-turn conversation that chains turns with `previous_response_id`.
+///
+/// The wrapped [`Responses`] client carries the per-session configuration
+/// (model, instructions, tools, ...). Only the input and
+/// `previous_response_id` are replaced on each turn; the earlier turns are
+/// kept by the API, so responses must be stored (the API default).
+#[derive(Debug, Clone)]
+pub struct ChatSession {
+    /// The client used to send each turn
+    responses: Responses,
+    /// The ID of the last response, used to chain the next turn
+    previous_response_id: Option<String>,
+    /// The user and assistant messages exchanged so far
+    history: Vec<Message>,
+}
+
+impl ChatSession {
+    /// Creates a new session from a configured `Responses` client.
+    ///
+    /// If the client already has a `previous_response_id`, the session
+    /// continues from that response.
+    ///
+    /// # Arguments
+    ///
+    /// * `responses` - The client to send turns with
+    pub fn new(responses: Responses) -> Self {
+        let previous_response_id = responses.request_body.previous_response_id.clone();
+        Self { responses, previous_response_id, history: Vec::new() }
+    }
+
+    /// Sends a user message and returns the assistant's reply text.
+    ///
+    /// The message is chained to the previous turn, and both the message and
+    /// the reply are appended to the history. If the request fails, the
+    /// session is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The user message
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The assistant's reply
+    /// * `Err(OpenAIToolError)` - If the request fails or the response has no text output
+    pub async fn send<T: AsRef<str>>(&mut self, text: T) -> Result<String> {
+        let text = text.as_ref();
+        self.responses.request_body.messages_input = None;
+        self.responses.request_body.previous_response_id = self.previous_response_id.clone();
+        self.responses.str_message(text);
+
+        let response = self.responses.complete().await?;
+        let reply = response.output_text().ok_or_else(|| OpenAIToolError::Error("Response has no output text.".into()))?;
+
+        self.previous_response_id = response.id;
+        self.history.push(Message::from_string(Role::User, text));
+        self.history.push(Message::from_string(Role::Assistant, &reply));
+        Ok(reply)
+    }
+
+    /// Returns the user and assistant messages exchanged so far, oldest first.
+    pub fn history(&self) -> &[Message] {
+        &self.history
+    }
+
+    /// Returns the ID of the last response, if any turn has been sent.
+    pub fn previous_response_id(&self) -> Option<&str> {
+        self.previous_response_id.as_deref()
+    }
+
+    /// Starts a new conversation, forgetting the previous turns.
+    ///
+    /// The client configuration is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.previous_response_id = None;
+        self.history.clear();
+        self
+    }
+
+    /// Returns the wrapped client, e.g. to change the model between turns.
+    pub fn responses(&mut self) -> &mut Responses {
+        &mut self.responses
+    }
+}
@@ -0,0 +1,408 @@
+//! Streaming support for the OpenAI Responses API.
+//!
+//! When `stream` is enabled, the Responses API sends server-sent events (SSE)
+//! describing the response as it is generated. This module provides the typed
+//! event enum, the SSE parser used by [`Responses::complete_stream`], and
+//! accumulators for assembling streamed content.
+//!
+//! [`Responses::complete_stream`]: crate::responses::request::Responses::complete_stream
+
+use crate::common::errors::{OpenAIToolError, Result};
+use crate::responses::response::{Output, Response};
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::pin::Pin;
+
+/// Stream of events returned by [`Responses::complete_stream`].
+///
+/// [`Responses::complete_stream`]: crate::responses::request::Responses::complete_stream
+pub type ResponseEventStream = Pin<Box<dyn Stream<Item = Result<ResponseStreamEvent>> + Send>>;
+
+/// A server-sent event emitted by the Responses API while streaming.
+///
+/// Event types not modelled here are deserialized as [`ResponseStreamEvent::Unknown`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum ResponseStreamEvent {
+    // ========== Response Lifecycle ==========
+    /// The response was created.
+    #[serde(rename = "response.created")]
+    ResponseCreated(ResponseLifecycleEvent),
+    /// The response is being generated.
+    #[serde(rename = "response.in_progress")]
+    ResponseInProgress(ResponseLifecycleEvent),
+    /// The response finished successfully.
+    #[serde(rename = "response.completed")]
+    ResponseCompleted(ResponseLifecycleEvent),
+    /// The response failed.
+    #[serde(rename = "response.failed")]
+    ResponseFailed(ResponseLifecycleEvent),
+    /// The response finished but is incomplete (e.g., `max_output_tokens` was reached).
+    #[serde(rename = "response.incomplete")]
+    ResponseIncomplete(ResponseLifecycleEvent),
+
+    // ========== Output Items ==========
+    /// A new output item was added.
+    #[serde(rename = "response.output_item.added")]
+    OutputItemAdded(OutputItemEvent),
+    /// An output item is complete.
+    #[serde(rename = "response.output_item.done")]
+    OutputItemDone(OutputItemEvent),
+
+    // ========== Output Text ==========
+    /// A chunk of output text.
+    #[serde(rename = "response.output_text.delta")]
+    OutputTextDelta(OutputTextDeltaEvent),
+    /// The output text of a content part is complete.
+    #[serde(rename = "response.output_text.done")]
+    OutputTextDone(OutputTextDoneEvent),
+
+    // ========== Function Calls ==========
+    /// A chunk of function call arguments.
+    #[serde(rename = "response.function_call_arguments.delta")]
+    FunctionCallArgumentsDelta(FunctionCallArgumentsDeltaEvent),
+    /// The function call arguments are complete.
+    #[serde(rename = "response.function_call_arguments.done")]
+    FunctionCallArgumentsDone(FunctionCallArgumentsDoneEvent),
+
+    // ========== Reasoning Summary ==========
+    /// A new reasoning summary part was added.
+    #[serde(rename = "response.reasoning_summary_part.added")]
+    ReasoningSummaryPartAdded(ReasoningSummaryPartEvent),
+    /// A reasoning summary part is complete.
+    #[serde(rename = "response.reasoning_summary_part.done")]
+    ReasoningSummaryPartDone(ReasoningSummaryPartEvent),
+    /// A chunk of reasoning summary text.
+    #[serde(rename = "response.reasoning_summary_text.delta")]
+    ReasoningSummaryTextDelta(ReasoningSummaryTextDeltaEvent),
+    /// The reasoning summary text of a summary part is complete.
+    #[serde(rename = "response.reasoning_summary_text.done")]
+    ReasoningSummaryTextDone(ReasoningSummaryTextDoneEvent),
+
+    // ========== Error ==========
+    /// An error occurred while streaming.
+    #[serde(rename = "error")]
+    Error(StreamErrorEvent),
+
+    /// Any event type not modelled above.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Event carrying a snapshot of the whole response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponseLifecycleEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub response: Response,
+}
+
+/// Event for an output item being added or completed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutputItemEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub output_index: u32,
+    pub item: Output,
+}
+
+/// Output text delta event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutputTextDeltaEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    pub delta: String,
+}
+
+/// Output text done event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutputTextDoneEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    pub text: String,
+}
+
+/// Function call arguments delta event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FunctionCallArgumentsDeltaEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub delta: String,
+}
+
+/// Function call arguments done event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FunctionCallArgumentsDoneEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub arguments: String,
+}
+
+/// A part of a reasoning summary.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ReasoningSummaryPart {
+    /// The type of the part, typically "summary_text"
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// The summary text (empty when the part was just added)
+    #[serde(default)]
+    pub text: String,
+}
+
+/// Reasoning summary part added/done event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReasoningSummaryPartEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub summary_index: u32,
+    pub part: ReasoningSummaryPart,
+}
+
+/// Reasoning summary text delta event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReasoningSummaryTextDeltaEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub summary_index: u32,
+    pub delta: String,
+}
+
+/// Reasoning summary text done event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReasoningSummaryTextDoneEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    pub item_id: String,
+    pub output_index: u32,
+    pub summary_index: u32,
+    pub text: String,
+}
+
+/// Error event sent in the stream.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamErrorEvent {
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+    #[serde(default)]
+    pub code: Option<String>,
+    pub message: String,
+    #[serde(default)]
+    pub param: Option<String>,
+}
+
+/// Accumulates streamed reasoning summary text.
+///
+/// Feed every [`ResponseStreamEvent`] to [`push`](Self::push); reasoning summary
+/// deltas are collected per reasoning item and summary index, so the model's
+/// "thinking" can be displayed live and read back in full afterwards.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use openai_tools::responses::request::Responses;
+/// use openai_tools::responses::stream::ReasoningSummaryAccumulator;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut responses = Responses::new();
+/// responses.str_message("Why is the sky blue?");
+///
+/// let mut summary = ReasoningSummaryAccumulator::default();
+/// let mut events = responses.complete_stream().await?;
+/// while let Some(event) = events.next().await {
+///     if let Some(delta) = summary.push(&event?) {
+///         print!("{}", delta);
+///     }
+/// }
+/// println!("\n\nFull summary:\n{}", summary.text());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ReasoningSummaryAccumulator {
+    /// Summary texts keyed by (output index, summary index)
+    parts: BTreeMap<(u32, u32), String>,
+}
+
+impl ReasoningSummaryAccumulator {
+    /// Records a stream event, returning the new summary text if it was a delta.
+    ///
+    /// `reasoning_summary_text.done` events replace the accumulated text of their
+    /// part with the final text. Other events are ignored.
+    pub fn push<'a>(&mut self, event: &'a ResponseStreamEvent) -> Option<&'a str> {
+        match event {
+            ResponseStreamEvent::ReasoningSummaryTextDelta(e) => {
+                self.parts.entry((e.output_index, e.summary_index)).or_default().push_str(&e.delta);
+                Some(&e.delta)
+            }
+            ResponseStreamEvent::ReasoningSummaryTextDone(e) => {
+                self.parts.insert((e.output_index, e.summary_index), e.text.clone());
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the summary parts received so far, in order.
+    pub fn parts(&self) -> Vec<&str> {
+        self.parts.values().map(String::as_str).collect()
+    }
+
+    /// Returns all summary parts joined by blank lines.
+    pub fn text(&self) -> String {
+        self.parts().join("\n\n")
+    }
+
+    /// Returns `true` if no reasoning summary text has been received.
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+}
+
+/// Event data marking the end of a stream.
+const DONE_SENTINEL: &str = "[DONE]";
+
+/// Incremental parser for server-sent events.
+///
+/// Collects the `data:` lines of each event and returns the payloads of the
+/// events completed by each chunk. Chunks may end anywhere, including inside
+/// a line, a `\r\n` line break or a multi-byte UTF-8 character: incomplete
+/// lines are buffered until the rest arrives. Lines may end with `\n`, `\r\n`
+/// or `\r`, and the `data:` lines of an event are joined with `\n`.
+///
+/// Comment lines (`: ping`) and events without data or with only whitespace
+/// are skipped. A `data: [DONE]` event ends the stream: it is not returned,
+/// and everything after it is ignored.
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    /// Bytes of the line being received
+    line: Vec<u8>,
+    /// Data lines of the event being received
+    data: Vec<String>,
+    /// Whether the last byte was a `\r`, so that a following `\n` ends no new line
+    after_cr: bool,
+    /// Whether the `[DONE]` sentinel has been received
+    done: bool,
+}
+
+impl SseParser {
+    /// Returns `true` once the `[DONE]` sentinel has been received.
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Feeds a chunk of the response body, returning the data of completed events.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<String> {
+        let mut payloads = Vec::new();
+        for &byte in chunk {
+            if self.done {
+                break;
+            }
+            match byte {
+                b'\n' if self.after_cr => self.after_cr = false,
+                b'\r' | b'\n' => {
+                    self.after_cr = byte == b'\r';
+                    payloads.extend(self.end_line());
+                }
+                _ => {
+                    self.after_cr = false;
+                    self.line.push(byte);
+                }
+            }
+        }
+        payloads
+    }
+
+    /// Returns the data of an event left unterminated at the end of the body.
+    pub(crate) fn finish(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        self.end_line().or_else(|| self.dispatch())
+    }
+
+    /// Processes the buffered line, returning the event data if it was the blank line ending an event.
+    fn end_line(&mut self) -> Option<String> {
+        // Line breaks are ASCII, so a complete line never ends inside a UTF-8 character
+        let line = String::from_utf8_lossy(&self.line).into_owned();
+        self.line.clear();
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        // `field: value`, where a single space after the colon is not part of the value;
+        // lines starting with a colon are comments
+        let (field, value) = line.split_once(':').unwrap_or((line.as_str(), ""));
+        if field == "data" {
+            self.data.push(value.strip_prefix(' ').unwrap_or(value).to_string());
+        }
+        None
+    }
+
+    /// Completes the current event, returning its data unless it is blank or the `[DONE]` sentinel.
+    fn dispatch(&mut self) -> Option<String> {
+        let data = std::mem::take(&mut self.data).join("\n");
+        match data.trim() {
+            "" => None,
+            DONE_SENTINEL => {
+                self.done = true;
+                None
+            }
+            _ => Some(data),
+        }
+    }
+}
+
+/// Converts a streamed response body into a stream of [`ResponseStreamEvent`]s.
+pub(crate) fn parse_event_stream<S>(body: S) -> impl Stream<Item = Result<ResponseStreamEvent>>
+where
+    S: Stream<Item = std::result::Result<Bytes, request::Error>> + Unpin,
+{
+    sse_data_stream(body).map(|data| data.and_then(|data| serde_json::from_str(&data).map_err(OpenAIToolError::SerdeJsonError)))
+}
+
+/// Converts a streamed response body into the `data` payloads of its server-sent events.
+///
+/// The stream ends at the `[DONE]` sentinel or at the end of the body.
+pub(crate) fn sse_data_stream<S>(body: S) -> impl Stream<Item = Result<String>>
+where
+    S: Stream<Item = std::result::Result<Bytes, request::Error>> + Unpin,
+{
+    let state = (body.fuse(), SseParser::default(), VecDeque::<String>::new());
+
+    stream::unfold(state, |(mut body, mut parser, mut pending)| async move {
+        loop {
+            if let Some(data) = pending.pop_front() {
+                return Some((Ok(data), (body, parser, pending)));
+            }
+            if parser.is_done() {
+                return None;
+            }
+            match body.next().await {
+                Some(Ok(chunk)) => pending.extend(parser.feed(&chunk)),
+                Some(Err(e)) => return Some((Err(OpenAIToolError::from(e)), (body, parser, pending))),
+                None => {
+                    pending.extend(parser.finish());
+                    if pending.is_empty() {
+                        return None;
+                    }
+                }
+            }
+        }
+    })
+}
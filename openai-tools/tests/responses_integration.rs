@@ -177,12 +177,17 @@ async fn test_responses_with_multi_turn_conversations() {
     responses.model_id("gpt-4o-mini");
     let messages = vec![Message::from_string(Role::User, "What's the weather like today?")];
     responses.messages(messages);
-    responses.previous_response_id(conversation_id);
+    responses.previous_response_id(&conversation_id);
     responses.safety_identifier("a5c75abeef286919b4bf3ae40bc74c2d9ba03ac1bde3759e470e0f2b7056a5a1");
 
     let body_json = serde_json::to_string_pretty(&responses.request_body).unwrap();
     tracing::info!("Request body for second turn: {}", body_json);
 
+    // Only the new turn is sent; the first turn is referenced by previous_response_id
+    let body: serde_json::Value = serde_json::from_str(&body_json).unwrap();
+    assert_eq!(body["previous_response_id"], conversation_id.as_str());
+    assert_eq!(body["input"], serde_json::json!([{"role": "user", "content": "What's the weather like today?"}]));
+
     let mut counter = 3;
     loop {
         match responses.complete().await {